@@ -1,3 +1,9 @@
+use std::{
+    cmp::Ordering,
+    iter::Sum,
+    marker::PhantomData,
+};
+
 /// Returns the target field of a container (struct) by value, consumes the container (unless it implements `Copy`).
 ///
 /// Auto-derive creates the implementation for all fields, works for structs (both with named and unnamed fields) if
@@ -15,7 +21,7 @@
 ///
 /// let joe = || Person { name: String::from("Joe"), age: 42 };
 ///
-/// assert_eq!(42u8, joe().get());
+/// assert_eq!(42u8, Get::<u8>::get(joe()));
 ///
 /// let joe_name: String = joe().get();
 ///
@@ -44,7 +50,7 @@ pub trait Get<Value> {
 ///
 /// let joe = Person { name: String::from("Joe"), age: 42 };
 ///
-/// assert_eq!(42u8, *joe.get_ref());
+/// assert_eq!(42u8, *GetRef::<u8>::get_ref(&joe));
 ///
 /// let name: &String = joe.get_ref();
 ///
@@ -56,6 +62,36 @@ pub trait GetRef<Value> {
     fn get_ref(&self) -> &Value;
 }
 
+/// Returns the target field of a container (struct) by mutable reference, keeps the container.
+///
+/// This trait does not have a corresponding auto-derive macro.
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// pub struct Counter {
+///     pub label: String,
+///     pub hits: u64,
+/// }
+///
+/// impl GetMut<u64> for Counter {
+///     fn get_mut(&mut self) -> &mut u64 {
+///         &mut self.hits
+///     }
+/// }
+///
+/// let mut counter = Counter { label: String::from("index"), hits: 41 };
+///
+/// *counter.get_mut() += 1;
+///
+/// assert_eq!(42, counter.hits);
+///
+///```
+///
+pub trait GetMut<Value> {
+    fn get_mut(&mut self) -> &mut Value;
+}
+
 /// Updates the field of a container (struct/enum) with the provided value. Consumes the original container (unless it implements `Copy`), returns the updated one.
 ///
 /// Auto-derive creates the implementation for all fields in structs and enum variants, works for structs and enums (both with named and unnamed fields) if
@@ -104,6 +140,23 @@ pub trait Set<Value> {
     fn set(self, new_value: Value) -> Self;
 }
 
+/// Updates the `Err` side of a `Result` with the provided value (the `Ok` side is covered by [`Set`]). Returns the original container if it holds an `Ok`.
+///
+/// [`Set`]: trait.Set.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// let failed: Result<u8, String> = Err(String::from("timeout"));
+///
+/// assert_eq!(Err(String::from("refused")), failed.set_err(String::from("refused")));
+/// assert_eq!(Ok(3), Ok(3).set_err(String::from("refused")));
+///```
+pub trait SetErr<E>
+    where Self: Sized {
+    fn set_err(self, new_value: E) -> Self;
+}
+
 /// Updates the field of a container (struct/enum) by applying the provided function on the target value. Consumes the original container (unless it implements `Copy`), returns the updated one.
 ///
 /// Auto-derive creates the implementation for all fields in structs and enum variants, works for structs and enums (both with named and unnamed fields) if
@@ -153,6 +206,109 @@ pub trait Modify<Value> {
     fn modify(self, f: impl FnOnce(Value) -> Value) -> Self;
 }
 
+/// Updates the `Err` side of a `Result` by applying the provided function on it (the `Ok` side is covered by [`Modify`]). Returns the original container if it holds an `Ok`.
+///
+/// [`Modify`]: trait.Modify.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// let failed: Result<u8, String> = Err(String::from("timeout"));
+///
+/// assert_eq!(Err(String::from("request: timeout")), failed.modify_err(|e| format!("request: {}", e)));
+///```
+pub trait ModifyErr<E>
+    where Self: Sized {
+    fn modify_err(self, f: impl FnOnce(E) -> E) -> Self;
+}
+
+/// Updates the field of a container (struct/enum) with the provided value in place, keeps the container.
+///
+/// This trait does not have a corresponding auto-derive macro, but every implementor of [`GetMut`] gets it for free.
+///
+/// [`GetMut`]: trait.GetMut.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// pub struct Counter {
+///     pub label: String,
+///     pub hits: u64,
+/// }
+///
+/// impl GetMut<u64> for Counter {
+///     fn get_mut(&mut self) -> &mut u64 {
+///         &mut self.hits
+///     }
+/// }
+///
+/// let mut counter = Counter { label: String::from("index"), hits: 41 };
+///
+/// counter.set_in_place(0u64);
+///
+/// assert_eq!(0, counter.hits);
+///
+///```
+///
+pub trait SetInPlace<Value> {
+    fn set_in_place(&mut self, new_value: Value);
+}
+
+/// Updates the field of a container (struct/enum) in place by applying the provided function on a mutable reference to the target value, keeps the container.
+///
+/// This trait does not have a corresponding auto-derive macro, but every implementor of [`GetMut`] gets it for free.
+/// For enums, implement it by hand, leaving the container untouched if the actual variant is different from the target variant (see example).
+///
+/// [`GetMut`]: trait.GetMut.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// pub struct Counter {
+///     pub label: String,
+///     pub hits: u64,
+/// }
+///
+/// impl GetMut<u64> for Counter {
+///     fn get_mut(&mut self) -> &mut u64 {
+///         &mut self.hits
+///     }
+/// }
+///
+/// let mut counter = Counter { label: String::from("index"), hits: 41 };
+///
+/// counter.modify_in_place(|hits: &mut u64| *hits += 1);
+///
+/// assert_eq!(42, counter.hits);
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum Deviation {
+///     Signed(i32),
+///     Unsigned(u32),
+/// }
+///
+/// impl ModifyInPlace<i32> for Deviation {
+///     fn modify_in_place(&mut self, f: impl FnOnce(&mut i32)) {
+///         if let Deviation::Signed(n) = self {
+///             f(n)
+///         }
+///     }
+/// }
+///
+/// let mut d = Deviation::Signed(-10);
+/// let mut d2 = Deviation::Unsigned(10);
+///
+/// d.modify_in_place(|n: &mut i32| *n = -*n);
+/// d2.modify_in_place(|n: &mut i32| *n = -*n);
+///
+/// assert_eq!(Deviation::Signed(10), d);
+/// assert_eq!(Deviation::Unsigned(10), d2);
+///```
+///
+pub trait ModifyInPlace<Value> {
+    fn modify_in_place(&mut self, f: impl FnOnce(&mut Value));
+}
+
 /// Returns the target field of a container (enum) by value as an `Option`, consumes the container (unless it implements `Copy`).
 ///
 /// Auto-derive creates the implementation for all fields in enum variants, works for enums (both with named and unnamed fields) if
@@ -192,6 +348,22 @@ pub trait GetOption<Value> {
     fn get_option(self) -> Option<Value>;
 }
 
+/// Returns the `Err` side of a `Result` wrapped in an `Option` (the `Ok` side is covered by [`GetOption`]).
+///
+/// [`GetOption`]: trait.GetOption.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// let failed: Result<u8, String> = Err(String::from("timeout"));
+///
+/// assert_eq!(Some(String::from("timeout")), failed.get_option_err());
+/// assert_eq!(None, Ok::<u8, String>(3).get_option_err());
+///```
+pub trait GetOptionErr<E> {
+    fn get_option_err(self) -> Option<E>;
+}
+
 /// Updates the field of a container (enum) with the provided value. Consumes the original container (unless it implements `Copy`), returns the updated one wrapped in an `Option`.
 ///
 /// Auto-derive creates the implementation for all fields in enum variants, works for enums (both with named and unnamed fields) if
@@ -259,6 +431,138 @@ pub trait ModifyOption<Value>
     fn modify_option(self, f: impl FnOnce(Value) -> Value) -> Option<Self>;
 }
 
+/// Returns a reference to the field of a container (enum) wrapped in an `Option`, without consuming the container. The borrowing counterpart of [`GetOption`].
+///
+/// This trait does not have a corresponding auto-derive macro.
+///
+/// If the actual enum variant is different from the target variant, the implementation should return `None` (see example).
+///
+/// [`GetOption`]: trait.GetOption.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// pub enum Deviation {
+///     Signed(i32),
+///     Named(String),
+/// }
+///
+/// impl GetRefOption<String> for Deviation {
+///     fn get_ref_option(&self) -> Option<&String> {
+///         match self {
+///             Deviation::Named(name) => Some(name),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// let d = Deviation::Named(String::from("sigma"));
+///
+/// assert_eq!(Some("sigma"), d.get_ref_option().map(String::as_str));
+/// assert_eq!(None, Deviation::Signed(-10).get_ref_option());
+///
+/// // d is still available
+///
+/// assert!(matches!(d, Deviation::Named(_)));
+///```
+pub trait GetRefOption<Value> {
+    fn get_ref_option(&self) -> Option<&Value>;
+}
+
+/// Returns a mutable reference to the field of a container (enum) wrapped in an `Option`, without consuming the container.
+///
+/// This trait does not have a corresponding auto-derive macro.
+///
+/// If the actual enum variant is different from the target variant, the implementation should return `None` (see example).
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// #[derive(Debug, PartialEq)]
+/// pub enum Deviation {
+///     Signed(i32),
+///     Unsigned(u32),
+/// }
+///
+/// impl GetMutOption<i32> for Deviation {
+///     fn get_mut_option(&mut self) -> Option<&mut i32> {
+///         match self {
+///             Deviation::Signed(value) => Some(value),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// let mut d = Deviation::Signed(-10);
+/// let mut d2 = Deviation::Unsigned(10);
+///
+/// if let Some(value) = d.get_mut_option() { *value = -*value; }
+///
+/// assert_eq!(Deviation::Signed(10), d);
+/// assert_eq!(None, d2.get_mut_option());
+///```
+pub trait GetMutOption<Value> {
+    fn get_mut_option(&mut self) -> Option<&mut Value>;
+}
+
+/// Updates the field of a container (struct/enum) by applying the provided fallible function on the target value. Consumes the original container (unless it implements `Copy`), returns the updated one, or the first error the function returned.
+///
+/// This trait does not have a corresponding auto-derive macro.
+///
+/// For enums, if the actual variant is different from the target variant, the implementation should return the original version (see example).
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// #[derive(Debug, PartialEq)]
+/// pub struct Account {
+///     pub owner: String,
+///     pub balance: u32,
+/// }
+///
+/// impl TryModify<u32> for Account {
+///     fn try_modify<E>(self, f: impl FnOnce(u32) -> Result<u32, E>) -> Result<Self, E> {
+///         Ok(Account { balance: f(self.balance)?, ..self })
+///     }
+/// }
+///
+/// let account = || Account { owner: String::from("Joe"), balance: 100 };
+///
+/// let withdraw = |amount: u32| move |balance: u32| balance.checked_sub(amount).ok_or("insufficient funds");
+///
+/// assert_eq!(Ok(60), account().try_modify(withdraw(40)).map(|a| a.balance));
+/// assert_eq!(Err("insufficient funds"), account().try_modify(withdraw(140)));
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum Input {
+///     Raw(String),
+///     Parsed(i64),
+/// }
+///
+/// impl TryModify<String> for Input {
+///     fn try_modify<E>(self, f: impl FnOnce(String) -> Result<String, E>) -> Result<Self, E> {
+///         match self {
+///             Input::Raw(raw) => f(raw).map(Input::Raw),
+///             other => Ok(other),
+///         }
+///     }
+/// }
+///
+/// let trimmed = Input::Raw(String::from(" 42 ")).try_modify(|raw: String| -> Result<String, ()> {
+///     Ok(raw.trim().to_owned())
+/// });
+///
+/// assert_eq!(Ok(Input::Raw(String::from("42"))), trimmed);
+///
+/// let untouched = Input::Parsed(42).try_modify(|_: String| Err(()));
+///
+/// assert_eq!(Ok(Input::Parsed(42)), untouched);
+///```
+pub trait TryModify<Value>
+    where Self: Sized {
+    fn try_modify<E>(self, f: impl FnOnce(Value) -> Result<Value, E>) -> Result<Self, E>;
+}
+
 /// Constructs the relevant enum variant based on the input.
 ///
 /// This trait does not have a corresponding auto-derive macro.
@@ -292,427 +596,5475 @@ pub trait ReverseGet<Value> {
     fn reverse_get(value: Value) -> Self;
 }
 
-pub mod composites {
-    use super::*;
+/// Creates a `Result` from an error (the `Ok` side is covered by [`ReverseGet`]).
+///
+/// [`ReverseGet`]: trait.ReverseGet.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// assert_eq!(Err::<u8, &str>("timeout"), Result::reverse_get_err("timeout"));
+///```
+pub trait ReverseGetErr<E> {
+    fn reverse_get_err(value: E) -> Self;
+}
 
-    /// A variant of [`Get`], reaching two levels deep in the data structure.
-    ///
-    /// [`Get`]: ../trait.Get.html
-    ///
-    /// # Examples
-    ///```
-    /// # use photonix::*;
-    /// #[derive(Get)]
-    /// pub struct Employee { pub name: String, pub company: Company }
-    ///
-    /// #[derive(Get)]
-    /// pub struct Company { pub name: String, pub address: Address }
-    ///
-    /// pub struct Address(String);
-    ///
-    /// //             Level 1   Level 2    Parent type
-    /// //               |          |           |
-    /// impl GetSecond<Company, String> for Employee {}
-    ///
-    /// let john_doe = Employee {
-    ///     name: String::from("John Doe"),
-    ///     company: Company {
-    ///         name: String::from("Acme Corporation"),
-    ///         address: Address(String::from("4 Foo Road, Bar City")),
-    ///         }
-    ///     };
-    ///
-    /// let company_name = john_doe.get_second();
-    ///
-    /// assert_eq!("Acme Corporation", company_name.as_str());
-    ///
-    ///```
-    pub trait GetSecond<LevelOne, LevelTwo>
-        where
-            LevelOne: Get<LevelTwo>,
-            Self: Get<LevelOne> + Sized {
-        fn get_second(self) -> LevelTwo {
-            self.get().get()
-        }
+/// Converts a container to the target type and back without losing information (isomorphism).
+///
+/// This trait does not have a corresponding auto-derive macro. An `Iso` does not give [`Get`], [`Set`], [`Modify`], and [`ReverseGet`] by itself: call the [`iso!`] macro to implement them based on it.
+///
+/// Blanket implementations (`impl<C: Iso<T>, T> Get<T> for C`) are ruled out by coherence: they would overlap with the instances for smart pointers and `Option` in [`implementations`], and every hand-written or derived `Get`/`Set`/`Modify` of a type would conflict with them, since the compiler has to assume that the type may implement `Iso` as well.
+///
+/// Since `from` has the same name as `From::from`, call it through the trait (e.g. `Iso::from(value)` with a type annotation).
+///
+/// [`iso!`]: ../macro.iso.html
+/// [`Get`]: trait.Get.html
+/// [`Set`]: trait.Set.html
+/// [`Modify`]: trait.Modify.html
+/// [`ReverseGet`]: trait.ReverseGet.html
+/// [`implementations`]: ../implementations/index.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// pub struct Meters(f64);
+///
+/// impl Iso<f64> for Meters {
+///     fn to(self) -> f64 {
+///         self.0
+///     }
+///
+///     fn from(target: f64) -> Self {
+///         Meters(target)
+///     }
+/// }
+///
+/// let marathon: Meters = Iso::from(42195.0);
+///
+/// assert_eq!(42195.0, marathon.to());
+/// assert_eq!(Meters(42196.0), marathon.over(|m| m + 1.0));
+/// assert_eq!(84390.0, Meters::under(42195.0, |Meters(m)| Meters(m * 2.0)));
+///
+/// assert!(marathon.round_trip());
+/// assert!(Meters::reverse_round_trip(&42195.0));
+///
+/// // The lens vocabulary is opt-in
+///
+/// iso![Meters => f64];
+///
+/// assert_eq!(42195.0, marathon.get());
+/// assert_eq!(Meters(0.0), marathon.set(0.0));
+///```
+pub trait Iso<Target>
+    where Self: Sized {
+    fn to(self) -> Target;
+
+    fn from(target: Target) -> Self;
+
+    /// Applies the provided function on the container, viewed as the target type.
+    fn over(self, f: impl FnOnce(Target) -> Target) -> Self {
+        <Self as Iso<Target>>::from(f(self.to()))
     }
 
-    /// A variant of [`GetRef`], reaching two levels deep in the data structure.
-    ///
-    /// [`GetRef`]: ../trait.GetRef.html
-    ///
-    /// # Examples
-    ///```
-    /// # use photonix::*;
-    /// #[derive(GetRef)]
-    /// pub struct Employee { pub name: String, pub company: Company }
-    ///
-    /// #[derive(GetRef)]
-    /// pub struct Company { pub name: String, pub address: Address }
-    ///
-    /// pub struct Address(String);
-    ///
-    /// //                      Level 1     Level 2     Parent type
-    /// //                          |          |            |
-    /// impl<'a> GetRefSecond<'a, Company, String> for Employee {}
-    ///
-    /// let john_doe = Employee {
-    ///     name: String::from("John Doe"),
-    ///     company: Company {
-    ///         name: String::from("Acme Corporation"),
-    ///         address: Address(String::from("4 Foo Road, Bar City")),
-    ///         }
-    ///     };
-    ///
-    /// let company_name = john_doe.get_ref_second();
-    ///
-    /// assert_eq!("Acme Corporation", company_name.as_str());
-    ///
-    ///```
-    pub trait GetRefSecond<'a, LevelOne, LevelTwo>
-        where
-            LevelOne: GetRef<LevelTwo> + 'a,
-            Self: GetRef<LevelOne> + 'a {
-        fn get_ref_second(&'a self) -> &'a LevelTwo {
-            self.get_ref().get_ref()
-        }
+    /// Applies the provided function on the target, viewed as the container type.
+    fn under(target: Target, f: impl FnOnce(Self) -> Self) -> Target {
+        f(<Self as Iso<Target>>::from(target)).to()
     }
 
-    /// A variant of [`Set`], reaching two levels deep in the data structure.
-    ///
-    /// [`Set`]: ../trait.Set.html
-    ///
-    /// # Examples
-    ///```
-    /// # use photonix::*;
-    /// #[derive(Get, Modify)]
-    /// pub struct Employee { pub name: String, pub company: Company }
-    ///
-    /// #[derive(Get, Set)]
-    /// pub struct Company { pub name: String, pub address: Address }
-    ///
-    /// pub struct Address(String);
-    ///
-    /// //            Level 1    Level 2     Parent type
-    /// //                |        |           |
-    /// impl SetSecond<Company, String> for Employee {}
-    /// impl GetSecond<Company, String> for Employee {}
-    ///
-    /// let john_doe = Employee {
-    ///     name: String::from("John Doe"),
-    ///     company: Company {
-    ///         name: String::from("Acme Corporation"),
-    ///         address: Address(String::from("4 Foo Road, Bar City")),
-    ///         }
-    ///     };
-    ///
-    /// let john_doe_new = john_doe.set_second(String::from("Evil Corp"));
-    ///
-    /// assert_eq!("Evil Corp", john_doe_new.get_second().as_str());
-    ///
-    ///```
-    pub trait SetSecond<LevelOne, LevelTwo>
-        where
-            LevelOne: Set<LevelTwo>,
-            Self: Modify<LevelOne> + Sized {
-        fn set_second(self, new_value: LevelTwo) -> Self {
-            self.modify(|level_one| level_one.set(new_value))
-        }
+    /// Checks the law `from(to(x)) == x`.
+    fn round_trip(&self) -> bool
+        where Self: Clone + PartialEq {
+        <Self as Iso<Target>>::from(self.clone().to()) == *self
     }
 
-    /// A variant of [`Modify`], reaching two levels deep in the data structure.
-    ///
-    /// [`Modify`]: ../trait.Modify.html
-    ///
-    /// # Examples
-    ///```
-    /// # use photonix::*;
-    /// #[derive(Get, Modify)]
-    /// pub struct Employee { pub name: String, pub company: Company }
+    /// Checks the law `to(from(y)) == y`.
+    fn reverse_round_trip(target: &Target) -> bool
+        where Target: Clone + PartialEq {
+        <Self as Iso<Target>>::from(target.clone()).to() == *target
+    }
+}
+
+/// Returns all the target values of a container (traversal) by reference, as an iterator, keeps the container.
+///
+/// This trait does not have a corresponding auto-derive macro. The [`traverse!`] macro implements it for struct fields having [`GetRef`].
+///
+/// [`traverse!`]: ../macro.traverse.html
+/// [`GetRef`]: trait.GetRef.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// pub struct Order {
+///     pub id: String,
+///     pub prices: Vec<u32>,
+/// }
+///
+/// impl GetAll<u32> for Order {
+///     fn get_all(&self) -> Box<dyn Iterator<Item = &u32> + '_> {
+///         self.prices.get_all()
+///     }
+/// }
+///
+/// let order = Order { id: String::from("order-1"), prices: vec![10, 20, 30] };
+///
+/// assert_eq!(60u32, order.get_all().sum::<u32>());
+///
+///```
+///
+pub trait GetAll<Value> {
+    fn get_all(&self) -> Box<dyn Iterator<Item = &Value> + '_>;
+}
+
+/// Updates all the target values of a container (traversal) by applying the provided function on each of them. Consumes the original container (unless it implements `Copy`), returns the updated one.
+///
+/// This trait does not have a corresponding auto-derive macro. The [`traverse!`] macro implements it for struct fields having [`Modify`].
+///
+/// [`traverse!`]: ../macro.traverse.html
+/// [`Modify`]: trait.Modify.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// #[derive(Debug, PartialEq)]
+/// pub struct Order {
+///     pub id: String,
+///     pub prices: Vec<u32>,
+/// }
+///
+/// impl ModifyAll<u32> for Order {
+///     fn modify_all(self, f: impl FnMut(u32) -> u32) -> Self {
+///         Order { prices: self.prices.modify_all(f), ..self }
+///     }
+/// }
+///
+/// let order = Order { id: String::from("order-1"), prices: vec![10, 20, 30] };
+///
+/// let discounted = order.modify_all(|price: u32| price / 2);
+///
+/// assert_eq!(vec![5, 10, 15], discounted.prices);
+///
+///```
+///
+pub trait ModifyAll<Value> {
+    fn modify_all(self, f: impl FnMut(Value) -> Value) -> Self;
+}
+
+/// Aggregates all the target values of a traversal (see [`GetAll`]) by reference, keeps the container.
+///
+/// Every implementor of [`GetAll`] gets it for free. To fold through composed optics, implement [`GetAll`] with one of the traversal [`composites`].
+///
+/// The method collecting the targets is called `to_ref_vec` rather than `to_vec`: `Vec<T>` and arrays implement [`GetAll`], and a `to_vec` here would shadow the slice method of the same name, so `vec.to_vec()` would silently start returning `Vec<&T>` instead of `Vec<T>` for everyone importing `photonix::*`.
+///
+/// [`GetAll`]: trait.GetAll.html
+/// [`composites`]: composites/index.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// #[derive(GetRef, Modify)]
+/// pub struct Order { pub id: String, pub lines: Vec<Line> }
+///
+/// #[derive(GetRef, Modify)]
+/// pub struct Line { pub sku: String, pub price: u32 }
+///
+/// traverse![Order => Vec<Line>, Line => u32];
+///
+/// pub struct Report { pub orders: Vec<Order> }
+///
+/// impl GetAll<u32> for Report {
+///     fn get_all(&self) -> Box<dyn Iterator<Item = &u32> + '_> {
+///         self.orders.get_all_fourth()
+///     }
+/// }
+///
+/// let order = |id: &str, prices: &[u32]| Order {
+///     id: String::from(id),
+///     lines: prices.iter().map(|&price| Line { sku: String::from("sku"), price }).collect(),
+/// };
+///
+/// let report = Report { orders: vec![order("order-1", &[3, 4]), order("order-2", &[5, 2])] };
+///
+/// assert_eq!(14u32, report.sum::<u32>());
+/// assert_eq!(4, report.count());
+/// assert_eq!(Some(&5), report.max_by(|a, b| a.cmp(b)));
+/// assert_eq!(Some(&4), report.find(|price| *price % 2 == 0));
+/// assert!(report.all(|price| *price > 1));
+/// assert!(!report.any(|price| *price > 5));
+/// assert_eq!(vec![&3, &4, &5, &2], report.to_ref_vec());
+/// assert_eq!(
+///     String::from("3452"),
+///     report.fold(String::new(), |acc, price| acc + &price.to_string())
+/// );
+///
+///```
+///
+pub trait Fold<Value>
+    where Self: GetAll<Value> {
+    fn fold<B>(&self, init: B, f: impl FnMut(B, &Value) -> B) -> B {
+        self.get_all().fold(init, f)
+    }
+
+    fn sum<'a, S>(&'a self) -> S
+        where S: Sum<&'a Value>,
+              Value: 'a {
+        self.get_all().sum()
+    }
+
+    fn count(&self) -> usize {
+        self.get_all().count()
+    }
+
+    fn any(&self, f: impl FnMut(&Value) -> bool) -> bool {
+        self.get_all().any(f)
+    }
+
+    fn all(&self, f: impl FnMut(&Value) -> bool) -> bool {
+        self.get_all().all(f)
+    }
+
+    fn find(&self, mut predicate: impl FnMut(&Value) -> bool) -> Option<&Value> {
+        self.get_all().find(|value| predicate(value))
+    }
+
+    fn max_by(&self, mut compare: impl FnMut(&Value, &Value) -> Ordering) -> Option<&Value> {
+        self.get_all().max_by(|a, b| compare(a, b))
+    }
+
+    fn to_ref_vec(&self) -> Vec<&Value> {
+        self.get_all().collect()
+    }
+}
+
+/// Returns a reference to the element at the given position of a container (sequence), or `None` if the index is out of bounds.
+///
+/// This trait does not have a corresponding auto-derive macro. It is implemented for `Vec`, `VecDeque`, arrays, slices, and boxed slices.
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// let primes = vec![2, 3, 5, 7];
+///
+/// assert_eq!(Some(&5), primes.get_index(2));
+/// assert_eq!(None, primes.get_index(4));
+///```
+pub trait GetIndex<Value> {
+    fn get_index(&self, index: usize) -> Option<&Value>;
+}
+
+/// Updates the element at the given position of a container (sequence) with the provided value. Consumes the original container, returns the updated one wrapped in an `Option`, or `None` if the index is out of bounds.
+///
+/// This trait does not have a corresponding auto-derive macro. It is implemented for `Vec`, `VecDeque`, arrays, and boxed slices.
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// let primes = || vec![2, 3, 5, 8];
+///
+/// assert_eq!(Some(vec![2, 3, 5, 7]), primes().set_index(3, 7));
+/// assert_eq!(None, primes().set_index(4, 7));
+///```
+pub trait SetIndex<Value>
+    where Self: Sized {
+    fn set_index(self, index: usize, new_value: Value) -> Option<Self>;
+}
+
+/// Updates the element at the given position of a container (sequence) by applying the provided function on it. Consumes the original container, returns the updated one wrapped in an `Option`, or `None` if the index is out of bounds.
+///
+/// This trait does not have a corresponding auto-derive macro. It is implemented for `Vec`, `VecDeque`, arrays, and boxed slices.
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// let primes = || [2, 3, 5, 8];
+///
+/// assert_eq!(Some([2, 3, 5, 7]), primes().modify_index(3, |n| n - 1));
+/// assert_eq!(None, primes().modify_index(4, |n| n - 1));
+///```
+pub trait ModifyIndex<Value>
+    where Self: Sized {
+    fn modify_index(self, index: usize, f: impl FnOnce(Value) -> Value) -> Option<Self>;
+}
+
+/// Returns a reference to the entry at the given key of a keyed container, or `None` if there is no such entry. For sets, the entry is the stored element equal to the key.
+///
+/// Like the lookups of the standard collections, it takes any borrowed form of the key (e.g. `str` for `String` keys).
+///
+/// This trait does not have a corresponding auto-derive macro. It is implemented for `HashMap`, `BTreeMap`, `HashSet`, and `BTreeSet`.
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// # use std::collections::{BTreeMap, HashSet};
+/// let mut ports = BTreeMap::new();
+/// ports.insert(String::from("http"), 80);
+///
+/// assert_eq!(Some(&80), ports.get_key("http"));
+/// assert_eq!(None, ports.get_key("https"));
+///
+/// let tags: HashSet<_> = vec![String::from("draft"), String::from("urgent")].into_iter().collect();
+///
+/// assert_eq!(Some(&String::from("urgent")), tags.get_key("urgent"));
+///```
+pub trait GetKey<Key, Value>
+    where Key: ?Sized {
+    fn get_key(&self, key: &Key) -> Option<&Value>;
+}
+
+/// Focuses on the entry at the given key of a keyed container: `Option<Value>` for maps, and membership (`bool`) for sets. Setting `Some` inserts (or replaces) an entry, setting `None` deletes it; setting `true` or `false` adds or removes a set element. Both methods consume the original container; entries are read by reference with [`GetKey`].
+///
+/// This trait does not have a corresponding auto-derive macro. It is implemented for `HashMap`, `BTreeMap`, `HashSet`, and `BTreeSet`.
+///
+/// [`GetKey`]: trait.GetKey.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// # use std::collections::{BTreeSet, HashMap};
+/// let mut ports = HashMap::new();
+/// ports.insert("http", 80);
+///
+/// let ports = ports.set_key("https", Some(443));
+///
+/// assert_eq!(Some(&443), ports.get_key(&"https"));
+///
+/// let ports = ports.modify_key("http", |port| port.map(|_| 8080)).set_key("https", None);
+///
+/// assert_eq!((Some(&8080), None), (ports.get_key(&"http"), ports.get_key(&"https")));
+///
+/// let tags = BTreeSet::new().set_key("draft", true).set_key("urgent", true).set_key("draft", false);
+///
+/// assert_eq!(vec!["urgent"], tags.into_iter().collect::<Vec<_>>());
+///```
+pub trait At<Key, Focus>
+    where Self: Sized {
+    fn set_key(self, key: Key, new_focus: Focus) -> Self;
+
+    fn modify_key(self, key: Key, f: impl FnOnce(Focus) -> Focus) -> Self;
+}
+
+/// Markers for the positions of a tuple, passed to [`GetNth`] and the related traits. They live in their own module so that `use photonix::*` doesn't bring ordinal names into scope; refer to them as `nth::Second`, or import them with `use photonix::nth::*`.
+///
+/// [`GetNth`]: ../trait.GetNth.html
+pub mod nth {
+    /// Marks the first position of a tuple (`.0`), see [`GetNth`].
     ///
-    /// #[derive(Get, Modify)]
-    /// pub struct Company { pub name: String, pub address: Address }
+    /// [`GetNth`]: ../trait.GetNth.html
+    pub struct First;
+
+    /// Marks the second position of a tuple (`.1`), see [`GetNth`].
     ///
-    /// pub struct Address(String);
+    /// [`GetNth`]: ../trait.GetNth.html
+    pub struct Second;
+
+    /// Marks the third position of a tuple (`.2`), see [`GetNth`].
     ///
-    /// //            Level 1    Level 2     Parent type
-    /// //                |        |           |
-    /// impl ModifySecond<Company, String> for Employee {}
-    /// impl GetSecond<Company, String> for Employee {}
+    /// [`GetNth`]: ../trait.GetNth.html
+    pub struct Third;
+
+    /// Marks the fourth position of a tuple (`.3`), see [`GetNth`].
     ///
-    /// let john_doe = Employee {
-    ///     name: String::from("John Doe"),
-    ///     company: Company {
-    ///         name: String::from("Acme Corporation"),
-    ///         address: Address(String::from("4 Foo Road, Bar City")),
-    ///         }
-    ///     };
+    /// [`GetNth`]: ../trait.GetNth.html
+    pub struct Fourth;
+
+    /// Marks the fifth position of a tuple (`.4`), see [`GetNth`].
     ///
-    /// let john_doe_new = john_doe.modify_second(|name| name.replace("Corporation", "Inc"));
+    /// [`GetNth`]: ../trait.GetNth.html
+    pub struct Fifth;
+
+    /// Marks the sixth position of a tuple (`.5`), see [`GetNth`].
     ///
-    /// assert_eq!("Acme Inc", john_doe_new.get_second().as_str());
+    /// [`GetNth`]: ../trait.GetNth.html
+    pub struct Sixth;
+
+    /// Marks the seventh position of a tuple (`.6`), see [`GetNth`].
     ///
-    ///```
-    pub trait ModifySecond<LevelOne, LevelTwo>
-        where
-            LevelOne: Modify<LevelTwo>,
-            Self: Modify<LevelOne> + Sized {
-        fn modify_second(self, f: impl FnOnce(LevelTwo) -> LevelTwo) -> Self {
-            self.modify(|level_one| level_one.modify(f))
-        }
-    }
+    /// [`GetNth`]: ../trait.GetNth.html
+    pub struct Seventh;
 
-    /// A variant of [`Get`], reaching three levels deep in the data structure.
+    /// Marks the eighth position of a tuple (`.7`), see [`GetNth`].
     ///
-    /// [`Get`]: ../trait.Get.html
+    /// [`GetNth`]: ../trait.GetNth.html
+    pub struct Eighth;
+
+    /// Marks the ninth position of a tuple (`.8`), see [`GetNth`].
     ///
-    /// # Examples
-    ///```
-    /// # use photonix::*;
-    /// #[derive(Get)]
-    /// pub struct Employee { pub name: String, pub company: Company }
+    /// [`GetNth`]: ../trait.GetNth.html
+    pub struct Ninth;
+
+    /// Marks the tenth position of a tuple (`.9`), see [`GetNth`].
     ///
-    /// #[derive(Get)]
-    /// pub struct Company { pub name: String, pub address: Address }
+    /// [`GetNth`]: ../trait.GetNth.html
+    pub struct Tenth;
+
+    /// Marks the eleventh position of a tuple (`.10`), see [`GetNth`].
     ///
-    /// #[derive(Get)]
-    /// pub struct Address(String);
+    /// [`GetNth`]: ../trait.GetNth.html
+    pub struct Eleventh;
+
+    /// Marks the twelfth position of a tuple (`.11`), see [`GetNth`].
     ///
-    /// //            Level 1    Level 2  Level 3   Parent type
-    /// //                |        |        |           |
-    /// impl GetThird<Company, Address, String> for Employee {}
-    ///
-    /// let john_doe = Employee {
-    ///     name: String::from("John Doe"),
-    ///     company: Company {
-    ///         name: String::from("Acme Corporation"),
-    ///         address: Address(String::from("4 Foo Road, Bar City")),
-    ///         }
-    ///     };
-    ///
-    /// let company_address = john_doe.get_third();
-    ///
-    /// assert_eq!("4 Foo Road, Bar City", company_address.as_str());
-    ///
-    ///```
-    pub trait GetThird<LevelOne, LevelTwo, LevelThree>
-        where
-            LevelTwo: Get<LevelThree>,
-            LevelOne: Get<LevelTwo>,
-            Self: Get<LevelOne> + Sized {
-        fn get_third(self) -> LevelThree {
-            self.get().get().get()
-        }
-    }
+    /// [`GetNth`]: ../trait.GetNth.html
+    pub struct Twelfth;
+}
 
-    /// A variant of [`GetRef`], reaching three levels deep in the data structure.
-    ///
-    /// [`GetRef`]: ../trait.GetRef.html
-    ///
-    /// # Examples
-    ///```
-    /// # use photonix::*;
-    /// #[derive(GetRef)]
-    /// pub struct Employee { pub name: String, pub company: Company }
-    ///
-    /// #[derive(GetRef)]
-    /// pub struct Company { pub name: String, pub address: Address }
-    ///
-    /// #[derive(GetRef)]
-    /// pub struct Address(String);
-    ///
-    /// //                      Level 1    Level 2  Level 3   Parent type
-    /// //                          |        |        |           |
-    /// impl<'a> GetRefThird<'a, Company, Address, String> for Employee {}
-    ///
-    /// let john_doe = Employee {
-    ///     name: String::from("John Doe"),
-    ///     company: Company {
-    ///         name: String::from("Acme Corporation"),
-    ///         address: Address(String::from("4 Foo Road, Bar City")),
-    ///         }
-    ///     };
-    ///
-    /// let company_address = john_doe.get_ref_third();
-    ///
-    /// assert_eq!("4 Foo Road, Bar City", company_address.as_str());
-    ///
-    ///```
-    pub trait GetRefThird<'a, LevelOne, LevelTwo, LevelThree>
-        where
-            LevelTwo: GetRef<LevelThree> + 'a,
-            LevelOne: GetRef<LevelTwo> + 'a,
-            Self: GetRef<LevelOne> + 'a {
-        fn get_ref_third(&'a self) -> &'a LevelThree {
-            self.get_ref().get_ref().get_ref()
-        }
-    }
+/// Returns the element at the given position of a tuple (up to 12 elements), consuming the tuple. The position is chosen by passing one of the markers [`nth::First`] to [`nth::Twelfth`].
+///
+/// Tuples don't fit the auto-derive macros (their elements may have the same type), so this trait is implemented for them by the crate.
+///
+/// [`nth::First`]: nth/struct.First.html
+/// [`nth::Twelfth`]: nth/struct.Twelfth.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// let pair = (String::from("answer"), 42);
+///
+/// assert_eq!(42, pair.get_nth(nth::Second));
+///```
+pub trait GetNth<Position> {
+    type Target;
 
-    /// A variant of [`Set`], reaching three levels deep in the data structure.
-    ///
-    /// [`Set`]: ../trait.Set.html
-    ///
-    /// # Examples
-    ///```
-    /// # use photonix::*;
-    /// #[derive(Get, Modify)]
-    /// pub struct Employee { pub name: String, pub company: Company }
-    ///
-    /// #[derive(Get, Modify)]
-    /// pub struct Company { pub name: String, pub address: Address }
-    ///
-    /// #[derive(Get, Set)]
-    /// pub struct Address(String);
-    ///
-    /// //            Level 1    Level 2  Level 3   Parent type
-    /// //                |        |        |           |
-    /// impl SetThird<Company, Address, String> for Employee {}
-    /// impl GetThird<Company, Address, String> for Employee {}
-    ///
-    /// let john_doe = Employee {
-    ///     name: String::from("John Doe"),
-    ///     company: Company {
-    ///         name: String::from("Acme Corporation"),
-    ///         address: Address(String::from("4 Foo Road, Bar City")),
-    ///         }
-    ///     };
-    ///
-    /// let john_doe_new = john_doe.set_third(String::from("3 Baz Road, Qux City"));
-    ///
-    /// assert_eq!("3 Baz Road, Qux City", john_doe_new.get_third().as_str());
-    ///
-    ///```
-    pub trait SetThird<LevelOne, LevelTwo, LevelThree>
-        where
-            LevelTwo: Set<LevelThree>,
-            LevelOne: Modify<LevelTwo>,
-            Self: Modify<LevelOne> + Sized {
-        fn set_third(self, new_value: LevelThree) -> Self {
-            self.modify(
-                |level_one| level_one.modify(
-                    |level_two| level_two.set(new_value)
-                )
-            )
-        }
-    }
+    fn get_nth(self, position: Position) -> Self::Target;
+}
 
-    /// A variant of [`Modify`], reaching three levels deep in the data structure.
-    ///
-    /// [`Modify`]: ../trait.Modify.html
-    ///
-    ///```
-    /// # use photonix::*;
-    /// #[derive(Get, Modify)]
-    /// pub struct Employee { pub name: String, pub company: Company }
-    ///
-    /// #[derive(Get, Modify)]
-    /// pub struct Company { pub name: String, pub address: Address }
-    ///
-    /// #[derive(Get, Modify)]
-    /// pub struct Address(String);
-    ///
-    /// //            Level 1    Level 2  Level 3   Parent type
-    /// //                |        |        |           |
-    /// impl ModifyThird<Company, Address, String> for Employee {}
-    /// impl GetThird<Company, Address, String> for Employee {}
-    ///
-    /// let john_doe = Employee {
-    ///     name: String::from("John Doe"),
-    ///     company: Company {
-    ///         name: String::from("Acme Corporation"),
-    ///         address: Address(String::from("4 Foo Road, Bar City")),
-    ///         }
-    ///     };
-    ///
-    /// let john_doe_new = john_doe.modify_third(|address| address.replace("4", "5"));
-    ///
-    /// assert_eq!("5 Foo Road, Bar City", john_doe_new.get_third().as_str());
-    ///
-    ///```
-    pub trait ModifyThird<LevelOne, LevelTwo, LevelThree>
-        where
-            LevelTwo: Modify<LevelThree>,
-            LevelOne: Modify<LevelTwo>,
-            Self: Modify<LevelOne> + Sized {
-        fn modify_third(self, f: impl FnOnce(LevelThree) -> LevelThree) -> Self {
-            self.modify(
-                |level_one| level_one.modify(
-                    |level_two| level_two.modify(f)
-                )
-            )
-        }
-    }
+/// Returns a reference to the element at the given position of a tuple (up to 12 elements).
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// let triple = (1, 2, 3);
+///
+/// assert_eq!(&3, triple.get_ref_nth(nth::Third));
+///```
+pub trait GetRefNth<Position>
+    where Self: GetNth<Position> {
+    fn get_ref_nth(&self, position: Position) -> &Self::Target;
+}
 
-    /// A variant of [`Get`], reaching four levels deep in the data structure.
-    ///
-    /// [`Get`]: ../trait.Get.html
-    ///
-    /// # Examples
-    ///```
-    ///  # use photonix::*;
-    ///  #[derive(Get)]
-    ///  pub struct Employee { pub name: String, pub company: Company }
-    ///
-    ///  #[derive(Get)]
-    ///  pub struct Company { pub name: String, pub address: Address }
-    ///
-    ///  #[derive(Get)]
-    ///  pub struct Address { pub city: String, pub street: Street }
-    ///
-    ///  #[derive(Get)]
-    ///  pub struct Street { pub number: u16, pub name: String }
-    ///
-    /// let john_doe = Employee {
-    ///     name: String::from("John Doe"),
-    ///     company: Company {
-    ///         name: String::from("Acme Corporation"),
-    ///         address: Address {
-    ///                 city: String::from("London"),
-    ///                 street: Street {
-    ///                     number: 23,
-    ///                     name: String::from("High street"),
-    ///                 }
-    ///             },
-    ///         }
-    ///     };
-    ///
-    /// //            Level 1   Level 2   Level 3 Level 4     Parent type
-    /// //               |         |        |       |           |
-    /// impl GetFourth<Company, Address, Street, String> for Employee {}
-    ///
-    /// assert_eq!("High street", john_doe.get_fourth().as_str());
-    ///
-    ///```
-    pub trait GetFourth<LevelOne, LevelTwo, LevelThree, LevelFour>
-        where
-            LevelThree: Get<LevelFour>,
-            LevelTwo: Get<LevelThree>,
-            LevelOne: Get<LevelTwo>,
-            Self: Get<LevelOne> + Sized {
-        fn get_fourth(self) -> LevelFour {
-            self.get().get().get().get()
-        }
-    }
+/// Returns a mutable reference to the element at the given position of a tuple (up to 12 elements).
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// let mut triple = (1, 2, 3);
+///
+/// *triple.get_mut_nth(nth::First) += 10;
+///
+/// assert_eq!((11, 2, 3), triple);
+///```
+pub trait GetMutNth<Position>
+    where Self: GetNth<Position> {
+    fn get_mut_nth(&mut self, position: Position) -> &mut Self::Target;
+}
 
-    /// A variant of [`GetRef`], reaching four levels deep in the data structure.
-    ///
-    /// [`GetRef`]: ../trait.GetRef.html
-    ///
-    /// # Examples
-    ///```
-    ///  # use photonix::*;
-    ///  #[derive(GetRef)]
-    ///  pub struct Employee { pub name: String, pub company: Company }
-    ///
-    ///  #[derive(GetRef)]
-    ///  pub struct Company { pub name: String, pub address: Address }
-    ///
-    ///  #[derive(GetRef)]
-    ///  pub struct Address { pub city: String, pub street: Street }
-    ///
-    ///  #[derive(GetRef)]
-    ///  pub struct Street { pub number: u16, pub name: String }
-    ///
-    /// let john_doe = Employee {
+/// Replaces the element at the given position of a tuple (up to 12 elements) with a value of any type. Consumes the original tuple, returns the updated one, the type of which reflects the new element.
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// let pair = (String::from("answer"), 42);
+///
+/// assert_eq!((String::from("answer"), "forty-two"), pair.set_nth(nth::Second, "forty-two"));
+///```
+pub trait SetNth<Position, Value> {
+    type Output;
+
+    fn set_nth(self, position: Position, new_value: Value) -> Self::Output;
+}
+
+/// Updates the element at the given position of a tuple (up to 12 elements) by applying the provided function on it, which may change its type. Consumes the original tuple, returns the updated one.
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// let pair = (String::from("answer"), 42);
+///
+/// assert_eq!((6, 42), pair.modify_nth(nth::First, |s| s.len()));
+///```
+pub trait ModifyNth<Position, Value>
+    where Self: GetNth<Position> {
+    type Output;
+
+    fn modify_nth(self, position: Position, f: impl FnOnce(Self::Target) -> Value) -> Self::Output;
+}
+
+/// Replaces the focused value(s) of type `A` with a value of type `B`, changing the type of the container accordingly (e.g. `Option<A>` becomes `Option<B>`). Consumes the original container, returns the updated one.
+///
+/// This trait does not have a corresponding auto-derive macro. It is implemented for `Option`, `Result` (the `Ok` side), `Vec`, and tuples of a single element type (up to 12 elements). The last two hold several values, so they require `B: Clone`.
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// assert_eq!(Some("ready"), Some(42).set_poly("ready"));
+/// assert_eq!(vec![0.5, 0.5], vec![1, 2].set_poly(0.5));
+/// assert_eq!(("n/a", "n/a"), (1, 2).set_poly("n/a"));
+///```
+pub trait SetPoly<A, B> {
+    type Output;
+
+    fn set_poly(self, new_value: B) -> Self::Output;
+}
+
+/// Updates the focused value(s) of type `A` by applying a function which returns a value of type `B`, changing the type of the container accordingly (e.g. `Wrapper<Raw>` becomes `Wrapper<Parsed>`). Consumes the original container, returns the updated one.
+///
+/// The function is called once for every focused value: at most once for `Option` and `Result`, and once per element for `Vec` and tuples of a single element type (up to 12 elements).
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// #[derive(Debug, PartialEq)]
+/// pub struct Wrapper<T> { pub id: u32, pub payload: T }
+///
+/// impl<A, B> ModifyPoly<A, B> for Wrapper<A> {
+///     type Output = Wrapper<B>;
+///
+///     fn modify_poly(self, mut f: impl FnMut(A) -> B) -> Wrapper<B> {
+///         Wrapper { id: self.id, payload: f(self.payload) }
+///     }
+/// }
+///
+/// let raw = Wrapper { id: 7, payload: "42" };
+///
+/// assert_eq!(Wrapper { id: 7, payload: 42 }, raw.modify_poly(|s: &str| s.parse::<u32>().unwrap()));
+///
+/// assert_eq!(Ok(2.5), Ok::<_, String>(5).modify_poly(|n| n as f64 / 2.0));
+/// assert_eq!(vec!["1", "2"], vec![1, 2].modify_poly(|n| if n == 1 { "1" } else { "2" }));
+///```
+pub trait ModifyPoly<A, B> {
+    type Output;
+
+    fn modify_poly(self, f: impl FnMut(A) -> B) -> Self::Output;
+}
+
+/// The type of `Container` after replacing its `A` value(s) with `B` via [`SetPoly`].
+///
+/// [`SetPoly`]: trait.SetPoly.html
+pub type SetPolyOutput<Container, A, B> = <Container as SetPoly<A, B>>::Output;
+
+/// The type of `Container` after mapping its `A` value(s) to `B` via [`ModifyPoly`].
+///
+/// [`ModifyPoly`]: trait.ModifyPoly.html
+pub type ModifyPolyOutput<Container, A, B> = <Container as ModifyPoly<A, B>>::Output;
+
+/// Returns the value of the field marked by `Tag`, consuming the container. Unlike [`Get`], it can tell apart fields of the same type, because the field is chosen by a zero-sized tag type (passed as an argument) instead of the type of the value.
+///
+/// Tags and the tagged traits can be generated with the [`tagged!`] macro.
+///
+/// [`Get`]: trait.Get.html
+/// [`tagged!`]: ../macro.tagged.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// pub struct Person { pub first_name: String, pub last_name: String }
+///
+/// tagged! {
+///     Person {
+///         first_name: String => FirstName,
+///         last_name: String => LastName,
+///     }
+/// }
+///
+/// let john_doe = || Person { first_name: String::from("John"), last_name: String::from("Doe") };
+///
+/// assert_eq!("Doe", john_doe().get_field(LastName));
+///```
+pub trait GetField<Tag, Value> {
+    fn get_field(self, tag: Tag) -> Value;
+}
+
+/// Returns a reference to the value of the field marked by `Tag`.
+///
+/// Tags and the tagged traits can be generated with the [`tagged!`] macro.
+///
+/// [`tagged!`]: ../macro.tagged.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// pub struct Person { pub first_name: String, pub last_name: String }
+///
+/// tagged! {
+///     Person {
+///         first_name: String => FirstName,
+///         last_name: String => LastName,
+///     }
+/// }
+///
+/// let john_doe = || Person { first_name: String::from("John"), last_name: String::from("Doe") };
+///
+/// assert_eq!("John", john_doe().get_ref_field(FirstName).as_str());
+///```
+pub trait GetRefField<Tag, Value> {
+    fn get_ref_field(&self, tag: Tag) -> &Value;
+}
+
+/// Returns a mutable reference to the value of the field marked by `Tag`.
+///
+/// Tags and the tagged traits can be generated with the [`tagged!`] macro.
+///
+/// [`tagged!`]: ../macro.tagged.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// pub struct Person { pub first_name: String, pub last_name: String }
+///
+/// tagged! {
+///     Person {
+///         first_name: String => FirstName,
+///         last_name: String => LastName,
+///     }
+/// }
+///
+/// let john_doe = || Person { first_name: String::from("John"), last_name: String::from("Doe") };
+///
+/// let mut john = john_doe();
+/// john.get_mut_field(FirstName).push_str("ny");
+///
+/// assert_eq!(("Johnny", "Doe"), (john.first_name.as_str(), john.last_name.as_str()));
+///```
+pub trait GetMutField<Tag, Value> {
+    fn get_mut_field(&mut self, tag: Tag) -> &mut Value;
+}
+
+/// Updates the value of the field marked by `Tag` with the provided value. Consumes the original container, returns the updated one.
+///
+/// Tags and the tagged traits can be generated with the [`tagged!`] macro.
+///
+/// [`tagged!`]: ../macro.tagged.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// pub struct Person { pub first_name: String, pub last_name: String }
+///
+/// tagged! {
+///     Person {
+///         first_name: String => FirstName,
+///         last_name: String => LastName,
+///     }
+/// }
+///
+/// let john_doe = || Person { first_name: String::from("John"), last_name: String::from("Doe") };
+///
+/// let jane = john_doe().set_field(FirstName, String::from("Jane"));
+///
+/// assert_eq!(("Jane", "Doe"), (jane.first_name.as_str(), jane.last_name.as_str()));
+///```
+pub trait SetField<Tag, Value> {
+    fn set_field(self, tag: Tag, new_value: Value) -> Self;
+}
+
+/// Updates the value of the field marked by `Tag` by applying the provided function on it. Consumes the original container, returns the updated one.
+///
+/// Tags and the tagged traits can be generated with the [`tagged!`] macro.
+///
+/// [`tagged!`]: ../macro.tagged.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// pub struct Person { pub first_name: String, pub last_name: String }
+///
+/// tagged! {
+///     Person {
+///         first_name: String => FirstName,
+///         last_name: String => LastName,
+///     }
+/// }
+///
+/// let john_doe = || Person { first_name: String::from("John"), last_name: String::from("Doe") };
+///
+/// let shouting = john_doe().modify_field(LastName, |name| name.to_uppercase());
+///
+/// assert_eq!(("John", "DOE"), (shouting.first_name.as_str(), shouting.last_name.as_str()));
+///```
+pub trait ModifyField<Tag, Value> {
+    fn modify_field(self, tag: Tag, f: impl FnOnce(Value) -> Value) -> Self;
+}
+
+/// Marks a [`GetAffine`] hop that goes through [`Get`].
+///
+/// [`GetAffine`]: trait.GetAffine.html
+/// [`Get`]: trait.Get.html
+pub struct ViaGet;
+
+/// Marks a [`GetAffine`] hop that goes through [`GetRef`] (the value gets cloned).
+///
+/// [`GetAffine`]: trait.GetAffine.html
+/// [`GetRef`]: trait.GetRef.html
+pub struct ViaGetRef;
+
+/// Marks a [`GetAffine`] hop that goes through [`GetOption`].
+///
+/// [`GetAffine`]: trait.GetAffine.html
+/// [`GetOption`]: trait.GetOption.html
+pub struct ViaGetOption;
+
+/// Retrieves the value of a field that may or may not be present, no matter if the container is a struct (lens) or an enum (prism). Consumes the container, returns the value wrapped in an `Option`.
+///
+/// There is no need to implement this trait: every container gets it for free via [`Get`] (marked by [`ViaGet`]), [`GetRef`] for `Clone` values ([`ViaGetRef`]), and [`GetOption`] ([`ViaGetOption`]). The marker type lets lens and prism levels be mixed in the affine [`composites`] (see also the [`zoom_affine!`] macro).
+///
+/// [`Get`]: trait.Get.html
+/// [`GetRef`]: trait.GetRef.html
+/// [`GetOption`]: trait.GetOption.html
+/// [`ViaGet`]: struct.ViaGet.html
+/// [`ViaGetRef`]: struct.ViaGetRef.html
+/// [`ViaGetOption`]: struct.ViaGetOption.html
+/// [`composites`]: composites/index.html
+/// [`zoom_affine!`]: ../macro.zoom_affine.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// #[derive(Get)]
+/// pub struct Card { pub last4: u16, pub holder: String }
+///
+/// #[derive(GetOption)]
+/// pub enum Payment { Card(Card), Cash(u32) }
+///
+/// let card = || Card { last4: 1234, holder: String::from("John Doe") };
+///
+/// let last4: Option<u16> = card().get_affine();
+///
+/// assert_eq!(Some(1234), last4);
+///
+/// let cash: Option<u32> = Payment::Cash(20).get_affine();
+///
+/// assert_eq!(Some(20), cash);
+///
+/// let missing_cash: Option<u32> = Payment::Card(card()).get_affine();
+///
+/// assert_eq!(None, missing_cash);
+///```
+pub trait GetAffine<Value, Via> {
+    fn get_affine(self) -> Option<Value>;
+}
+
+/// A type-level path leading into a nested data structure: `Head` is the type at the next level, `Tail` is the rest of the path (another `Path`, or [`End`] after the last level).
+///
+/// Paths are never instantiated, they only select the implementations of [`GetPath`], [`GetRefPath`], [`GetMutPath`], [`SetPath`], and [`ModifyPath`]. The [`path!`] macro spells them out conveniently.
+///
+/// [`End`]: struct.End.html
+/// [`GetPath`]: trait.GetPath.html
+/// [`GetRefPath`]: trait.GetRefPath.html
+/// [`GetMutPath`]: trait.GetMutPath.html
+/// [`SetPath`]: trait.SetPath.html
+/// [`ModifyPath`]: trait.ModifyPath.html
+/// [`path!`]: ../macro.path.html
+pub struct Path<Head, Tail>(PhantomData<(Head, Tail)>);
+
+/// Marks the end of a type-level [`Path`].
+///
+/// [`Path`]: struct.Path.html
+pub struct End;
+
+/// Concatenates two type-level paths (used for composing optics, see [`Lens::then`]).
+///
+/// [`Lens::then`]: ../optics/struct.Lens.html#method.then
+pub trait Append<Suffix> {
+    type Output;
+}
+
+/// Returns the target field at the end of a type-level [`Path`] by value, consumes the container (unless it implements `Copy`).
+///
+/// Implemented for every container that has a chain of [`Get`] implementations along the path. Usually called via [`AtPath::get_at`].
+///
+/// [`Path`]: struct.Path.html
+/// [`Get`]: trait.Get.html
+/// [`AtPath::get_at`]: trait.AtPath.html#method.get_at
+pub trait GetPath<P> {
+    type Target;
+
+    fn get_path(self) -> Self::Target;
+}
+
+/// Returns a reference to the target field at the end of a type-level [`Path`].
+///
+/// Implemented for every container that has a chain of [`GetRef`] implementations along the path. Usually called via [`AtPath::get_ref_at`].
+///
+/// [`Path`]: struct.Path.html
+/// [`GetRef`]: trait.GetRef.html
+/// [`AtPath::get_ref_at`]: trait.AtPath.html#method.get_ref_at
+pub trait GetRefPath<'a, P> {
+    type Target;
+
+    fn get_ref_path(&'a self) -> &'a Self::Target;
+}
+
+/// Returns a mutable reference to the target field at the end of a type-level [`Path`].
+///
+/// Implemented for every container that has a chain of [`GetMut`] implementations along the path. Usually called via [`AtPath::get_mut_at`].
+///
+/// [`Path`]: struct.Path.html
+/// [`GetMut`]: trait.GetMut.html
+/// [`AtPath::get_mut_at`]: trait.AtPath.html#method.get_mut_at
+pub trait GetMutPath<'a, P> {
+    type Target;
+
+    fn get_mut_path(&'a mut self) -> &'a mut Self::Target;
+}
+
+/// Updates the target field at the end of a type-level [`Path`] with the provided value. Consumes the original container (unless it implements `Copy`), returns the updated one.
+///
+/// Implemented for every container that has a chain of [`Modify`] implementations along the path, with [`Set`] at the last level (just like [`SetSecond`]). Usually called via [`AtPath::set_at`].
+///
+/// [`Path`]: struct.Path.html
+/// [`Modify`]: trait.Modify.html
+/// [`Set`]: trait.Set.html
+/// [`SetSecond`]: composites/trait.SetSecond.html
+/// [`AtPath::set_at`]: trait.AtPath.html#method.set_at
+pub trait SetPath<P>
+    where Self: Sized {
+    type Target;
+
+    fn set_path(self, new_value: Self::Target) -> Self;
+}
+
+/// Updates the target field at the end of a type-level [`Path`] by applying the provided function on it. Consumes the original container (unless it implements `Copy`), returns the updated one.
+///
+/// Implemented for every container that has a chain of [`Modify`] implementations along the path. Usually called via [`AtPath::modify_at`].
+///
+/// [`Path`]: struct.Path.html
+/// [`Modify`]: trait.Modify.html
+/// [`AtPath::modify_at`]: trait.AtPath.html#method.modify_at
+pub trait ModifyPath<P>
+    where Self: Sized {
+    type Target;
+
+    fn modify_path(self, f: impl FnOnce(Self::Target) -> Self::Target) -> Self;
+}
+
+/// Returns the target field at the end of a type-level [`Path`] wrapped in an `Option`, consumes the container.
+///
+/// Implemented for every container that has a chain of [`GetOption`] implementations along the path.
+///
+/// [`Path`]: struct.Path.html
+/// [`GetOption`]: trait.GetOption.html
+pub trait GetOptionPath<P> {
+    type Target;
+
+    fn get_option_path(self) -> Option<Self::Target>;
+}
+
+/// Creates a container from the value at the end of a type-level [`Path`].
+///
+/// Implemented for every container that has a chain of [`ReverseGet`] implementations along the path.
+///
+/// [`Path`]: struct.Path.html
+/// [`ReverseGet`]: trait.ReverseGet.html
+pub trait ReverseGetPath<P> {
+    type Target;
+
+    fn reverse_get_path(value: Self::Target) -> Self;
+}
+
+/// Updates the target field at the end of a type-level [`Path`] with the provided value, returns the updated container wrapped in an `Option`.
+///
+/// Implemented for every container that has a chain of [`GetOption`] and [`ReverseGet`] implementations along the path, with [`SetOption`] at the last level (just like [`SetOptionSecond`]).
+///
+/// [`Path`]: struct.Path.html
+/// [`GetOption`]: trait.GetOption.html
+/// [`ReverseGet`]: trait.ReverseGet.html
+/// [`SetOption`]: trait.SetOption.html
+/// [`SetOptionSecond`]: composites/trait.SetOptionSecond.html
+pub trait SetOptionPath<P>
+    where Self: Sized {
+    type Target;
+
+    fn set_option_path(self, new_value: Self::Target) -> Option<Self>;
+}
+
+/// Updates the target field at the end of a type-level [`Path`] by applying the provided function on it, returns the updated container wrapped in an `Option`.
+///
+/// Implemented for every container that has a chain of [`GetOption`] and [`ReverseGet`] implementations along the path, with [`ModifyOption`] at the last level (just like [`ModifyOptionSecond`]).
+///
+/// [`Path`]: struct.Path.html
+/// [`GetOption`]: trait.GetOption.html
+/// [`ReverseGet`]: trait.ReverseGet.html
+/// [`ModifyOption`]: trait.ModifyOption.html
+/// [`ModifyOptionSecond`]: composites/trait.ModifyOptionSecond.html
+pub trait ModifyOptionPath<P>
+    where Self: Sized {
+    type Target;
+
+    fn modify_option_path(self, f: impl FnOnce(Self::Target) -> Self::Target) -> Option<Self>;
+}
+
+/// Returns references to all the targets at the end of a type-level [`Path`].
+///
+/// Implemented for every container that has a chain of [`GetAll`] implementations along the path.
+///
+/// [`Path`]: struct.Path.html
+/// [`GetAll`]: trait.GetAll.html
+pub trait GetAllPath<'a, P> {
+    type Target;
+
+    fn get_all_path(&'a self) -> Box<dyn Iterator<Item = &'a Self::Target> + 'a>;
+}
+
+/// Updates all the targets at the end of a type-level [`Path`] by applying the provided function on each of them.
+///
+/// Implemented for every container that has a chain of [`ModifyAll`] implementations along the path.
+///
+/// [`Path`]: struct.Path.html
+/// [`ModifyAll`]: trait.ModifyAll.html
+pub trait ModifyAllPath<P>
+    where Self: Sized {
+    type Target;
+
+    fn modify_all_path(self, f: impl FnMut(Self::Target) -> Self::Target) -> Self;
+}
+
+/// Reaches arbitrarily deep in the data structure along a type-level [`Path`], with no need for composite implementations. Every type gets these methods for free, the path only has to be valid for the method at hand.
+///
+/// [`Path`]: struct.Path.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// #[derive(Get, GetRef, Set, Modify)]
+/// pub struct Config { pub name: String, pub server: Server }
+///
+/// #[derive(Get, GetRef, Set, Modify)]
+/// pub struct Server { pub port: u16, pub tls: Tls }
+///
+/// #[derive(Get, GetRef, Set, Modify)]
+/// pub struct Tls { pub enabled: bool, pub cert: Cert }
+///
+/// #[derive(Get, GetRef, Set, Modify)]
+/// pub struct Cert { pub path: String, pub issuer: Issuer }
+///
+/// #[derive(Get, GetRef, Set, Modify)]
+/// pub struct Issuer { pub name: String, pub contact: Contact }
+///
+/// #[derive(Get, GetRef, Set, Modify)]
+/// pub struct Contact { pub email: String, pub phone: u64 }
+///
+/// let config = Config {
+///     name: String::from("prod"),
+///     server: Server {
+///         port: 443,
+///         tls: Tls {
+///             enabled: true,
+///             cert: Cert {
+///                 path: String::from("/etc/cert.pem"),
+///                 issuer: Issuer {
+///                     name: String::from("Acme CA"),
+///                     contact: Contact { email: String::from("ca@acme.com"), phone: 5550100 },
+///                 },
+///             },
+///         },
+///     },
+/// };
+///
+/// // Six levels deep, beyond the named composites
+///
+/// type IssuerEmail = path!(Server, Tls, Cert, Issuer, Contact, String);
+///
+/// assert_eq!("ca@acme.com", config.get_ref_at::<IssuerEmail>().as_str());
+///
+/// let config = config.set_at::<IssuerEmail>(String::from("pki@acme.com"));
+/// let config = config.modify_at::<path!(Server, Tls, Cert, Issuer, Contact, u64)>(|phone| phone + 1);
+///
+/// assert_eq!(5550101, config.get_ref_at::<path!(Server, Tls, Cert, Issuer, Contact, u64)>().clone());
+/// assert_eq!("pki@acme.com", config.get_at::<IssuerEmail>().as_str());
+///
+///```
+pub trait AtPath {
+    fn get_at<P>(self) -> <Self as GetPath<P>>::Target
+        where Self: GetPath<P> + Sized {
+        self.get_path()
+    }
+
+    fn get_ref_at<'a, P>(&'a self) -> &'a <Self as GetRefPath<'a, P>>::Target
+        where Self: GetRefPath<'a, P> {
+        self.get_ref_path()
+    }
+
+    fn get_mut_at<'a, P>(&'a mut self) -> &'a mut <Self as GetMutPath<'a, P>>::Target
+        where Self: GetMutPath<'a, P> {
+        self.get_mut_path()
+    }
+
+    fn set_at<P>(self, new_value: <Self as SetPath<P>>::Target) -> Self
+        where Self: SetPath<P> {
+        self.set_path(new_value)
+    }
+
+    fn modify_at<P>(self, f: impl FnOnce(<Self as ModifyPath<P>>::Target) -> <Self as ModifyPath<P>>::Target) -> Self
+        where Self: ModifyPath<P> {
+        self.modify_path(f)
+    }
+}
+
+pub mod composites {
+    use super::*;
+
+    /// A variant of [`Get`], reaching two levels deep in the data structure.
+    ///
+    /// [`Get`]: ../trait.Get.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Get)]
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// #[derive(Get)]
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// pub struct Address(String);
+    ///
+    /// //             Level 1   Level 2    Parent type
+    /// //               |          |           |
+    /// impl GetSecond<Company, String> for Employee {}
+    ///
+    /// let john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address(String::from("4 Foo Road, Bar City")),
+    ///         }
+    ///     };
+    ///
+    /// let company_name = john_doe.get_second();
+    ///
+    /// assert_eq!("Acme Corporation", company_name.as_str());
+    ///
+    ///```
+    pub trait GetSecond<LevelOne, LevelTwo>
+        where
+            LevelOne: Get<LevelTwo>,
+            Self: Get<LevelOne> + Sized {
+        fn get_second(self) -> LevelTwo {
+            self.get().get()
+        }
+    }
+
+    /// A variant of [`GetRef`], reaching two levels deep in the data structure.
+    ///
+    /// [`GetRef`]: ../trait.GetRef.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(GetRef)]
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// #[derive(GetRef)]
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// pub struct Address(String);
+    ///
+    /// //                      Level 1     Level 2     Parent type
+    /// //                          |          |            |
+    /// impl<'a> GetRefSecond<'a, Company, String> for Employee {}
+    ///
+    /// let john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address(String::from("4 Foo Road, Bar City")),
+    ///         }
+    ///     };
+    ///
+    /// let company_name = john_doe.get_ref_second();
+    ///
+    /// assert_eq!("Acme Corporation", company_name.as_str());
+    ///
+    ///```
+    pub trait GetRefSecond<'a, LevelOne, LevelTwo>
+        where
+            LevelOne: GetRef<LevelTwo> + 'a,
+            Self: GetRef<LevelOne> + 'a {
+        fn get_ref_second(&'a self) -> &'a LevelTwo {
+            self.get_ref().get_ref()
+        }
+    }
+
+    /// A variant of [`GetMut`], reaching two levels deep in the data structure.
+    ///
+    /// [`GetMut`]: ../trait.GetMut.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// pub struct Company { pub name: String, pub headcount: u32 }
+    ///
+    /// impl GetMut<Company> for Employee {
+    ///     fn get_mut(&mut self) -> &mut Company { &mut self.company }
+    /// }
+    ///
+    /// impl GetMut<u32> for Company {
+    ///     fn get_mut(&mut self) -> &mut u32 { &mut self.headcount }
+    /// }
+    ///
+    /// //                      Level 1   Level 2   Parent type
+    /// //                          |        |          |
+    /// impl<'a> GetMutSecond<'a, Company, u32> for Employee {}
+    ///
+    /// let mut john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         headcount: 100,
+    ///         }
+    ///     };
+    ///
+    /// *john_doe.get_mut_second() += 1;
+    ///
+    /// assert_eq!(101, john_doe.company.headcount);
+    ///
+    ///```
+    pub trait GetMutSecond<'a, LevelOne, LevelTwo>
+        where
+            LevelOne: GetMut<LevelTwo> + 'a,
+            Self: GetMut<LevelOne> + 'a {
+        fn get_mut_second(&'a mut self) -> &'a mut LevelTwo {
+            self.get_mut().get_mut()
+        }
+    }
+
+    /// A variant of [`Set`], reaching two levels deep in the data structure.
+    ///
+    /// [`Set`]: ../trait.Set.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Get, Modify)]
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// #[derive(Get, Set)]
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// pub struct Address(String);
+    ///
+    /// //            Level 1    Level 2     Parent type
+    /// //                |        |           |
+    /// impl SetSecond<Company, String> for Employee {}
+    /// impl GetSecond<Company, String> for Employee {}
+    ///
+    /// let john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address(String::from("4 Foo Road, Bar City")),
+    ///         }
+    ///     };
+    ///
+    /// let john_doe_new = john_doe.set_second(String::from("Evil Corp"));
+    ///
+    /// assert_eq!("Evil Corp", john_doe_new.get_second().as_str());
+    ///
+    ///```
+    pub trait SetSecond<LevelOne, LevelTwo>
+        where
+            LevelOne: Set<LevelTwo>,
+            Self: Modify<LevelOne> + Sized {
+        fn set_second(self, new_value: LevelTwo) -> Self {
+            self.modify(|level_one| level_one.set(new_value))
+        }
+    }
+
+    /// A variant of [`Modify`], reaching two levels deep in the data structure.
+    ///
+    /// [`Modify`]: ../trait.Modify.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Get, Modify)]
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// #[derive(Get, Modify)]
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// pub struct Address(String);
+    ///
+    /// //            Level 1    Level 2     Parent type
+    /// //                |        |           |
+    /// impl ModifySecond<Company, String> for Employee {}
+    /// impl GetSecond<Company, String> for Employee {}
+    ///
+    /// let john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address(String::from("4 Foo Road, Bar City")),
+    ///         }
+    ///     };
+    ///
+    /// let john_doe_new = john_doe.modify_second(|name| name.replace("Corporation", "Inc"));
+    ///
+    /// assert_eq!("Acme Inc", john_doe_new.get_second().as_str());
+    ///
+    ///```
+    pub trait ModifySecond<LevelOne, LevelTwo>
+        where
+            LevelOne: Modify<LevelTwo>,
+            Self: Modify<LevelOne> + Sized {
+        fn modify_second(self, f: impl FnOnce(LevelTwo) -> LevelTwo) -> Self {
+            self.modify(|level_one| level_one.modify(f))
+        }
+    }
+
+    /// A variant of [`SetInPlace`], reaching two levels deep in the data structure.
+    ///
+    /// [`SetInPlace`]: ../trait.SetInPlace.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// pub struct Company { pub name: String, pub headcount: u32 }
+    ///
+    /// impl GetMut<Company> for Employee {
+    ///     fn get_mut(&mut self) -> &mut Company { &mut self.company }
+    /// }
+    ///
+    /// impl GetMut<u32> for Company {
+    ///     fn get_mut(&mut self) -> &mut u32 { &mut self.headcount }
+    /// }
+    ///
+    /// impl SetInPlaceSecond<Company, u32> for Employee {}
+    ///
+    /// let mut john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         headcount: 100,
+    ///         }
+    ///     };
+    ///
+    /// john_doe.set_in_place_second(250);
+    ///
+    /// assert_eq!(250, john_doe.company.headcount);
+    ///
+    ///```
+    pub trait SetInPlaceSecond<LevelOne, LevelTwo>
+        where
+            LevelOne: SetInPlace<LevelTwo>,
+            Self: ModifyInPlace<LevelOne> {
+        fn set_in_place_second(&mut self, new_value: LevelTwo) {
+            self.modify_in_place(|level_one| level_one.set_in_place(new_value))
+        }
+    }
+
+    /// A variant of [`ModifyInPlace`], reaching two levels deep in the data structure.
+    ///
+    /// [`ModifyInPlace`]: ../trait.ModifyInPlace.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// pub struct Company { pub name: String, pub headcount: u32 }
+    ///
+    /// impl GetMut<Company> for Employee {
+    ///     fn get_mut(&mut self) -> &mut Company { &mut self.company }
+    /// }
+    ///
+    /// impl GetMut<u32> for Company {
+    ///     fn get_mut(&mut self) -> &mut u32 { &mut self.headcount }
+    /// }
+    ///
+    /// impl ModifyInPlaceSecond<Company, u32> for Employee {}
+    ///
+    /// let mut john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         headcount: 100,
+    ///         }
+    ///     };
+    ///
+    /// john_doe.modify_in_place_second(|headcount| *headcount += 1);
+    ///
+    /// assert_eq!(101, john_doe.company.headcount);
+    ///
+    ///```
+    pub trait ModifyInPlaceSecond<LevelOne, LevelTwo>
+        where
+            LevelOne: ModifyInPlace<LevelTwo>,
+            Self: ModifyInPlace<LevelOne> {
+        fn modify_in_place_second(&mut self, f: impl FnOnce(&mut LevelTwo)) {
+            self.modify_in_place(|level_one| level_one.modify_in_place(f))
+        }
+    }
+
+    /// A variant of [`TryModify`], reaching two levels deep in the data structure.
+    ///
+    /// [`TryModify`]: ../trait.TryModify.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// pub struct Company { pub name: String, pub headcount: u32 }
+    ///
+    /// impl TryModify<Company> for Employee {
+    ///     fn try_modify<E>(self, f: impl FnOnce(Company) -> Result<Company, E>) -> Result<Self, E> {
+    ///         Ok(Employee { company: f(self.company)?, ..self })
+    ///     }
+    /// }
+    ///
+    /// impl TryModify<u32> for Company {
+    ///     fn try_modify<E>(self, f: impl FnOnce(u32) -> Result<u32, E>) -> Result<Self, E> {
+    ///         Ok(Company { headcount: f(self.headcount)?, ..self })
+    ///     }
+    /// }
+    ///
+    /// impl TryModifySecond<Company, u32> for Employee {}
+    ///
+    /// let john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         headcount: 100,
+    ///         }
+    ///     };
+    ///
+    /// let result = john_doe.try_modify_second(|headcount: u32| headcount.checked_add(1).ok_or("overflow"));
+    ///
+    /// assert_eq!(Ok(101), result.map(|e| e.company.headcount));
+    ///
+    ///```
+    pub trait TryModifySecond<LevelOne, LevelTwo>
+        where
+            LevelOne: TryModify<LevelTwo>,
+            Self: TryModify<LevelOne> {
+        fn try_modify_second<E>(self, f: impl FnOnce(LevelTwo) -> Result<LevelTwo, E>) -> Result<Self, E> {
+            self.try_modify(|level_one| level_one.try_modify(f))
+        }
+    }
+
+    /// A variant of [`Get`], reaching three levels deep in the data structure.
+    ///
+    /// [`Get`]: ../trait.Get.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Get)]
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// #[derive(Get)]
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// #[derive(Get)]
+    /// pub struct Address(String);
+    ///
+    /// //            Level 1    Level 2  Level 3   Parent type
+    /// //                |        |        |           |
+    /// impl GetThird<Company, Address, String> for Employee {}
+    ///
+    /// let john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address(String::from("4 Foo Road, Bar City")),
+    ///         }
+    ///     };
+    ///
+    /// let company_address = john_doe.get_third();
+    ///
+    /// assert_eq!("4 Foo Road, Bar City", company_address.as_str());
+    ///
+    ///```
+    pub trait GetThird<LevelOne, LevelTwo, LevelThree>
+        where
+            LevelTwo: Get<LevelThree>,
+            LevelOne: Get<LevelTwo>,
+            Self: Get<LevelOne> + Sized {
+        fn get_third(self) -> LevelThree {
+            self.get().get().get()
+        }
+    }
+
+    /// A variant of [`GetRef`], reaching three levels deep in the data structure.
+    ///
+    /// [`GetRef`]: ../trait.GetRef.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(GetRef)]
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// #[derive(GetRef)]
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// #[derive(GetRef)]
+    /// pub struct Address(String);
+    ///
+    /// //                      Level 1    Level 2  Level 3   Parent type
+    /// //                          |        |        |           |
+    /// impl<'a> GetRefThird<'a, Company, Address, String> for Employee {}
+    ///
+    /// let john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address(String::from("4 Foo Road, Bar City")),
+    ///         }
+    ///     };
+    ///
+    /// let company_address = john_doe.get_ref_third();
+    ///
+    /// assert_eq!("4 Foo Road, Bar City", company_address.as_str());
+    ///
+    ///```
+    pub trait GetRefThird<'a, LevelOne, LevelTwo, LevelThree>
+        where
+            LevelTwo: GetRef<LevelThree> + 'a,
+            LevelOne: GetRef<LevelTwo> + 'a,
+            Self: GetRef<LevelOne> + 'a {
+        fn get_ref_third(&'a self) -> &'a LevelThree {
+            self.get_ref().get_ref().get_ref()
+        }
+    }
+
+    /// A variant of [`GetMut`], reaching three levels deep in the data structure.
+    ///
+    /// [`GetMut`]: ../trait.GetMut.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// pub struct Address { pub city: String }
+    ///
+    /// impl GetMut<Company> for Employee {
+    ///     fn get_mut(&mut self) -> &mut Company { &mut self.company }
+    /// }
+    ///
+    /// impl GetMut<Address> for Company {
+    ///     fn get_mut(&mut self) -> &mut Address { &mut self.address }
+    /// }
+    ///
+    /// impl GetMut<String> for Address {
+    ///     fn get_mut(&mut self) -> &mut String { &mut self.city }
+    /// }
+    ///
+    /// //                      Level 1    Level 2  Level 3   Parent type
+    /// //                          |        |        |           |
+    /// impl<'a> GetMutThird<'a, Company, Address, String> for Employee {}
+    ///
+    /// let mut john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address { city: String::from("Bar City") },
+    ///         }
+    ///     };
+    ///
+    /// john_doe.get_mut_third().push_str(" Heights");
+    ///
+    /// assert_eq!("Bar City Heights", john_doe.company.address.city.as_str());
+    ///
+    ///```
+    pub trait GetMutThird<'a, LevelOne, LevelTwo, LevelThree>
+        where
+            LevelTwo: GetMut<LevelThree> + 'a,
+            LevelOne: GetMut<LevelTwo> + 'a,
+            Self: GetMut<LevelOne> + 'a {
+        fn get_mut_third(&'a mut self) -> &'a mut LevelThree {
+            self.get_mut().get_mut().get_mut()
+        }
+    }
+
+    /// A variant of [`Set`], reaching three levels deep in the data structure.
+    ///
+    /// [`Set`]: ../trait.Set.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Get, Modify)]
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// #[derive(Get, Modify)]
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// #[derive(Get, Set)]
+    /// pub struct Address(String);
+    ///
+    /// //            Level 1    Level 2  Level 3   Parent type
+    /// //                |        |        |           |
+    /// impl SetThird<Company, Address, String> for Employee {}
+    /// impl GetThird<Company, Address, String> for Employee {}
+    ///
+    /// let john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address(String::from("4 Foo Road, Bar City")),
+    ///         }
+    ///     };
+    ///
+    /// let john_doe_new = john_doe.set_third(String::from("3 Baz Road, Qux City"));
+    ///
+    /// assert_eq!("3 Baz Road, Qux City", john_doe_new.get_third().as_str());
+    ///
+    ///```
+    pub trait SetThird<LevelOne, LevelTwo, LevelThree>
+        where
+            LevelTwo: Set<LevelThree>,
+            LevelOne: Modify<LevelTwo>,
+            Self: Modify<LevelOne> + Sized {
+        fn set_third(self, new_value: LevelThree) -> Self {
+            self.modify(
+                |level_one| level_one.modify(
+                    |level_two| level_two.set(new_value)
+                )
+            )
+        }
+    }
+
+    /// A variant of [`Modify`], reaching three levels deep in the data structure.
+    ///
+    /// [`Modify`]: ../trait.Modify.html
+    ///
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Get, Modify)]
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// #[derive(Get, Modify)]
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// #[derive(Get, Modify)]
+    /// pub struct Address(String);
+    ///
+    /// //            Level 1    Level 2  Level 3   Parent type
+    /// //                |        |        |           |
+    /// impl ModifyThird<Company, Address, String> for Employee {}
+    /// impl GetThird<Company, Address, String> for Employee {}
+    ///
+    /// let john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address(String::from("4 Foo Road, Bar City")),
+    ///         }
+    ///     };
+    ///
+    /// let john_doe_new = john_doe.modify_third(|address| address.replace("4", "5"));
+    ///
+    /// assert_eq!("5 Foo Road, Bar City", john_doe_new.get_third().as_str());
+    ///
+    ///```
+    pub trait ModifyThird<LevelOne, LevelTwo, LevelThree>
+        where
+            LevelTwo: Modify<LevelThree>,
+            LevelOne: Modify<LevelTwo>,
+            Self: Modify<LevelOne> + Sized {
+        fn modify_third(self, f: impl FnOnce(LevelThree) -> LevelThree) -> Self {
+            self.modify(
+                |level_one| level_one.modify(
+                    |level_two| level_two.modify(f)
+                )
+            )
+        }
+    }
+
+    /// A variant of [`SetInPlace`], reaching three levels deep in the data structure.
+    ///
+    /// [`SetInPlace`]: ../trait.SetInPlace.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// pub struct Address { pub city: String }
+    ///
+    /// impl GetMut<Company> for Employee {
+    ///     fn get_mut(&mut self) -> &mut Company { &mut self.company }
+    /// }
+    ///
+    /// impl GetMut<Address> for Company {
+    ///     fn get_mut(&mut self) -> &mut Address { &mut self.address }
+    /// }
+    ///
+    /// impl GetMut<String> for Address {
+    ///     fn get_mut(&mut self) -> &mut String { &mut self.city }
+    /// }
+    ///
+    /// impl SetInPlaceThird<Company, Address, String> for Employee {}
+    ///
+    /// let mut john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address { city: String::from("Bar City") },
+    ///         }
+    ///     };
+    ///
+    /// john_doe.set_in_place_third(String::from("Qux City"));
+    ///
+    /// assert_eq!("Qux City", john_doe.company.address.city.as_str());
+    ///
+    ///```
+    pub trait SetInPlaceThird<LevelOne, LevelTwo, LevelThree>
+        where
+            LevelTwo: SetInPlace<LevelThree>,
+            LevelOne: ModifyInPlace<LevelTwo>,
+            Self: ModifyInPlace<LevelOne> {
+        fn set_in_place_third(&mut self, new_value: LevelThree) {
+            self.modify_in_place(
+                |level_one| level_one.modify_in_place(
+                    |level_two| level_two.set_in_place(new_value)
+                )
+            )
+        }
+    }
+
+    /// A variant of [`ModifyInPlace`], reaching three levels deep in the data structure.
+    ///
+    /// [`ModifyInPlace`]: ../trait.ModifyInPlace.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// pub struct Address { pub city: String }
+    ///
+    /// impl GetMut<Company> for Employee {
+    ///     fn get_mut(&mut self) -> &mut Company { &mut self.company }
+    /// }
+    ///
+    /// impl GetMut<Address> for Company {
+    ///     fn get_mut(&mut self) -> &mut Address { &mut self.address }
+    /// }
+    ///
+    /// impl GetMut<String> for Address {
+    ///     fn get_mut(&mut self) -> &mut String { &mut self.city }
+    /// }
+    ///
+    /// impl ModifyInPlaceThird<Company, Address, String> for Employee {}
+    ///
+    /// let mut john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address { city: String::from("Bar City") },
+    ///         }
+    ///     };
+    ///
+    /// john_doe.modify_in_place_third(|city| city.push_str(" Heights"));
+    ///
+    /// assert_eq!("Bar City Heights", john_doe.company.address.city.as_str());
+    ///
+    ///```
+    pub trait ModifyInPlaceThird<LevelOne, LevelTwo, LevelThree>
+        where
+            LevelTwo: ModifyInPlace<LevelThree>,
+            LevelOne: ModifyInPlace<LevelTwo>,
+            Self: ModifyInPlace<LevelOne> {
+        fn modify_in_place_third(&mut self, f: impl FnOnce(&mut LevelThree)) {
+            self.modify_in_place(
+                |level_one| level_one.modify_in_place(
+                    |level_two| level_two.modify_in_place(f)
+                )
+            )
+        }
+    }
+
+    /// A variant of [`TryModify`], reaching three levels deep in the data structure.
+    ///
+    /// [`TryModify`]: ../trait.TryModify.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// pub struct Address { pub city: String }
+    ///
+    /// impl TryModify<Company> for Employee {
+    ///     fn try_modify<E>(self, f: impl FnOnce(Company) -> Result<Company, E>) -> Result<Self, E> {
+    ///         Ok(Employee { company: f(self.company)?, ..self })
+    ///     }
+    /// }
+    ///
+    /// impl TryModify<Address> for Company {
+    ///     fn try_modify<E>(self, f: impl FnOnce(Address) -> Result<Address, E>) -> Result<Self, E> {
+    ///         Ok(Company { address: f(self.address)?, ..self })
+    ///     }
+    /// }
+    ///
+    /// impl TryModify<String> for Address {
+    ///     fn try_modify<E>(self, f: impl FnOnce(String) -> Result<String, E>) -> Result<Self, E> {
+    ///         Ok(Address { city: f(self.city)?, ..self })
+    ///     }
+    /// }
+    ///
+    /// impl TryModifyThird<Company, Address, String> for Employee {}
+    ///
+    /// let john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address { city: String::from("Bar City") },
+    ///         }
+    ///     };
+    ///
+    /// let result = john_doe.try_modify_third(|city: String| if city.is_empty() { Err("empty city") } else { Ok(city.to_uppercase()) });
+    ///
+    /// assert_eq!(Ok(String::from("BAR CITY")), result.map(|e| e.company.address.city));
+    ///
+    ///```
+    pub trait TryModifyThird<LevelOne, LevelTwo, LevelThree>
+        where
+            LevelTwo: TryModify<LevelThree>,
+            LevelOne: TryModify<LevelTwo>,
+            Self: TryModify<LevelOne> {
+        fn try_modify_third<E>(self, f: impl FnOnce(LevelThree) -> Result<LevelThree, E>) -> Result<Self, E> {
+            self.try_modify(
+                |level_one| level_one.try_modify(
+                    |level_two| level_two.try_modify(f)
+                )
+            )
+        }
+    }
+
+    /// A variant of [`Get`], reaching four levels deep in the data structure.
+    ///
+    /// [`Get`]: ../trait.Get.html
+    ///
+    /// # Examples
+    ///```
+    ///  # use photonix::*;
+    ///  #[derive(Get)]
+    ///  pub struct Employee { pub name: String, pub company: Company }
+    ///
+    ///  #[derive(Get)]
+    ///  pub struct Company { pub name: String, pub address: Address }
+    ///
+    ///  #[derive(Get)]
+    ///  pub struct Address { pub city: String, pub street: Street }
+    ///
+    ///  #[derive(Get)]
+    ///  pub struct Street { pub number: u16, pub name: String }
+    ///
+    /// let john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address {
+    ///                 city: String::from("London"),
+    ///                 street: Street {
+    ///                     number: 23,
+    ///                     name: String::from("High street"),
+    ///                 }
+    ///             },
+    ///         }
+    ///     };
+    ///
+    /// //            Level 1   Level 2   Level 3 Level 4     Parent type
+    /// //               |         |        |       |           |
+    /// impl GetFourth<Company, Address, Street, String> for Employee {}
+    ///
+    /// assert_eq!("High street", john_doe.get_fourth().as_str());
+    ///
+    ///```
+    pub trait GetFourth<LevelOne, LevelTwo, LevelThree, LevelFour>
+        where
+            LevelThree: Get<LevelFour>,
+            LevelTwo: Get<LevelThree>,
+            LevelOne: Get<LevelTwo>,
+            Self: Get<LevelOne> + Sized {
+        fn get_fourth(self) -> LevelFour {
+            self.get().get().get().get()
+        }
+    }
+
+    /// A variant of [`GetRef`], reaching four levels deep in the data structure.
+    ///
+    /// [`GetRef`]: ../trait.GetRef.html
+    ///
+    /// # Examples
+    ///```
+    ///  # use photonix::*;
+    ///  #[derive(GetRef)]
+    ///  pub struct Employee { pub name: String, pub company: Company }
+    ///
+    ///  #[derive(GetRef)]
+    ///  pub struct Company { pub name: String, pub address: Address }
+    ///
+    ///  #[derive(GetRef)]
+    ///  pub struct Address { pub city: String, pub street: Street }
+    ///
+    ///  #[derive(GetRef)]
+    ///  pub struct Street { pub number: u16, pub name: String }
+    ///
+    /// let john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address {
+    ///                 city: String::from("London"),
+    ///                 street: Street {
+    ///                     number: 23,
+    ///                     name: String::from("High street"),
+    ///                 }
+    ///             },
+    ///         }
+    ///     };
+    ///
+    /// //                      Level 1   Level 2   Level 3 Level 4     Parent type
+    /// //                         |         |        |       |           |
+    /// impl<'a> GetRefFourth<'a, Company, Address, Street, String> for Employee {}
+    ///
+    /// assert_eq!("High street", john_doe.get_ref_fourth().as_str());
+    ///
+    ///```
+    pub trait GetRefFourth<'a, LevelOne, LevelTwo, LevelThree, LevelFour>
+        where
+            LevelThree: GetRef<LevelFour> + 'a,
+            LevelTwo: GetRef<LevelThree> + 'a,
+            LevelOne: GetRef<LevelTwo> + 'a,
+            Self: GetRef<LevelOne> + 'a {
+        fn get_ref_fourth(&'a self) -> &'a LevelFour {
+            self.get_ref().get_ref().get_ref().get_ref()
+        }
+    }
+
+    /// A variant of [`GetMut`], reaching four levels deep in the data structure.
+    ///
+    /// [`GetMut`]: ../trait.GetMut.html
+    ///
+    /// # Examples
+    ///```
+    ///  # use photonix::*;
+    ///  pub struct Employee { pub name: String, pub company: Company }
+    ///
+    ///  pub struct Company { pub name: String, pub address: Address }
+    ///
+    ///  pub struct Address { pub city: String, pub street: Street }
+    ///
+    ///  pub struct Street { pub number: u16, pub name: String }
+    ///
+    ///  impl GetMut<Company> for Employee {
+    ///      fn get_mut(&mut self) -> &mut Company { &mut self.company }
+    ///  }
+    ///
+    ///  impl GetMut<Address> for Company {
+    ///      fn get_mut(&mut self) -> &mut Address { &mut self.address }
+    ///  }
+    ///
+    ///  impl GetMut<Street> for Address {
+    ///      fn get_mut(&mut self) -> &mut Street { &mut self.street }
+    ///  }
+    ///
+    ///  impl GetMut<u16> for Street {
+    ///      fn get_mut(&mut self) -> &mut u16 { &mut self.number }
+    ///  }
+    ///
+    /// let mut john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address {
+    ///                 city: String::from("London"),
+    ///                 street: Street {
+    ///                     number: 23,
+    ///                     name: String::from("High street"),
+    ///                 }
+    ///             },
+    ///         }
+    ///     };
+    ///
+    /// //                      Level 1   Level 2   Level 3 Level 4   Parent type
+    /// //                         |         |        |      |            |
+    /// impl<'a> GetMutFourth<'a, Company, Address, Street, u16> for Employee {}
+    ///
+    /// *john_doe.get_mut_fourth() += 1;
+    ///
+    /// assert_eq!(24, john_doe.company.address.street.number);
+    ///
+    ///```
+    pub trait GetMutFourth<'a, LevelOne, LevelTwo, LevelThree, LevelFour>
+        where
+            LevelThree: GetMut<LevelFour> + 'a,
+            LevelTwo: GetMut<LevelThree> + 'a,
+            LevelOne: GetMut<LevelTwo> + 'a,
+            Self: GetMut<LevelOne> + 'a {
+        fn get_mut_fourth(&'a mut self) -> &'a mut LevelFour {
+            self.get_mut().get_mut().get_mut().get_mut()
+        }
+    }
+
+    /// A variant of [`Set`], reaching four levels deep in the data structure.
+    ///
+    /// [`Set`]: ../trait.Set.html
+    ///
+    /// # Examples
+    ///```
+    ///  # use photonix::*;
+    ///  #[derive(Get, Modify)]
+    ///  pub struct Employee { pub name: String, pub company: Company }
+    ///
+    ///  #[derive(Get, Modify)]
+    ///  pub struct Company { pub name: String, pub address: Address }
+    ///
+    ///  #[derive(Get, Modify)]
+    ///  pub struct Address { pub city: String, pub street: Street }
+    ///
+    ///  #[derive(Get, Set)]
+    ///  pub struct Street { pub number: u16, pub name: String }
+    ///
+    /// let john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address {
+    ///                 city: String::from("London"),
+    ///                 street: Street {
+    ///                     number: 23,
+    ///                     name: String::from("High street"),
+    ///                 }
+    ///             },
+    ///         }
+    ///     };
+    ///
+    /// //            Level 1   Level 2   Level 3 Level 4     Parent type
+    /// //               |         |        |       |           |
+    /// impl SetFourth<Company, Address, Street, String> for Employee {}
+    /// impl GetFourth<Company, Address, Street, String> for Employee {}
+    ///
+    /// let john_at_new_street = john_doe.set_fourth(String::from("Low street"));
+    ///
+    /// assert_eq!("Low street", john_at_new_street.get_fourth().as_str());
+    ///
+    ///```
+    pub trait SetFourth<LevelOne, LevelTwo, LevelThree, LevelFour>
+        where
+            LevelThree: Set<LevelFour>,
+            LevelTwo: Modify<LevelThree>,
+            LevelOne: Modify<LevelTwo>,
+            Self: Modify<LevelOne> + Sized {
+        fn set_fourth(self, new_value: LevelFour) -> Self {
+            self.modify(
+                |level_one| level_one.modify(
+                    |level_two| level_two.modify(
+                        |level_three| level_three.set(new_value)
+                    )
+                )
+            )
+        }
+    }
+
+    /// A variant of [`Modify`], reaching four levels deep in the data structure.
+    ///
+    /// [`Modify`]: ../trait.Modify.html
+    ///
+    /// # Examples
+    ///```
+    ///  # use photonix::*;
+    ///  #[derive(Get, Modify)]
+    ///  pub struct Employee { pub name: String, pub company: Company }
+    ///
+    ///  #[derive(Get, Modify)]
+    ///  pub struct Company { pub name: String, pub address: Address }
+    ///
+    ///  #[derive(Get, Modify)]
+    ///  pub struct Address { pub city: String, pub street: Street }
+    ///
+    ///  #[derive(Get, Modify)]
+    ///  pub struct Street { pub number: u16, pub name: String }
+    ///
+    /// let john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address {
+    ///                 city: String::from("London"),
+    ///                 street: Street {
+    ///                     number: 23,
+    ///                     name: String::from("High street"),
+    ///                 }
+    ///             },
+    ///         }
+    ///     };
+    ///
+    /// //              Level 1   Level 2   Level 3 Level 4     Parent type
+    /// //                 |         |        |       |           |
+    /// impl ModifyFourth<Company, Address, Street, String> for Employee {}
+    /// impl GetFourth<Company, Address, Street, String> for Employee {}
+    ///
+    /// let john_at_new_street =
+    ///     john_doe.modify_fourth(|street_name| street_name.replace("street", "road"));
+    ///
+    /// assert_eq!("High road", john_at_new_street.get_fourth().as_str());
+    ///
+    ///```
+    pub trait ModifyFourth<LevelOne, LevelTwo, LevelThree, LevelFour>
+        where
+            LevelThree: Modify<LevelFour>,
+            LevelTwo: Modify<LevelThree>,
+            LevelOne: Modify<LevelTwo>,
+            Self: Modify<LevelOne> + Sized {
+        fn modify_fourth(self, f: impl FnOnce(LevelFour) -> LevelFour) -> Self {
+            self.modify(
+                |level_one| level_one.modify(
+                    |level_two| level_two.modify(
+                        |level_three| level_three.modify(f)
+                    )
+                )
+            )
+        }
+    }
+
+    /// A variant of [`SetInPlace`], reaching four levels deep in the data structure.
+    ///
+    /// [`SetInPlace`]: ../trait.SetInPlace.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// pub struct Address { pub city: String, pub street: Street }
+    ///
+    /// pub struct Street { pub number: u16, pub name: String }
+    ///
+    /// impl GetMut<Company> for Employee {
+    ///     fn get_mut(&mut self) -> &mut Company { &mut self.company }
+    /// }
+    ///
+    /// impl GetMut<Address> for Company {
+    ///     fn get_mut(&mut self) -> &mut Address { &mut self.address }
+    /// }
+    ///
+    /// impl GetMut<Street> for Address {
+    ///     fn get_mut(&mut self) -> &mut Street { &mut self.street }
+    /// }
+    ///
+    /// impl GetMut<u16> for Street {
+    ///     fn get_mut(&mut self) -> &mut u16 { &mut self.number }
+    /// }
+    ///
+    /// impl SetInPlaceFourth<Company, Address, Street, u16> for Employee {}
+    ///
+    /// let mut john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address {
+    ///                 city: String::from("London"),
+    ///                 street: Street {
+    ///                     number: 23,
+    ///                     name: String::from("High street"),
+    ///                 }
+    ///             },
+    ///         }
+    ///     };
+    ///
+    /// john_doe.set_in_place_fourth(666);
+    ///
+    /// assert_eq!(666, john_doe.company.address.street.number);
+    ///
+    ///```
+    pub trait SetInPlaceFourth<LevelOne, LevelTwo, LevelThree, LevelFour>
+        where
+            LevelThree: SetInPlace<LevelFour>,
+            LevelTwo: ModifyInPlace<LevelThree>,
+            LevelOne: ModifyInPlace<LevelTwo>,
+            Self: ModifyInPlace<LevelOne> {
+        fn set_in_place_fourth(&mut self, new_value: LevelFour) {
+            self.modify_in_place(
+                |level_one| level_one.modify_in_place(
+                    |level_two| level_two.modify_in_place(
+                        |level_three| level_three.set_in_place(new_value)
+                    )
+                )
+            )
+        }
+    }
+
+    /// A variant of [`ModifyInPlace`], reaching four levels deep in the data structure.
+    ///
+    /// [`ModifyInPlace`]: ../trait.ModifyInPlace.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// pub struct Address { pub city: String, pub street: Street }
+    ///
+    /// pub struct Street { pub number: u16, pub name: String }
+    ///
+    /// impl GetMut<Company> for Employee {
+    ///     fn get_mut(&mut self) -> &mut Company { &mut self.company }
+    /// }
+    ///
+    /// impl GetMut<Address> for Company {
+    ///     fn get_mut(&mut self) -> &mut Address { &mut self.address }
+    /// }
+    ///
+    /// impl GetMut<Street> for Address {
+    ///     fn get_mut(&mut self) -> &mut Street { &mut self.street }
+    /// }
+    ///
+    /// impl GetMut<u16> for Street {
+    ///     fn get_mut(&mut self) -> &mut u16 { &mut self.number }
+    /// }
+    ///
+    /// impl ModifyInPlaceFourth<Company, Address, Street, u16> for Employee {}
+    ///
+    /// let mut john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address {
+    ///                 city: String::from("London"),
+    ///                 street: Street {
+    ///                     number: 23,
+    ///                     name: String::from("High street"),
+    ///                 }
+    ///             },
+    ///         }
+    ///     };
+    ///
+    /// john_doe.modify_in_place_fourth(|number| *number += 1);
+    ///
+    /// assert_eq!(24, john_doe.company.address.street.number);
+    ///
+    ///```
+    pub trait ModifyInPlaceFourth<LevelOne, LevelTwo, LevelThree, LevelFour>
+        where
+            LevelThree: ModifyInPlace<LevelFour>,
+            LevelTwo: ModifyInPlace<LevelThree>,
+            LevelOne: ModifyInPlace<LevelTwo>,
+            Self: ModifyInPlace<LevelOne> {
+        fn modify_in_place_fourth(&mut self, f: impl FnOnce(&mut LevelFour)) {
+            self.modify_in_place(
+                |level_one| level_one.modify_in_place(
+                    |level_two| level_two.modify_in_place(
+                        |level_three| level_three.modify_in_place(f)
+                    )
+                )
+            )
+        }
+    }
+
+    /// A variant of [`TryModify`], reaching four levels deep in the data structure.
+    ///
+    /// [`TryModify`]: ../trait.TryModify.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// pub struct Address { pub city: String, pub street: Street }
+    ///
+    /// pub struct Street { pub number: u16, pub name: String }
+    ///
+    /// impl TryModify<Company> for Employee {
+    ///     fn try_modify<E>(self, f: impl FnOnce(Company) -> Result<Company, E>) -> Result<Self, E> {
+    ///         Ok(Employee { company: f(self.company)?, ..self })
+    ///     }
+    /// }
+    ///
+    /// impl TryModify<Address> for Company {
+    ///     fn try_modify<E>(self, f: impl FnOnce(Address) -> Result<Address, E>) -> Result<Self, E> {
+    ///         Ok(Company { address: f(self.address)?, ..self })
+    ///     }
+    /// }
+    ///
+    /// impl TryModify<Street> for Address {
+    ///     fn try_modify<E>(self, f: impl FnOnce(Street) -> Result<Street, E>) -> Result<Self, E> {
+    ///         Ok(Address { street: f(self.street)?, ..self })
+    ///     }
+    /// }
+    ///
+    /// impl TryModify<u16> for Street {
+    ///     fn try_modify<E>(self, f: impl FnOnce(u16) -> Result<u16, E>) -> Result<Self, E> {
+    ///         Ok(Street { number: f(self.number)?, ..self })
+    ///     }
+    /// }
+    ///
+    /// impl TryModifyFourth<Company, Address, Street, u16> for Employee {}
+    ///
+    /// let john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address {
+    ///                 city: String::from("London"),
+    ///                 street: Street {
+    ///                     number: 23,
+    ///                     name: String::from("High street"),
+    ///                 }
+    ///             },
+    ///         }
+    ///     };
+    ///
+    /// let result = john_doe.try_modify_fourth(|number: u16| number.checked_sub(30).ok_or("no such number"));
+    ///
+    /// assert_eq!(Err("no such number"), result.map(|e| e.company.address.street.number));
+    ///
+    ///```
+    pub trait TryModifyFourth<LevelOne, LevelTwo, LevelThree, LevelFour>
+        where
+            LevelThree: TryModify<LevelFour>,
+            LevelTwo: TryModify<LevelThree>,
+            LevelOne: TryModify<LevelTwo>,
+            Self: TryModify<LevelOne> {
+        fn try_modify_fourth<E>(self, f: impl FnOnce(LevelFour) -> Result<LevelFour, E>) -> Result<Self, E> {
+            self.try_modify(
+                |level_one| level_one.try_modify(
+                    |level_two| level_two.try_modify(
+                        |level_three| level_three.try_modify(f)
+                    )
+                )
+            )
+        }
+    }
+
+    /// A variant of [`Get`], reaching five levels deep in the data structure.
+    ///
+    /// [`Get`]: ../trait.Get.html
+    ///
+    /// # Examples
+    ///```
+    ///  # use photonix::*;
+    ///  #[derive(Get)]
+    ///  pub struct Employee { pub name: String, pub company: Company }
+    ///
+    ///  #[derive(Get)]
+    ///  pub struct Company { pub name: String, pub address: Address }
+    ///
+    ///  #[derive(Get)]
+    ///  pub struct Address { pub city: String, pub street: Street }
+    ///
+    ///  #[derive(Get)]
+    ///  pub struct Street { pub number: StreetNumber, pub name: String }
+    ///
+    ///  #[derive(Get)]
+    ///  pub struct StreetNumber(u16);
+    ///
+    /// let john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address {
+    ///                 city: String::from("London"),
+    ///                 street: Street {
+    ///                     number: StreetNumber(23),
+    ///                     name: String::from("High street"),
+    ///                 }
+    ///             },
+    ///         }
+    ///     };
+    ///
+    /// //            Level 1   Level 2   Level 3 Level     Level 5   Parent type
+    /// //               |         |        |       |           |         |
+    /// impl GetFifth<Company, Address, Street, StreetNumber, u16> for Employee {}
+    ///
+    /// assert_eq!(23, john_doe.get_fifth());
+    ///
+    ///```
+    pub trait GetFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        where
+            LevelFour: Get<LevelFive>,
+            LevelThree: Get<LevelFour>,
+            LevelTwo: Get<LevelThree>,
+            LevelOne: Get<LevelTwo>,
+            Self: Get<LevelOne> + Sized {
+        fn get_fifth(self) -> LevelFive {
+            self.get().get().get().get().get()
+        }
+    }
+
+    /// A variant of [`GetRef`], reaching five levels deep in the data structure.
+    ///
+    /// [`GetRef`]: ../trait.GetRef.html
+    ///
+    /// # Examples
+    ///```
+    ///  # use photonix::*;
+    ///  #[derive(GetRef)]
+    ///  pub struct Employee { pub name: String, pub company: Company }
+    ///
+    ///  #[derive(GetRef)]
+    ///  pub struct Company { pub name: String, pub address: Address }
+    ///
+    ///  #[derive(GetRef)]
+    ///  pub struct Address { pub city: String, pub street: Street }
+    ///
+    ///  #[derive(GetRef)]
+    ///  pub struct Street { pub number: StreetNumber, pub name: String }
+    ///
+    ///  #[derive(GetRef)]
+    ///  pub struct StreetNumber(u16);
+    ///
+    /// let john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address {
+    ///                 city: String::from("London"),
+    ///                 street: Street {
+    ///                     number: StreetNumber(23),
+    ///                     name: String::from("High street"),
+    ///                 }
+    ///             },
+    ///         }
+    ///     };
+    ///
+    /// //                      Level 1   Level 2   Level 3 Level     Level 5   Parent type
+    /// //                         |         |        |       |           |         |
+    /// impl<'a> GetRefFifth<'a, Company, Address, Street, StreetNumber, u16> for Employee {}
+    ///
+    /// assert_eq!(23, *john_doe.get_ref_fifth());
+    ///
+    ///```
+    pub trait GetRefFifth<'a, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        where
+            LevelFour: GetRef<LevelFive> + 'a,
+            LevelThree: GetRef<LevelFour> + 'a,
+            LevelTwo: GetRef<LevelThree> + 'a,
+            LevelOne: GetRef<LevelTwo> + 'a,
+            Self: GetRef<LevelOne> + 'a {
+        fn get_ref_fifth(&'a self) -> &'a LevelFive {
+            self.get_ref().get_ref().get_ref().get_ref().get_ref()
+        }
+    }
+
+    /// A variant of [`GetMut`], reaching five levels deep in the data structure.
+    ///
+    /// [`GetMut`]: ../trait.GetMut.html
+    ///
+    /// # Examples
+    ///```
+    ///  # use photonix::*;
+    ///  pub struct Employee { pub name: String, pub company: Company }
+    ///
+    ///  pub struct Company { pub name: String, pub address: Address }
+    ///
+    ///  pub struct Address { pub city: String, pub street: Street }
+    ///
+    ///  pub struct Street { pub number: StreetNumber, pub name: String }
+    ///
+    ///  pub struct StreetNumber { pub value: u16 }
+    ///
+    ///  impl GetMut<Company> for Employee {
+    ///      fn get_mut(&mut self) -> &mut Company { &mut self.company }
+    ///  }
+    ///
+    ///  impl GetMut<Address> for Company {
+    ///      fn get_mut(&mut self) -> &mut Address { &mut self.address }
+    ///  }
+    ///
+    ///  impl GetMut<Street> for Address {
+    ///      fn get_mut(&mut self) -> &mut Street { &mut self.street }
+    ///  }
+    ///
+    ///  impl GetMut<StreetNumber> for Street {
+    ///      fn get_mut(&mut self) -> &mut StreetNumber { &mut self.number }
+    ///  }
+    ///
+    ///  impl GetMut<u16> for StreetNumber {
+    ///      fn get_mut(&mut self) -> &mut u16 { &mut self.value }
+    ///  }
+    ///
+    /// let mut john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address {
+    ///                 city: String::from("London"),
+    ///                 street: Street {
+    ///                     number: StreetNumber { value: 23 },
+    ///                     name: String::from("High street"),
+    ///                 }
+    ///             },
+    ///         }
+    ///     };
+    ///
+    /// //                      Level 1   Level 2   Level 3 Level     Level 5   Parent type
+    /// //                         |         |        |       |          |         |
+    /// impl<'a> GetMutFifth<'a, Company, Address, Street, StreetNumber, u16> for Employee {}
+    ///
+    /// *john_doe.get_mut_fifth() *= 10;
+    ///
+    /// assert_eq!(230, john_doe.company.address.street.number.value);
+    ///
+    ///```
+    pub trait GetMutFifth<'a, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        where
+            LevelFour: GetMut<LevelFive> + 'a,
+            LevelThree: GetMut<LevelFour> + 'a,
+            LevelTwo: GetMut<LevelThree> + 'a,
+            LevelOne: GetMut<LevelTwo> + 'a,
+            Self: GetMut<LevelOne> + 'a {
+        fn get_mut_fifth(&'a mut self) -> &'a mut LevelFive {
+            self.get_mut().get_mut().get_mut().get_mut().get_mut()
+        }
+    }
+
+    /// A variant of [`Set`], reaching five levels deep in the data structure.
+    ///
+    /// [`Set`]: ../trait.Set.html
+    ///
+    /// # Examples
+    ///```
+    ///  # use photonix::*;
+    ///  #[derive(Get, Modify)]
+    ///  pub struct Employee { pub name: String, pub company: Company }
+    ///
+    ///  #[derive(Get, Modify)]
+    ///  pub struct Company { pub name: String, pub address: Address }
+    ///
+    ///  #[derive(Get, Modify)]
+    ///  pub struct Address { pub city: String, pub street: Street }
+    ///
+    ///  #[derive(Get, Modify)]
+    ///  pub struct Street { pub number: StreetNumber, pub name: String }
+    ///
+    ///  #[derive(Get, Set)]
+    ///  pub struct StreetNumber(u16);
+    ///
+    /// let john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address {
+    ///                 city: String::from("London"),
+    ///                 street: Street {
+    ///                     number: StreetNumber(23),
+    ///                     name: String::from("High street"),
+    ///                 }
+    ///             },
+    ///         }
+    ///     };
+    ///
+    /// //            Level 1   Level 2   Level 3 Level     Level 5   Parent type
+    /// //               |         |        |       |          |         |
+    /// impl SetFifth<Company, Address, Street, StreetNumber, u16> for Employee {}
+    /// impl GetFifth<Company, Address, Street, StreetNumber, u16> for Employee {}
+    ///
+    /// let john_doe_new_number = john_doe.set_fifth(666);
+    ///
+    /// assert_eq!(666, john_doe_new_number.get_fifth());
+    ///
+    ///```
+    pub trait SetFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        where
+            LevelFour: Set<LevelFive>,
+            LevelThree: Modify<LevelFour>,
+            LevelTwo: Modify<LevelThree>,
+            LevelOne: Modify<LevelTwo>,
+            Self: Modify<LevelOne> + Sized {
+        fn set_fifth(self, new_value: LevelFive) -> Self {
+            self.modify(
+                |level_one| level_one.modify(
+                    |level_two| level_two.modify(
+                        |level_three| level_three.modify(
+                            |level_four| level_four.set(new_value)
+                        )
+                    )
+                )
+            )
+        }
+    }
+
+    /// A variant of [`Modify`], reaching five levels deep in the data structure.
+    ///
+    /// [`Modify`]: ../trait.Modify.html
+    ///
+    /// # Examples
+    ///```
+    ///  # use photonix::*;
+    ///  #[derive(Get, Modify)]
+    ///  pub struct Employee { pub name: String, pub company: Company }
+    ///
+    ///  #[derive(Get, Modify)]
+    ///  pub struct Company { pub name: String, pub address: Address }
+    ///
+    ///  #[derive(Get, Modify)]
+    ///  pub struct Address { pub city: String, pub street: Street }
+    ///
+    ///  #[derive(Get, Modify)]
+    ///  pub struct Street { pub number: StreetNumber, pub name: String }
+    ///
+    ///  #[derive(Get, Modify)]
+    ///  pub struct StreetNumber(u16);
+    ///
+    /// let john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address {
+    ///                 city: String::from("London"),
+    ///                 street: Street {
+    ///                     number: StreetNumber(23),
+    ///                     name: String::from("High street"),
+    ///                 }
+    ///             },
+    ///         }
+    ///     };
+    ///
+    /// //              Level 1   Level 2   Level 3 Level     Level 5   Parent type
+    /// //                 |         |        |       |          |         |
+    /// impl ModifyFifth<Company, Address, Street, StreetNumber, u16> for Employee {}
+    /// impl GetFifth<Company, Address, Street, StreetNumber, u16> for Employee {}
+    ///
+    /// let john_doe_new_number = john_doe.modify_fifth(|num: u16| num * 10);
+    ///
+    /// assert_eq!(230, john_doe_new_number.get_fifth());
+    ///
+    ///```
+    pub trait ModifyFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        where
+            LevelFour: Modify<LevelFive>,
+            LevelThree: Modify<LevelFour>,
+            LevelTwo: Modify<LevelThree>,
+            LevelOne: Modify<LevelTwo>,
+            Self: Modify<LevelOne> + Sized {
+        fn modify_fifth(self, f: impl FnOnce(LevelFive) -> LevelFive) -> Self {
+            self.modify(
+                |level_one| level_one.modify(
+                    |level_two| level_two.modify(
+                        |level_three| level_three.modify(
+                            |level_four| level_four.modify(f)
+                        )
+                    )
+                )
+            )
+        }
+    }
+
+    /// A variant of [`SetInPlace`], reaching five levels deep in the data structure.
+    ///
+    /// [`SetInPlace`]: ../trait.SetInPlace.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// pub struct Address { pub city: String, pub street: Street }
+    ///
+    /// pub struct Street { pub number: StreetNumber, pub name: String }
+    ///
+    /// pub struct StreetNumber { pub value: u16 }
+    ///
+    /// impl GetMut<Company> for Employee {
+    ///     fn get_mut(&mut self) -> &mut Company { &mut self.company }
+    /// }
+    ///
+    /// impl GetMut<Address> for Company {
+    ///     fn get_mut(&mut self) -> &mut Address { &mut self.address }
+    /// }
+    ///
+    /// impl GetMut<Street> for Address {
+    ///     fn get_mut(&mut self) -> &mut Street { &mut self.street }
+    /// }
+    ///
+    /// impl GetMut<StreetNumber> for Street {
+    ///     fn get_mut(&mut self) -> &mut StreetNumber { &mut self.number }
+    /// }
+    ///
+    /// impl GetMut<u16> for StreetNumber {
+    ///     fn get_mut(&mut self) -> &mut u16 { &mut self.value }
+    /// }
+    ///
+    /// impl SetInPlaceFifth<Company, Address, Street, StreetNumber, u16> for Employee {}
+    ///
+    /// let mut john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address {
+    ///                 city: String::from("London"),
+    ///                 street: Street {
+    ///                     number: StreetNumber { value: 23 },
+    ///                     name: String::from("High street"),
+    ///                 }
+    ///             },
+    ///         }
+    ///     };
+    ///
+    /// john_doe.set_in_place_fifth(666);
+    ///
+    /// assert_eq!(666, john_doe.company.address.street.number.value);
+    ///
+    ///```
+    pub trait SetInPlaceFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        where
+            LevelFour: SetInPlace<LevelFive>,
+            LevelThree: ModifyInPlace<LevelFour>,
+            LevelTwo: ModifyInPlace<LevelThree>,
+            LevelOne: ModifyInPlace<LevelTwo>,
+            Self: ModifyInPlace<LevelOne> {
+        fn set_in_place_fifth(&mut self, new_value: LevelFive) {
+            self.modify_in_place(
+                |level_one| level_one.modify_in_place(
+                    |level_two| level_two.modify_in_place(
+                        |level_three| level_three.modify_in_place(
+                            |level_four| level_four.set_in_place(new_value)
+                        )
+                    )
+                )
+            )
+        }
+    }
+
+    /// A variant of [`ModifyInPlace`], reaching five levels deep in the data structure.
+    ///
+    /// [`ModifyInPlace`]: ../trait.ModifyInPlace.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// pub struct Address { pub city: String, pub street: Street }
+    ///
+    /// pub struct Street { pub number: StreetNumber, pub name: String }
+    ///
+    /// pub struct StreetNumber { pub value: u16 }
+    ///
+    /// impl GetMut<Company> for Employee {
+    ///     fn get_mut(&mut self) -> &mut Company { &mut self.company }
+    /// }
+    ///
+    /// impl GetMut<Address> for Company {
+    ///     fn get_mut(&mut self) -> &mut Address { &mut self.address }
+    /// }
+    ///
+    /// impl GetMut<Street> for Address {
+    ///     fn get_mut(&mut self) -> &mut Street { &mut self.street }
+    /// }
+    ///
+    /// impl GetMut<StreetNumber> for Street {
+    ///     fn get_mut(&mut self) -> &mut StreetNumber { &mut self.number }
+    /// }
+    ///
+    /// impl GetMut<u16> for StreetNumber {
+    ///     fn get_mut(&mut self) -> &mut u16 { &mut self.value }
+    /// }
+    ///
+    /// impl ModifyInPlaceFifth<Company, Address, Street, StreetNumber, u16> for Employee {}
+    ///
+    /// let mut john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address {
+    ///                 city: String::from("London"),
+    ///                 street: Street {
+    ///                     number: StreetNumber { value: 23 },
+    ///                     name: String::from("High street"),
+    ///                 }
+    ///             },
+    ///         }
+    ///     };
+    ///
+    /// john_doe.modify_in_place_fifth(|number| *number *= 10);
+    ///
+    /// assert_eq!(230, john_doe.company.address.street.number.value);
+    ///
+    ///```
+    pub trait ModifyInPlaceFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        where
+            LevelFour: ModifyInPlace<LevelFive>,
+            LevelThree: ModifyInPlace<LevelFour>,
+            LevelTwo: ModifyInPlace<LevelThree>,
+            LevelOne: ModifyInPlace<LevelTwo>,
+            Self: ModifyInPlace<LevelOne> {
+        fn modify_in_place_fifth(&mut self, f: impl FnOnce(&mut LevelFive)) {
+            self.modify_in_place(
+                |level_one| level_one.modify_in_place(
+                    |level_two| level_two.modify_in_place(
+                        |level_three| level_three.modify_in_place(
+                            |level_four| level_four.modify_in_place(f)
+                        )
+                    )
+                )
+            )
+        }
+    }
+
+    /// A variant of [`TryModify`], reaching five levels deep in the data structure.
+    ///
+    /// [`TryModify`]: ../trait.TryModify.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// pub struct Address { pub city: String, pub street: Street }
+    ///
+    /// pub struct Street { pub number: StreetNumber, pub name: String }
+    ///
+    /// pub struct StreetNumber { pub value: u16 }
+    ///
+    /// impl TryModify<Company> for Employee {
+    ///     fn try_modify<E>(self, f: impl FnOnce(Company) -> Result<Company, E>) -> Result<Self, E> {
+    ///         Ok(Employee { company: f(self.company)?, ..self })
+    ///     }
+    /// }
+    ///
+    /// impl TryModify<Address> for Company {
+    ///     fn try_modify<E>(self, f: impl FnOnce(Address) -> Result<Address, E>) -> Result<Self, E> {
+    ///         Ok(Company { address: f(self.address)?, ..self })
+    ///     }
+    /// }
+    ///
+    /// impl TryModify<Street> for Address {
+    ///     fn try_modify<E>(self, f: impl FnOnce(Street) -> Result<Street, E>) -> Result<Self, E> {
+    ///         Ok(Address { street: f(self.street)?, ..self })
+    ///     }
+    /// }
+    ///
+    /// impl TryModify<StreetNumber> for Street {
+    ///     fn try_modify<E>(self, f: impl FnOnce(StreetNumber) -> Result<StreetNumber, E>) -> Result<Self, E> {
+    ///         Ok(Street { number: f(self.number)?, ..self })
+    ///     }
+    /// }
+    ///
+    /// impl TryModify<u16> for StreetNumber {
+    ///     fn try_modify<E>(self, f: impl FnOnce(u16) -> Result<u16, E>) -> Result<Self, E> {
+    ///         Ok(StreetNumber { value: f(self.value)?, ..self })
+    ///     }
+    /// }
+    ///
+    /// impl TryModifyFifth<Company, Address, Street, StreetNumber, u16> for Employee {}
+    ///
+    /// let john_doe = Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address {
+    ///                 city: String::from("London"),
+    ///                 street: Street {
+    ///                     number: StreetNumber { value: 23 },
+    ///                     name: String::from("High street"),
+    ///                 }
+    ///             },
+    ///         }
+    ///     };
+    ///
+    /// let result = john_doe.try_modify_fifth(|number: u16| number.checked_mul(10).ok_or("overflow"));
+    ///
+    /// assert_eq!(Ok(230), result.map(|e| e.company.address.street.number.value));
+    ///
+    ///```
+    pub trait TryModifyFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        where
+            LevelFour: TryModify<LevelFive>,
+            LevelThree: TryModify<LevelFour>,
+            LevelTwo: TryModify<LevelThree>,
+            LevelOne: TryModify<LevelTwo>,
+            Self: TryModify<LevelOne> {
+        fn try_modify_fifth<E>(self, f: impl FnOnce(LevelFive) -> Result<LevelFive, E>) -> Result<Self, E> {
+            self.try_modify(
+                |level_one| level_one.try_modify(
+                    |level_two| level_two.try_modify(
+                        |level_three| level_three.try_modify(
+                            |level_four| level_four.try_modify(f)
+                        )
+                    )
+                )
+            )
+        }
+    }
+
+    /// A variant of [`GetAll`], reaching two levels deep in the data structure, traversing every level.
+    ///
+    /// [`GetAll`]: ../trait.GetAll.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(GetRef, Modify)]
+    /// pub struct Basket { pub owner: String, pub lines: Vec<Line> }
+    ///
+    /// pub struct Line { pub sku: String, pub price: u32 }
+    ///
+    /// traverse![Basket => Vec<Line>];
+    ///
+    /// let basket = Basket {
+    ///     owner: String::from("John Doe"),
+    ///     lines: vec![
+    ///         Line { sku: String::from("apple"), price: 3 },
+    ///         Line { sku: String::from("pear"), price: 4 },
+    ///     ],
+    /// };
+    ///
+    /// //                        Level 1   Level 2   Parent type
+    /// //                           |         |          |
+    /// impl<'a> GetAllSecond<'a, Vec<Line>, Line> for Basket {}
+    ///
+    /// assert_eq!(7, basket.get_all_second().map(|line| line.price).sum::<u32>());
+    ///
+    ///```
+    pub trait GetAllSecond<'a, LevelOne, LevelTwo>
+        where
+            LevelOne: GetAll<LevelTwo> + 'a,
+            Self: GetAll<LevelOne> + 'a {
+        fn get_all_second(&'a self) -> Box<dyn Iterator<Item = &'a LevelTwo> + 'a> {
+            Box::new(
+                self.get_all()
+                    .flat_map(|level_one| level_one.get_all())
+            )
+        }
+    }
+
+    /// A variant of [`ModifyAll`], reaching two levels deep in the data structure, traversing every level.
+    ///
+    /// [`ModifyAll`]: ../trait.ModifyAll.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(GetRef, Modify)]
+    /// pub struct Line { pub sku: String, pub price: u32 }
+    ///
+    /// traverse![Line => u32];
+    ///
+    /// let lines = vec![
+    ///     Line { sku: String::from("apple"), price: 3 },
+    ///     Line { sku: String::from("pear"), price: 4 },
+    /// ];
+    ///
+    /// // Vec<Level 1> gets the composites of its elements for free
+    /// let doubled = lines.modify_all_second(|price: u32| price * 2);
+    ///
+    /// assert_eq!(vec![6, 8], doubled.get_all_second().copied().collect::<Vec<u32>>());
+    ///
+    ///```
+    pub trait ModifyAllSecond<LevelOne, LevelTwo>
+        where
+            LevelOne: ModifyAll<LevelTwo>,
+            Self: ModifyAll<LevelOne> + Sized {
+        fn modify_all_second(self, mut f: impl FnMut(LevelTwo) -> LevelTwo) -> Self {
+            self.modify_all(|level_one| level_one.modify_all(&mut f))
+        }
+    }
+
+    /// A variant of [`GetAll`], reaching three levels deep in the data structure, traversing every level.
+    ///
+    /// [`GetAll`]: ../trait.GetAll.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(GetRef, Modify)]
+    /// pub struct Order { pub id: String, pub lines: Vec<Line> }
+    ///
+    /// #[derive(GetRef, Modify)]
+    /// pub struct Line { pub sku: String, pub price: u32 }
+    ///
+    /// traverse![Order => Vec<Line>, Line => u32];
+    ///
+    /// let order = || Order {
+    ///     id: String::from("order-1"),
+    ///     lines: vec![
+    ///         Line { sku: String::from("apple"), price: 3 },
+    ///         Line { sku: String::from("pear"), price: 4 },
+    ///     ],
+    /// };
+    ///
+    /// //                        Level 1    Level 2 Level 3   Parent type
+    /// //                           |          |      |           |
+    /// impl<'a> GetAllThird<'a, Vec<Line>, Line, u32> for Order {}
+    ///
+    /// assert_eq!(7u32, order().get_all_third().sum::<u32>());
+    ///
+    ///```
+    pub trait GetAllThird<'a, LevelOne, LevelTwo, LevelThree>
+        where
+            LevelTwo: GetAll<LevelThree> + 'a,
+            LevelOne: GetAll<LevelTwo> + 'a,
+            Self: GetAll<LevelOne> + 'a {
+        fn get_all_third(&'a self) -> Box<dyn Iterator<Item = &'a LevelThree> + 'a> {
+            Box::new(
+                self.get_all()
+                    .flat_map(|level_one| level_one.get_all())
+                    .flat_map(|level_two| level_two.get_all())
+            )
+        }
+    }
+
+    /// A variant of [`ModifyAll`], reaching three levels deep in the data structure, traversing every level.
+    ///
+    /// [`ModifyAll`]: ../trait.ModifyAll.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(GetRef, Modify)]
+    /// pub struct Order { pub id: String, pub lines: Vec<Line> }
+    ///
+    /// #[derive(GetRef, Modify)]
+    /// pub struct Line { pub sku: String, pub price: u32 }
+    ///
+    /// traverse![Order => Vec<Line>, Line => u32];
+    ///
+    /// let order = || Order {
+    ///     id: String::from("order-1"),
+    ///     lines: vec![
+    ///         Line { sku: String::from("apple"), price: 3 },
+    ///         Line { sku: String::from("pear"), price: 4 },
+    ///     ],
+    /// };
+    ///
+    /// //                   Level 1    Level 2 Level 3   Parent type
+    /// //                      |          |      |           |
+    /// impl ModifyAllThird<Vec<Line>, Line, u32> for Order {}
+    /// impl<'a> GetAllThird<'a, Vec<Line>, Line, u32> for Order {}
+    ///
+    /// let discounted = order().modify_all_third(|price| price - 1);
+    ///
+    /// assert_eq!(5u32, discounted.get_all_third().sum::<u32>());
+    ///
+    ///```
+    pub trait ModifyAllThird<LevelOne, LevelTwo, LevelThree>
+        where
+            LevelTwo: ModifyAll<LevelThree>,
+            LevelOne: ModifyAll<LevelTwo>,
+            Self: ModifyAll<LevelOne> + Sized {
+        fn modify_all_third(self, mut f: impl FnMut(LevelThree) -> LevelThree) -> Self {
+            self.modify_all(
+                |level_one| level_one.modify_all(
+                    |level_two| level_two.modify_all(&mut f)
+                )
+            )
+        }
+    }
+
+    /// A variant of [`GetAll`], reaching four levels deep in the data structure, traversing every level.
+    ///
+    /// [`GetAll`]: ../trait.GetAll.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(GetRef, Modify)]
+    /// pub struct Order { pub id: String, pub lines: Vec<Line> }
+    ///
+    /// #[derive(GetRef, Modify)]
+    /// pub struct Line { pub sku: String, pub price: u32 }
+    ///
+    /// traverse![Order => Vec<Line>, Line => u32];
+    ///
+    /// let order = || Order {
+    ///     id: String::from("order-1"),
+    ///     lines: vec![
+    ///         Line { sku: String::from("apple"), price: 3 },
+    ///         Line { sku: String::from("pear"), price: 4 },
+    ///     ],
+    /// };
+    ///
+    /// let orders = vec![order(), order()];
+    ///
+    /// // Vec<Level 1> gets the composites of its elements for free
+    /// assert_eq!(14u32, orders.get_all_fourth().sum::<u32>());
+    ///
+    ///```
+    pub trait GetAllFourth<'a, LevelOne, LevelTwo, LevelThree, LevelFour>
+        where
+            LevelThree: GetAll<LevelFour> + 'a,
+            LevelTwo: GetAll<LevelThree> + 'a,
+            LevelOne: GetAll<LevelTwo> + 'a,
+            Self: GetAll<LevelOne> + 'a {
+        fn get_all_fourth(&'a self) -> Box<dyn Iterator<Item = &'a LevelFour> + 'a> {
+            Box::new(
+                self.get_all()
+                    .flat_map(|level_one| level_one.get_all())
+                    .flat_map(|level_two| level_two.get_all())
+                    .flat_map(|level_three| level_three.get_all())
+            )
+        }
+    }
+
+    /// A variant of [`ModifyAll`], reaching four levels deep in the data structure, traversing every level.
+    ///
+    /// [`ModifyAll`]: ../trait.ModifyAll.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(GetRef, Modify)]
+    /// pub struct Order { pub id: String, pub lines: Vec<Line> }
+    ///
+    /// #[derive(GetRef, Modify)]
+    /// pub struct Line { pub sku: String, pub price: u32 }
+    ///
+    /// traverse![Order => Vec<Line>, Line => u32];
+    ///
+    /// let order = || Order {
+    ///     id: String::from("order-1"),
+    ///     lines: vec![
+    ///         Line { sku: String::from("apple"), price: 3 },
+    ///         Line { sku: String::from("pear"), price: 4 },
+    ///     ],
+    /// };
+    ///
+    /// let orders = vec![order(), order()];
+    ///
+    /// let repriced = orders.modify_all_fourth(|price: u32| price * 10);
+    ///
+    /// assert_eq!(140u32, repriced.get_all_fourth().sum::<u32>());
+    ///
+    ///```
+    pub trait ModifyAllFourth<LevelOne, LevelTwo, LevelThree, LevelFour>
+        where
+            LevelThree: ModifyAll<LevelFour>,
+            LevelTwo: ModifyAll<LevelThree>,
+            LevelOne: ModifyAll<LevelTwo>,
+            Self: ModifyAll<LevelOne> + Sized {
+        fn modify_all_fourth(self, mut f: impl FnMut(LevelFour) -> LevelFour) -> Self {
+            self.modify_all(
+                |level_one| level_one.modify_all(
+                    |level_two| level_two.modify_all(
+                        |level_three| level_three.modify_all(&mut f)
+                    )
+                )
+            )
+        }
+    }
+
+    /// A variant of [`GetAll`], reaching five levels deep in the data structure, traversing every level.
+    ///
+    /// [`GetAll`]: ../trait.GetAll.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(GetRef, Modify)]
+    /// pub struct Order { pub id: String, pub lines: Vec<Line> }
+    ///
+    /// #[derive(GetRef, Modify)]
+    /// pub struct Line { pub sku: String, pub price: u32 }
+    ///
+    /// traverse![Order => Vec<Line>, Line => u32];
+    ///
+    /// let order = || Order {
+    ///     id: String::from("order-1"),
+    ///     lines: vec![
+    ///         Line { sku: String::from("apple"), price: 3 },
+    ///         Line { sku: String::from("pear"), price: 4 },
+    ///     ],
+    /// };
+    ///
+    /// let history = Some(vec![order(), order()]);
+    ///
+    /// // Option<Level 1> and Vec<Level 2> get the composites of their elements for free
+    /// assert_eq!(4, history.get_all_fifth().count());
+    ///
+    ///```
+    pub trait GetAllFifth<'a, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        where
+            LevelFour: GetAll<LevelFive> + 'a,
+            LevelThree: GetAll<LevelFour> + 'a,
+            LevelTwo: GetAll<LevelThree> + 'a,
+            LevelOne: GetAll<LevelTwo> + 'a,
+            Self: GetAll<LevelOne> + 'a {
+        fn get_all_fifth(&'a self) -> Box<dyn Iterator<Item = &'a LevelFive> + 'a> {
+            Box::new(
+                self.get_all()
+                    .flat_map(|level_one| level_one.get_all())
+                    .flat_map(|level_two| level_two.get_all())
+                    .flat_map(|level_three| level_three.get_all())
+                    .flat_map(|level_four| level_four.get_all())
+            )
+        }
+    }
+
+    /// A variant of [`ModifyAll`], reaching five levels deep in the data structure, traversing every level.
+    ///
+    /// [`ModifyAll`]: ../trait.ModifyAll.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(GetRef, Modify)]
+    /// pub struct Order { pub id: String, pub lines: Vec<Line> }
+    ///
+    /// #[derive(GetRef, Modify)]
+    /// pub struct Line { pub sku: String, pub price: u32 }
+    ///
+    /// traverse![Order => Vec<Line>, Line => u32];
+    ///
+    /// let order = || Order {
+    ///     id: String::from("order-1"),
+    ///     lines: vec![
+    ///         Line { sku: String::from("apple"), price: 3 },
+    ///         Line { sku: String::from("pear"), price: 4 },
+    ///     ],
+    /// };
+    ///
+    /// let history = Some(vec![order(), order()]);
+    ///
+    /// let repriced = history.modify_all_fifth(|price: u32| price + 1);
+    ///
+    /// assert_eq!(18u32, repriced.get_all_fifth().sum::<u32>());
+    ///
+    ///```
+    pub trait ModifyAllFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        where
+            LevelFour: ModifyAll<LevelFive>,
+            LevelThree: ModifyAll<LevelFour>,
+            LevelTwo: ModifyAll<LevelThree>,
+            LevelOne: ModifyAll<LevelTwo>,
+            Self: ModifyAll<LevelOne> + Sized {
+        fn modify_all_fifth(self, mut f: impl FnMut(LevelFive) -> LevelFive) -> Self {
+            self.modify_all(
+                |level_one| level_one.modify_all(
+                    |level_two| level_two.modify_all(
+                        |level_three| level_three.modify_all(
+                            |level_four| level_four.modify_all(&mut f)
+                        )
+                    )
+                )
+            )
+        }
+    }
+
+    /// A variant of [`GetOption`], reaching two levels deep in the data structure.
+    ///
+    /// [`GetOption`]: ../trait.GetOption.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(GetOption)]
+    /// pub enum Event { Payment(Payment), Ping }
+    ///
+    /// #[derive(GetOption)]
+    /// pub enum Payment { Cash(u32), Voucher(String) }
+    ///
+    /// impl GetOptionSecond<Payment, u32> for Event {}
+    ///
+    /// assert_eq!(Some(20), Event::Payment(Payment::Cash(20)).get_option_second());
+    /// assert_eq!(None, Event::Ping.get_option_second());
+    ///
+    ///```
+    pub trait GetOptionSecond<LevelOne, LevelTwo>
+        where
+            LevelOne: GetOption<LevelTwo>,
+            Self: GetOption<LevelOne> + Sized {
+        fn get_option_second(self) -> Option<LevelTwo> {
+            self.get_option()
+                .and_then(|level_one| level_one.get_option())
+        }
+    }
+
+    /// A variant of [`SetOption`], reaching two levels deep in the data structure.
+    ///
+    /// Every level but the last one is reached with [`GetOption`], then rebuilt with [`ReverseGet`] after the update.
+    ///
+    /// [`SetOption`]: ../trait.SetOption.html
+    /// [`GetOption`]: ../trait.GetOption.html
+    /// [`ReverseGet`]: ../trait.ReverseGet.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Debug, GetOption, SetOption, PartialEq)]
+    /// pub enum Event { Payment(Payment), Ping }
+    ///
+    /// #[derive(Debug, GetOption, SetOption, PartialEq)]
+    /// pub enum Payment { Cash(u32), Voucher(String) }
+    ///
+    /// impl ReverseGet<Payment> for Event {
+    ///     fn reverse_get(value: Payment) -> Self { Event::Payment(value) }
+    /// }
+    ///
+    /// impl SetOptionSecond<Payment, u32> for Event {}
+    ///
+    /// assert_eq!(
+    ///     Some(Event::Payment(Payment::Cash(42))),
+    ///     Event::Payment(Payment::Cash(20)).set_option_second(42)
+    /// );
+    /// assert_eq!(None, Event::Ping.set_option_second(42));
+    ///
+    ///```
+    pub trait SetOptionSecond<LevelOne, LevelTwo>
+        where
+            LevelOne: SetOption<LevelTwo>,
+            Self: GetOption<LevelOne> + ReverseGet<LevelOne> + Sized {
+        fn set_option_second(self, new_value: LevelTwo) -> Option<Self> {
+            self.get_option()
+                .and_then(|level_one| level_one.set_option(new_value))
+                .map(<Self as ReverseGet<LevelOne>>::reverse_get)
+        }
+    }
+
+    /// A variant of [`ModifyOption`], reaching two levels deep in the data structure.
+    ///
+    /// Every level but the last one is reached with [`GetOption`], then rebuilt with [`ReverseGet`] after the update.
+    ///
+    /// [`ModifyOption`]: ../trait.ModifyOption.html
+    /// [`GetOption`]: ../trait.GetOption.html
+    /// [`ReverseGet`]: ../trait.ReverseGet.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Debug, GetOption, ModifyOption, PartialEq)]
+    /// pub enum Event { Payment(Payment), Ping }
+    ///
+    /// #[derive(Debug, GetOption, ModifyOption, PartialEq)]
+    /// pub enum Payment { Cash(u32), Voucher(String) }
+    ///
+    /// impl ReverseGet<Payment> for Event {
+    ///     fn reverse_get(value: Payment) -> Self { Event::Payment(value) }
+    /// }
+    ///
+    /// impl ModifyOptionSecond<Payment, u32> for Event {}
+    ///
+    /// assert_eq!(
+    ///     Some(Event::Payment(Payment::Cash(21))),
+    ///     Event::Payment(Payment::Cash(20)).modify_option_second(|n| n + 1)
+    /// );
+    /// assert_eq!(None, Event::Ping.modify_option_second(|n| n + 1));
+    ///
+    ///```
+    pub trait ModifyOptionSecond<LevelOne, LevelTwo>
+        where
+            LevelOne: ModifyOption<LevelTwo>,
+            Self: GetOption<LevelOne> + ReverseGet<LevelOne> + Sized {
+        fn modify_option_second(self, f: impl FnOnce(LevelTwo) -> LevelTwo) -> Option<Self> {
+            self.get_option()
+                .and_then(|level_one| level_one.modify_option(f))
+                .map(<Self as ReverseGet<LevelOne>>::reverse_get)
+        }
+    }
+
+    /// A variant of [`GetOption`], reaching three levels deep in the data structure.
+    ///
+    /// [`GetOption`]: ../trait.GetOption.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(GetOption)]
+    /// pub enum Event { Payment(Payment), Ping }
+    ///
+    /// #[derive(GetOption)]
+    /// pub enum Payment { Cash(u32), Card(Card) }
+    ///
+    /// #[derive(GetOption)]
+    /// pub enum Card { Debit(u16), Credit(u64) }
+    ///
+    /// impl GetOptionThird<Payment, Card, u16> for Event {}
+    ///
+    /// assert_eq!(Some(1234), Event::Payment(Payment::Card(Card::Debit(1234))).get_option_third());
+    /// assert_eq!(None, Event::Payment(Payment::Cash(20)).get_option_third());
+    ///
+    ///```
+    pub trait GetOptionThird<LevelOne, LevelTwo, LevelThree>
+        where
+            LevelTwo: GetOption<LevelThree>,
+            LevelOne: GetOption<LevelTwo>,
+            Self: GetOption<LevelOne> + Sized {
+        fn get_option_third(self) -> Option<LevelThree> {
+            self.get_option()
+                .and_then(|level_one| level_one.get_option())
+                .and_then(|level_two| level_two.get_option())
+        }
+    }
+
+    /// A variant of [`SetOption`], reaching three levels deep in the data structure.
+    ///
+    /// Every level but the last one is reached with [`GetOption`], then rebuilt with [`ReverseGet`] after the update.
+    ///
+    /// [`SetOption`]: ../trait.SetOption.html
+    /// [`GetOption`]: ../trait.GetOption.html
+    /// [`ReverseGet`]: ../trait.ReverseGet.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Debug, GetOption, SetOption, PartialEq)]
+    /// pub enum Event { Payment(Payment), Ping }
+    ///
+    /// #[derive(Debug, GetOption, SetOption, PartialEq)]
+    /// pub enum Payment { Cash(u32), Card(Card) }
+    ///
+    /// #[derive(Debug, GetOption, SetOption, PartialEq)]
+    /// pub enum Card { Debit(u16), Credit(u64) }
+    ///
+    /// impl ReverseGet<Payment> for Event {
+    ///     fn reverse_get(value: Payment) -> Self { Event::Payment(value) }
+    /// }
+    ///
+    /// impl ReverseGet<Card> for Payment {
+    ///     fn reverse_get(value: Card) -> Self { Payment::Card(value) }
+    /// }
+    ///
+    /// impl SetOptionThird<Payment, Card, u16> for Event {}
+    ///
+    /// assert_eq!(
+    ///     Some(Event::Payment(Payment::Card(Card::Debit(42)))),
+    ///     Event::Payment(Payment::Card(Card::Debit(1234))).set_option_third(42)
+    /// );
+    /// assert_eq!(None, Event::Payment(Payment::Cash(20)).set_option_third(42));
+    ///
+    ///```
+    pub trait SetOptionThird<LevelOne, LevelTwo, LevelThree>
+        where
+            LevelTwo: SetOption<LevelThree>,
+            LevelOne: GetOption<LevelTwo> + ReverseGet<LevelTwo>,
+            Self: GetOption<LevelOne> + ReverseGet<LevelOne> + Sized {
+        fn set_option_third(self, new_value: LevelThree) -> Option<Self> {
+            self.get_option()
+                .and_then(|level_one| level_one.get_option()
+                    .and_then(|level_two| level_two.set_option(new_value))
+                    .map(<LevelOne as ReverseGet<LevelTwo>>::reverse_get))
+                .map(<Self as ReverseGet<LevelOne>>::reverse_get)
+        }
+    }
+
+    /// A variant of [`ModifyOption`], reaching three levels deep in the data structure.
+    ///
+    /// Every level but the last one is reached with [`GetOption`], then rebuilt with [`ReverseGet`] after the update.
+    ///
+    /// [`ModifyOption`]: ../trait.ModifyOption.html
+    /// [`GetOption`]: ../trait.GetOption.html
+    /// [`ReverseGet`]: ../trait.ReverseGet.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Debug, GetOption, ModifyOption, PartialEq)]
+    /// pub enum Event { Payment(Payment), Ping }
+    ///
+    /// #[derive(Debug, GetOption, ModifyOption, PartialEq)]
+    /// pub enum Payment { Cash(u32), Card(Card) }
+    ///
+    /// #[derive(Debug, GetOption, ModifyOption, PartialEq)]
+    /// pub enum Card { Debit(u16), Credit(u64) }
+    ///
+    /// impl ReverseGet<Payment> for Event {
+    ///     fn reverse_get(value: Payment) -> Self { Event::Payment(value) }
+    /// }
+    ///
+    /// impl ReverseGet<Card> for Payment {
+    ///     fn reverse_get(value: Card) -> Self { Payment::Card(value) }
+    /// }
+    ///
+    /// impl ModifyOptionThird<Payment, Card, u16> for Event {}
+    ///
+    /// assert_eq!(
+    ///     Some(Event::Payment(Payment::Card(Card::Debit(1235)))),
+    ///     Event::Payment(Payment::Card(Card::Debit(1234))).modify_option_third(|n| n + 1)
+    /// );
+    /// assert_eq!(None, Event::Payment(Payment::Cash(20)).modify_option_third(|n| n + 1));
+    ///
+    ///```
+    pub trait ModifyOptionThird<LevelOne, LevelTwo, LevelThree>
+        where
+            LevelTwo: ModifyOption<LevelThree>,
+            LevelOne: GetOption<LevelTwo> + ReverseGet<LevelTwo>,
+            Self: GetOption<LevelOne> + ReverseGet<LevelOne> + Sized {
+        fn modify_option_third(self, f: impl FnOnce(LevelThree) -> LevelThree) -> Option<Self> {
+            self.get_option()
+                .and_then(|level_one| level_one.get_option()
+                    .and_then(|level_two| level_two.modify_option(f))
+                    .map(<LevelOne as ReverseGet<LevelTwo>>::reverse_get))
+                .map(<Self as ReverseGet<LevelOne>>::reverse_get)
+        }
+    }
+
+    /// A variant of [`GetOption`], reaching four levels deep in the data structure.
+    ///
+    /// [`GetOption`]: ../trait.GetOption.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(GetOption)]
+    /// pub enum Message { Incoming(Event), Outgoing(String) }
+    ///
+    /// #[derive(GetOption)]
+    /// pub enum Event { Payment(Payment), Ping }
+    ///
+    /// #[derive(GetOption)]
+    /// pub enum Payment { Cash(u32), Card(Card) }
+    ///
+    /// #[derive(GetOption)]
+    /// pub enum Card { Debit(u16), Credit(u64) }
+    ///
+    /// impl GetOptionFourth<Event, Payment, Card, u16> for Message {}
+    ///
+    /// assert_eq!(
+    ///     Some(1234),
+    ///     Message::Incoming(Event::Payment(Payment::Card(Card::Debit(1234)))).get_option_fourth()
+    /// );
+    /// assert_eq!(None, Message::Outgoing(String::from("ack")).get_option_fourth());
+    ///
+    ///```
+    pub trait GetOptionFourth<LevelOne, LevelTwo, LevelThree, LevelFour>
+        where
+            LevelThree: GetOption<LevelFour>,
+            LevelTwo: GetOption<LevelThree>,
+            LevelOne: GetOption<LevelTwo>,
+            Self: GetOption<LevelOne> + Sized {
+        fn get_option_fourth(self) -> Option<LevelFour> {
+            self.get_option()
+                .and_then(|level_one| level_one.get_option())
+                .and_then(|level_two| level_two.get_option())
+                .and_then(|level_three| level_three.get_option())
+        }
+    }
+
+    /// A variant of [`SetOption`], reaching four levels deep in the data structure.
+    ///
+    /// Every level but the last one is reached with [`GetOption`], then rebuilt with [`ReverseGet`] after the update.
+    ///
+    /// [`SetOption`]: ../trait.SetOption.html
+    /// [`GetOption`]: ../trait.GetOption.html
+    /// [`ReverseGet`]: ../trait.ReverseGet.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Debug, GetOption, SetOption, PartialEq)]
+    /// pub enum Message { Incoming(Event), Outgoing(String) }
+    ///
+    /// #[derive(Debug, GetOption, SetOption, PartialEq)]
+    /// pub enum Event { Payment(Payment), Ping }
+    ///
+    /// #[derive(Debug, GetOption, SetOption, PartialEq)]
+    /// pub enum Payment { Cash(u32), Card(Card) }
+    ///
+    /// #[derive(Debug, GetOption, SetOption, PartialEq)]
+    /// pub enum Card { Debit(u16), Credit(u64) }
+    ///
+    /// impl ReverseGet<Event> for Message {
+    ///     fn reverse_get(value: Event) -> Self { Message::Incoming(value) }
+    /// }
+    ///
+    /// impl ReverseGet<Payment> for Event {
+    ///     fn reverse_get(value: Payment) -> Self { Event::Payment(value) }
+    /// }
+    ///
+    /// impl ReverseGet<Card> for Payment {
+    ///     fn reverse_get(value: Card) -> Self { Payment::Card(value) }
+    /// }
+    ///
+    /// impl SetOptionFourth<Event, Payment, Card, u16> for Message {}
+    ///
+    /// assert_eq!(
+    ///     Some(Message::Incoming(Event::Payment(Payment::Card(Card::Debit(42))))),
+    ///     Message::Incoming(Event::Payment(Payment::Card(Card::Debit(1234)))).set_option_fourth(42)
+    /// );
+    /// assert_eq!(None, Message::Outgoing(String::from("ack")).set_option_fourth(42));
+    ///
+    ///```
+    pub trait SetOptionFourth<LevelOne, LevelTwo, LevelThree, LevelFour>
+        where
+            LevelThree: SetOption<LevelFour>,
+            LevelTwo: GetOption<LevelThree> + ReverseGet<LevelThree>,
+            LevelOne: GetOption<LevelTwo> + ReverseGet<LevelTwo>,
+            Self: GetOption<LevelOne> + ReverseGet<LevelOne> + Sized {
+        fn set_option_fourth(self, new_value: LevelFour) -> Option<Self> {
+            self.get_option()
+                .and_then(|level_one| level_one.get_option()
+                    .and_then(|level_two| level_two.get_option()
+                        .and_then(|level_three| level_three.set_option(new_value))
+                        .map(<LevelTwo as ReverseGet<LevelThree>>::reverse_get))
+                    .map(<LevelOne as ReverseGet<LevelTwo>>::reverse_get))
+                .map(<Self as ReverseGet<LevelOne>>::reverse_get)
+        }
+    }
+
+    /// A variant of [`ModifyOption`], reaching four levels deep in the data structure.
+    ///
+    /// Every level but the last one is reached with [`GetOption`], then rebuilt with [`ReverseGet`] after the update.
+    ///
+    /// [`ModifyOption`]: ../trait.ModifyOption.html
+    /// [`GetOption`]: ../trait.GetOption.html
+    /// [`ReverseGet`]: ../trait.ReverseGet.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Debug, GetOption, ModifyOption, PartialEq)]
+    /// pub enum Message { Incoming(Event), Outgoing(String) }
+    ///
+    /// #[derive(Debug, GetOption, ModifyOption, PartialEq)]
+    /// pub enum Event { Payment(Payment), Ping }
+    ///
+    /// #[derive(Debug, GetOption, ModifyOption, PartialEq)]
+    /// pub enum Payment { Cash(u32), Card(Card) }
+    ///
+    /// #[derive(Debug, GetOption, ModifyOption, PartialEq)]
+    /// pub enum Card { Debit(u16), Credit(u64) }
+    ///
+    /// impl ReverseGet<Event> for Message {
+    ///     fn reverse_get(value: Event) -> Self { Message::Incoming(value) }
+    /// }
+    ///
+    /// impl ReverseGet<Payment> for Event {
+    ///     fn reverse_get(value: Payment) -> Self { Event::Payment(value) }
+    /// }
+    ///
+    /// impl ReverseGet<Card> for Payment {
+    ///     fn reverse_get(value: Card) -> Self { Payment::Card(value) }
+    /// }
+    ///
+    /// impl ModifyOptionFourth<Event, Payment, Card, u16> for Message {}
+    ///
+    /// assert_eq!(
+    ///     Some(Message::Incoming(Event::Payment(Payment::Card(Card::Debit(1235))))),
+    ///     Message::Incoming(Event::Payment(Payment::Card(Card::Debit(1234)))).modify_option_fourth(|n| n + 1)
+    /// );
+    /// assert_eq!(None, Message::Outgoing(String::from("ack")).modify_option_fourth(|n| n + 1));
+    ///
+    ///```
+    pub trait ModifyOptionFourth<LevelOne, LevelTwo, LevelThree, LevelFour>
+        where
+            LevelThree: ModifyOption<LevelFour>,
+            LevelTwo: GetOption<LevelThree> + ReverseGet<LevelThree>,
+            LevelOne: GetOption<LevelTwo> + ReverseGet<LevelTwo>,
+            Self: GetOption<LevelOne> + ReverseGet<LevelOne> + Sized {
+        fn modify_option_fourth(self, f: impl FnOnce(LevelFour) -> LevelFour) -> Option<Self> {
+            self.get_option()
+                .and_then(|level_one| level_one.get_option()
+                    .and_then(|level_two| level_two.get_option()
+                        .and_then(|level_three| level_three.modify_option(f))
+                        .map(<LevelTwo as ReverseGet<LevelThree>>::reverse_get))
+                    .map(<LevelOne as ReverseGet<LevelTwo>>::reverse_get))
+                .map(<Self as ReverseGet<LevelOne>>::reverse_get)
+        }
+    }
+
+    /// A variant of [`GetOption`], reaching five levels deep in the data structure.
+    ///
+    /// [`GetOption`]: ../trait.GetOption.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(GetOption)]
+    /// pub enum Envelope { Signed(Message), Unsigned(String) }
+    ///
+    /// #[derive(GetOption)]
+    /// pub enum Message { Incoming(Event), Outgoing(String) }
+    ///
+    /// #[derive(GetOption)]
+    /// pub enum Event { Payment(Payment), Ping }
+    ///
+    /// #[derive(GetOption)]
+    /// pub enum Payment { Cash(u32), Card(Card) }
+    ///
+    /// #[derive(GetOption)]
+    /// pub enum Card { Debit(u16), Credit(u64) }
+    ///
+    /// impl GetOptionFifth<Message, Event, Payment, Card, u16> for Envelope {}
+    ///
+    /// assert_eq!(
+    ///     Some(1234),
+    ///     Envelope::Signed(Message::Incoming(Event::Payment(Payment::Card(Card::Debit(1234))))).get_option_fifth()
+    /// );
+    /// assert_eq!(None, Envelope::Unsigned(String::from("ack")).get_option_fifth());
+    ///
+    ///```
+    pub trait GetOptionFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        where
+            LevelFour: GetOption<LevelFive>,
+            LevelThree: GetOption<LevelFour>,
+            LevelTwo: GetOption<LevelThree>,
+            LevelOne: GetOption<LevelTwo>,
+            Self: GetOption<LevelOne> + Sized {
+        fn get_option_fifth(self) -> Option<LevelFive> {
+            self.get_option()
+                .and_then(|level_one| level_one.get_option())
+                .and_then(|level_two| level_two.get_option())
+                .and_then(|level_three| level_three.get_option())
+                .and_then(|level_four| level_four.get_option())
+        }
+    }
+
+    /// A variant of [`SetOption`], reaching five levels deep in the data structure.
+    ///
+    /// Every level but the last one is reached with [`GetOption`], then rebuilt with [`ReverseGet`] after the update.
+    ///
+    /// [`SetOption`]: ../trait.SetOption.html
+    /// [`GetOption`]: ../trait.GetOption.html
+    /// [`ReverseGet`]: ../trait.ReverseGet.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Debug, GetOption, SetOption, PartialEq)]
+    /// pub enum Envelope { Signed(Message), Unsigned(String) }
+    ///
+    /// #[derive(Debug, GetOption, SetOption, PartialEq)]
+    /// pub enum Message { Incoming(Event), Outgoing(String) }
+    ///
+    /// #[derive(Debug, GetOption, SetOption, PartialEq)]
+    /// pub enum Event { Payment(Payment), Ping }
+    ///
+    /// #[derive(Debug, GetOption, SetOption, PartialEq)]
+    /// pub enum Payment { Cash(u32), Card(Card) }
+    ///
+    /// #[derive(Debug, GetOption, SetOption, PartialEq)]
+    /// pub enum Card { Debit(u16), Credit(u64) }
+    ///
+    /// impl ReverseGet<Message> for Envelope {
+    ///     fn reverse_get(value: Message) -> Self { Envelope::Signed(value) }
+    /// }
+    ///
+    /// impl ReverseGet<Event> for Message {
+    ///     fn reverse_get(value: Event) -> Self { Message::Incoming(value) }
+    /// }
+    ///
+    /// impl ReverseGet<Payment> for Event {
+    ///     fn reverse_get(value: Payment) -> Self { Event::Payment(value) }
+    /// }
+    ///
+    /// impl ReverseGet<Card> for Payment {
+    ///     fn reverse_get(value: Card) -> Self { Payment::Card(value) }
+    /// }
+    ///
+    /// impl SetOptionFifth<Message, Event, Payment, Card, u16> for Envelope {}
+    ///
+    /// assert_eq!(
+    ///     Some(Envelope::Signed(Message::Incoming(Event::Payment(Payment::Card(Card::Debit(42)))))),
+    ///     Envelope::Signed(Message::Incoming(Event::Payment(Payment::Card(Card::Debit(1234))))).set_option_fifth(42)
+    /// );
+    /// assert_eq!(None, Envelope::Unsigned(String::from("ack")).set_option_fifth(42));
+    ///
+    ///```
+    pub trait SetOptionFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        where
+            LevelFour: SetOption<LevelFive>,
+            LevelThree: GetOption<LevelFour> + ReverseGet<LevelFour>,
+            LevelTwo: GetOption<LevelThree> + ReverseGet<LevelThree>,
+            LevelOne: GetOption<LevelTwo> + ReverseGet<LevelTwo>,
+            Self: GetOption<LevelOne> + ReverseGet<LevelOne> + Sized {
+        fn set_option_fifth(self, new_value: LevelFive) -> Option<Self> {
+            self.get_option()
+                .and_then(|level_one| level_one.get_option()
+                    .and_then(|level_two| level_two.get_option()
+                        .and_then(|level_three| level_three.get_option()
+                            .and_then(|level_four| level_four.set_option(new_value))
+                            .map(<LevelThree as ReverseGet<LevelFour>>::reverse_get))
+                        .map(<LevelTwo as ReverseGet<LevelThree>>::reverse_get))
+                    .map(<LevelOne as ReverseGet<LevelTwo>>::reverse_get))
+                .map(<Self as ReverseGet<LevelOne>>::reverse_get)
+        }
+    }
+
+    /// A variant of [`ModifyOption`], reaching five levels deep in the data structure.
+    ///
+    /// Every level but the last one is reached with [`GetOption`], then rebuilt with [`ReverseGet`] after the update.
+    ///
+    /// [`ModifyOption`]: ../trait.ModifyOption.html
+    /// [`GetOption`]: ../trait.GetOption.html
+    /// [`ReverseGet`]: ../trait.ReverseGet.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Debug, GetOption, ModifyOption, PartialEq)]
+    /// pub enum Envelope { Signed(Message), Unsigned(String) }
+    ///
+    /// #[derive(Debug, GetOption, ModifyOption, PartialEq)]
+    /// pub enum Message { Incoming(Event), Outgoing(String) }
+    ///
+    /// #[derive(Debug, GetOption, ModifyOption, PartialEq)]
+    /// pub enum Event { Payment(Payment), Ping }
+    ///
+    /// #[derive(Debug, GetOption, ModifyOption, PartialEq)]
+    /// pub enum Payment { Cash(u32), Card(Card) }
+    ///
+    /// #[derive(Debug, GetOption, ModifyOption, PartialEq)]
+    /// pub enum Card { Debit(u16), Credit(u64) }
+    ///
+    /// impl ReverseGet<Message> for Envelope {
+    ///     fn reverse_get(value: Message) -> Self { Envelope::Signed(value) }
+    /// }
+    ///
+    /// impl ReverseGet<Event> for Message {
+    ///     fn reverse_get(value: Event) -> Self { Message::Incoming(value) }
+    /// }
+    ///
+    /// impl ReverseGet<Payment> for Event {
+    ///     fn reverse_get(value: Payment) -> Self { Event::Payment(value) }
+    /// }
+    ///
+    /// impl ReverseGet<Card> for Payment {
+    ///     fn reverse_get(value: Card) -> Self { Payment::Card(value) }
+    /// }
+    ///
+    /// impl ModifyOptionFifth<Message, Event, Payment, Card, u16> for Envelope {}
+    ///
+    /// assert_eq!(
+    ///     Some(Envelope::Signed(Message::Incoming(Event::Payment(Payment::Card(Card::Debit(1235)))))),
+    ///     Envelope::Signed(Message::Incoming(Event::Payment(Payment::Card(Card::Debit(1234))))).modify_option_fifth(|n| n + 1)
+    /// );
+    /// assert_eq!(None, Envelope::Unsigned(String::from("ack")).modify_option_fifth(|n| n + 1));
+    ///
+    ///```
+    pub trait ModifyOptionFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        where
+            LevelFour: ModifyOption<LevelFive>,
+            LevelThree: GetOption<LevelFour> + ReverseGet<LevelFour>,
+            LevelTwo: GetOption<LevelThree> + ReverseGet<LevelThree>,
+            LevelOne: GetOption<LevelTwo> + ReverseGet<LevelTwo>,
+            Self: GetOption<LevelOne> + ReverseGet<LevelOne> + Sized {
+        fn modify_option_fifth(self, f: impl FnOnce(LevelFive) -> LevelFive) -> Option<Self> {
+            self.get_option()
+                .and_then(|level_one| level_one.get_option()
+                    .and_then(|level_two| level_two.get_option()
+                        .and_then(|level_three| level_three.get_option()
+                            .and_then(|level_four| level_four.modify_option(f))
+                            .map(<LevelThree as ReverseGet<LevelFour>>::reverse_get))
+                        .map(<LevelTwo as ReverseGet<LevelThree>>::reverse_get))
+                    .map(<LevelOne as ReverseGet<LevelTwo>>::reverse_get))
+                .map(<Self as ReverseGet<LevelOne>>::reverse_get)
+        }
+    }
+
+    /// A variant of [`GetRefOption`], reaching two levels deep in the data structure.
+    ///
+    /// [`GetRefOption`]: ../trait.GetRefOption.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub enum Event { Payment(Payment), Ping }
+    ///
+    /// pub enum Payment { Cash(u32), Voucher(String) }
+    ///
+    /// impl GetRefOption<Payment> for Event {
+    ///     fn get_ref_option(&self) -> Option<&Payment> {
+    ///         match self { Event::Payment(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl GetRefOption<u32> for Payment {
+    ///     fn get_ref_option(&self) -> Option<&u32> {
+    ///         match self { Payment::Cash(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl<'a> GetRefOptionSecond<'a, Payment, u32> for Event {}
+    ///
+    /// let value = Event::Payment(Payment::Cash(1234));
+    ///
+    /// assert_eq!(Some(&1234), value.get_ref_option_second());
+    /// assert_eq!(None, Event::Ping.get_ref_option_second());
+    ///
+    ///```
+    pub trait GetRefOptionSecond<'a, LevelOne, LevelTwo>
+        where
+            LevelOne: GetRefOption<LevelTwo> + 'a,
+            Self: GetRefOption<LevelOne> + 'a {
+        fn get_ref_option_second(&'a self) -> Option<&'a LevelTwo> {
+            self.get_ref_option()
+                .and_then(|level_one| level_one.get_ref_option())
+        }
+    }
+
+    /// A variant of [`GetRefOption`], reaching three levels deep in the data structure.
+    ///
+    /// [`GetRefOption`]: ../trait.GetRefOption.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub enum Event { Payment(Payment), Ping }
+    ///
+    /// pub enum Payment { Card(Card), Cash(u32) }
+    ///
+    /// pub enum Card { Debit(u16), Credit(u64) }
+    ///
+    /// impl GetRefOption<Payment> for Event {
+    ///     fn get_ref_option(&self) -> Option<&Payment> {
+    ///         match self { Event::Payment(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl GetRefOption<Card> for Payment {
+    ///     fn get_ref_option(&self) -> Option<&Card> {
+    ///         match self { Payment::Card(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl GetRefOption<u16> for Card {
+    ///     fn get_ref_option(&self) -> Option<&u16> {
+    ///         match self { Card::Debit(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl<'a> GetRefOptionThird<'a, Payment, Card, u16> for Event {}
+    ///
+    /// let value = Event::Payment(Payment::Card(Card::Debit(1234)));
+    ///
+    /// assert_eq!(Some(&1234), value.get_ref_option_third());
+    /// assert_eq!(None, Event::Ping.get_ref_option_third());
+    ///
+    ///```
+    pub trait GetRefOptionThird<'a, LevelOne, LevelTwo, LevelThree>
+        where
+            LevelTwo: GetRefOption<LevelThree> + 'a,
+            LevelOne: GetRefOption<LevelTwo> + 'a,
+            Self: GetRefOption<LevelOne> + 'a {
+        fn get_ref_option_third(&'a self) -> Option<&'a LevelThree> {
+            self.get_ref_option()
+                .and_then(|level_one| level_one.get_ref_option())
+                .and_then(|level_two| level_two.get_ref_option())
+        }
+    }
+
+    /// A variant of [`GetRefOption`], reaching four levels deep in the data structure.
+    ///
+    /// [`GetRefOption`]: ../trait.GetRefOption.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub enum Message { Incoming(Event), Outgoing(String) }
+    ///
+    /// pub enum Event { Payment(Payment), Ping }
+    ///
+    /// pub enum Payment { Card(Card), Cash(u32) }
+    ///
+    /// pub enum Card { Debit(u16), Credit(u64) }
+    ///
+    /// impl GetRefOption<Event> for Message {
+    ///     fn get_ref_option(&self) -> Option<&Event> {
+    ///         match self { Message::Incoming(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl GetRefOption<Payment> for Event {
+    ///     fn get_ref_option(&self) -> Option<&Payment> {
+    ///         match self { Event::Payment(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl GetRefOption<Card> for Payment {
+    ///     fn get_ref_option(&self) -> Option<&Card> {
+    ///         match self { Payment::Card(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl GetRefOption<u16> for Card {
+    ///     fn get_ref_option(&self) -> Option<&u16> {
+    ///         match self { Card::Debit(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl<'a> GetRefOptionFourth<'a, Event, Payment, Card, u16> for Message {}
+    ///
+    /// let value = Message::Incoming(Event::Payment(Payment::Card(Card::Debit(1234))));
+    ///
+    /// assert_eq!(Some(&1234), value.get_ref_option_fourth());
+    /// assert_eq!(None, Message::Outgoing(String::from("ack")).get_ref_option_fourth());
+    ///
+    ///```
+    pub trait GetRefOptionFourth<'a, LevelOne, LevelTwo, LevelThree, LevelFour>
+        where
+            LevelThree: GetRefOption<LevelFour> + 'a,
+            LevelTwo: GetRefOption<LevelThree> + 'a,
+            LevelOne: GetRefOption<LevelTwo> + 'a,
+            Self: GetRefOption<LevelOne> + 'a {
+        fn get_ref_option_fourth(&'a self) -> Option<&'a LevelFour> {
+            self.get_ref_option()
+                .and_then(|level_one| level_one.get_ref_option())
+                .and_then(|level_two| level_two.get_ref_option())
+                .and_then(|level_three| level_three.get_ref_option())
+        }
+    }
+
+    /// A variant of [`GetRefOption`], reaching five levels deep in the data structure.
+    ///
+    /// [`GetRefOption`]: ../trait.GetRefOption.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub enum Envelope { Signed(Message), Unsigned(String) }
+    ///
+    /// pub enum Message { Incoming(Event), Outgoing(String) }
+    ///
+    /// pub enum Event { Payment(Payment), Ping }
+    ///
+    /// pub enum Payment { Card(Card), Cash(u32) }
+    ///
+    /// pub enum Card { Debit(u16), Credit(u64) }
+    ///
+    /// impl GetRefOption<Message> for Envelope {
+    ///     fn get_ref_option(&self) -> Option<&Message> {
+    ///         match self { Envelope::Signed(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl GetRefOption<Event> for Message {
+    ///     fn get_ref_option(&self) -> Option<&Event> {
+    ///         match self { Message::Incoming(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl GetRefOption<Payment> for Event {
+    ///     fn get_ref_option(&self) -> Option<&Payment> {
+    ///         match self { Event::Payment(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl GetRefOption<Card> for Payment {
+    ///     fn get_ref_option(&self) -> Option<&Card> {
+    ///         match self { Payment::Card(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl GetRefOption<u16> for Card {
+    ///     fn get_ref_option(&self) -> Option<&u16> {
+    ///         match self { Card::Debit(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl<'a> GetRefOptionFifth<'a, Message, Event, Payment, Card, u16> for Envelope {}
+    ///
+    /// let value = Envelope::Signed(Message::Incoming(Event::Payment(Payment::Card(Card::Debit(1234)))));
+    ///
+    /// assert_eq!(Some(&1234), value.get_ref_option_fifth());
+    /// assert_eq!(None, Envelope::Unsigned(String::from("ack")).get_ref_option_fifth());
+    ///
+    ///```
+    pub trait GetRefOptionFifth<'a, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        where
+            LevelFour: GetRefOption<LevelFive> + 'a,
+            LevelThree: GetRefOption<LevelFour> + 'a,
+            LevelTwo: GetRefOption<LevelThree> + 'a,
+            LevelOne: GetRefOption<LevelTwo> + 'a,
+            Self: GetRefOption<LevelOne> + 'a {
+        fn get_ref_option_fifth(&'a self) -> Option<&'a LevelFive> {
+            self.get_ref_option()
+                .and_then(|level_one| level_one.get_ref_option())
+                .and_then(|level_two| level_two.get_ref_option())
+                .and_then(|level_three| level_three.get_ref_option())
+                .and_then(|level_four| level_four.get_ref_option())
+        }
+    }
+
+    /// A variant of [`GetMutOption`], reaching two levels deep in the data structure.
+    ///
+    /// [`GetMutOption`]: ../trait.GetMutOption.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Debug, PartialEq)]
+    /// pub enum Event { Payment(Payment), Ping }
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// pub enum Payment { Cash(u32), Voucher(String) }
+    ///
+    /// impl GetMutOption<Payment> for Event {
+    ///     fn get_mut_option(&mut self) -> Option<&mut Payment> {
+    ///         match self { Event::Payment(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl GetMutOption<u32> for Payment {
+    ///     fn get_mut_option(&mut self) -> Option<&mut u32> {
+    ///         match self { Payment::Cash(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl<'a> GetMutOptionSecond<'a, Payment, u32> for Event {}
+    ///
+    /// let mut value = Event::Payment(Payment::Cash(1234));
+    ///
+    /// if let Some(number) = value.get_mut_option_second() { *number += 1; }
+    ///
+    /// assert_eq!(Event::Payment(Payment::Cash(1235)), value);
+    /// assert_eq!(None, Event::Ping.get_mut_option_second());
+    ///
+    ///```
+    pub trait GetMutOptionSecond<'a, LevelOne, LevelTwo>
+        where
+            LevelOne: GetMutOption<LevelTwo> + 'a,
+            Self: GetMutOption<LevelOne> + 'a {
+        fn get_mut_option_second(&'a mut self) -> Option<&'a mut LevelTwo> {
+            self.get_mut_option()
+                .and_then(|level_one| level_one.get_mut_option())
+        }
+    }
+
+    /// A variant of [`GetMutOption`], reaching three levels deep in the data structure.
+    ///
+    /// [`GetMutOption`]: ../trait.GetMutOption.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Debug, PartialEq)]
+    /// pub enum Event { Payment(Payment), Ping }
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// pub enum Payment { Card(Card), Cash(u32) }
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// pub enum Card { Debit(u16), Credit(u64) }
+    ///
+    /// impl GetMutOption<Payment> for Event {
+    ///     fn get_mut_option(&mut self) -> Option<&mut Payment> {
+    ///         match self { Event::Payment(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl GetMutOption<Card> for Payment {
+    ///     fn get_mut_option(&mut self) -> Option<&mut Card> {
+    ///         match self { Payment::Card(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl GetMutOption<u16> for Card {
+    ///     fn get_mut_option(&mut self) -> Option<&mut u16> {
+    ///         match self { Card::Debit(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl<'a> GetMutOptionThird<'a, Payment, Card, u16> for Event {}
+    ///
+    /// let mut value = Event::Payment(Payment::Card(Card::Debit(1234)));
+    ///
+    /// if let Some(number) = value.get_mut_option_third() { *number += 1; }
+    ///
+    /// assert_eq!(Event::Payment(Payment::Card(Card::Debit(1235))), value);
+    /// assert_eq!(None, Event::Ping.get_mut_option_third());
+    ///
+    ///```
+    pub trait GetMutOptionThird<'a, LevelOne, LevelTwo, LevelThree>
+        where
+            LevelTwo: GetMutOption<LevelThree> + 'a,
+            LevelOne: GetMutOption<LevelTwo> + 'a,
+            Self: GetMutOption<LevelOne> + 'a {
+        fn get_mut_option_third(&'a mut self) -> Option<&'a mut LevelThree> {
+            self.get_mut_option()
+                .and_then(|level_one| level_one.get_mut_option())
+                .and_then(|level_two| level_two.get_mut_option())
+        }
+    }
+
+    /// A variant of [`GetMutOption`], reaching four levels deep in the data structure.
+    ///
+    /// [`GetMutOption`]: ../trait.GetMutOption.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Debug, PartialEq)]
+    /// pub enum Message { Incoming(Event), Outgoing(String) }
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// pub enum Event { Payment(Payment), Ping }
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// pub enum Payment { Card(Card), Cash(u32) }
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// pub enum Card { Debit(u16), Credit(u64) }
+    ///
+    /// impl GetMutOption<Event> for Message {
+    ///     fn get_mut_option(&mut self) -> Option<&mut Event> {
+    ///         match self { Message::Incoming(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl GetMutOption<Payment> for Event {
+    ///     fn get_mut_option(&mut self) -> Option<&mut Payment> {
+    ///         match self { Event::Payment(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl GetMutOption<Card> for Payment {
+    ///     fn get_mut_option(&mut self) -> Option<&mut Card> {
+    ///         match self { Payment::Card(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl GetMutOption<u16> for Card {
+    ///     fn get_mut_option(&mut self) -> Option<&mut u16> {
+    ///         match self { Card::Debit(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl<'a> GetMutOptionFourth<'a, Event, Payment, Card, u16> for Message {}
+    ///
+    /// let mut value = Message::Incoming(Event::Payment(Payment::Card(Card::Debit(1234))));
+    ///
+    /// if let Some(number) = value.get_mut_option_fourth() { *number += 1; }
+    ///
+    /// assert_eq!(Message::Incoming(Event::Payment(Payment::Card(Card::Debit(1235)))), value);
+    /// assert_eq!(None, Message::Outgoing(String::from("ack")).get_mut_option_fourth());
+    ///
+    ///```
+    pub trait GetMutOptionFourth<'a, LevelOne, LevelTwo, LevelThree, LevelFour>
+        where
+            LevelThree: GetMutOption<LevelFour> + 'a,
+            LevelTwo: GetMutOption<LevelThree> + 'a,
+            LevelOne: GetMutOption<LevelTwo> + 'a,
+            Self: GetMutOption<LevelOne> + 'a {
+        fn get_mut_option_fourth(&'a mut self) -> Option<&'a mut LevelFour> {
+            self.get_mut_option()
+                .and_then(|level_one| level_one.get_mut_option())
+                .and_then(|level_two| level_two.get_mut_option())
+                .and_then(|level_three| level_three.get_mut_option())
+        }
+    }
+
+    /// A variant of [`GetMutOption`], reaching five levels deep in the data structure.
+    ///
+    /// [`GetMutOption`]: ../trait.GetMutOption.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Debug, PartialEq)]
+    /// pub enum Envelope { Signed(Message), Unsigned(String) }
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// pub enum Message { Incoming(Event), Outgoing(String) }
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// pub enum Event { Payment(Payment), Ping }
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// pub enum Payment { Card(Card), Cash(u32) }
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// pub enum Card { Debit(u16), Credit(u64) }
+    ///
+    /// impl GetMutOption<Message> for Envelope {
+    ///     fn get_mut_option(&mut self) -> Option<&mut Message> {
+    ///         match self { Envelope::Signed(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl GetMutOption<Event> for Message {
+    ///     fn get_mut_option(&mut self) -> Option<&mut Event> {
+    ///         match self { Message::Incoming(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl GetMutOption<Payment> for Event {
+    ///     fn get_mut_option(&mut self) -> Option<&mut Payment> {
+    ///         match self { Event::Payment(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl GetMutOption<Card> for Payment {
+    ///     fn get_mut_option(&mut self) -> Option<&mut Card> {
+    ///         match self { Payment::Card(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl GetMutOption<u16> for Card {
+    ///     fn get_mut_option(&mut self) -> Option<&mut u16> {
+    ///         match self { Card::Debit(value) => Some(value), _ => None }
+    ///     }
+    /// }
+    ///
+    /// impl<'a> GetMutOptionFifth<'a, Message, Event, Payment, Card, u16> for Envelope {}
+    ///
+    /// let mut value = Envelope::Signed(Message::Incoming(Event::Payment(Payment::Card(Card::Debit(1234)))));
+    ///
+    /// if let Some(number) = value.get_mut_option_fifth() { *number += 1; }
+    ///
+    /// assert_eq!(Envelope::Signed(Message::Incoming(Event::Payment(Payment::Card(Card::Debit(1235))))), value);
+    /// assert_eq!(None, Envelope::Unsigned(String::from("ack")).get_mut_option_fifth());
+    ///
+    ///```
+    pub trait GetMutOptionFifth<'a, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        where
+            LevelFour: GetMutOption<LevelFive> + 'a,
+            LevelThree: GetMutOption<LevelFour> + 'a,
+            LevelTwo: GetMutOption<LevelThree> + 'a,
+            LevelOne: GetMutOption<LevelTwo> + 'a,
+            Self: GetMutOption<LevelOne> + 'a {
+        fn get_mut_option_fifth(&'a mut self) -> Option<&'a mut LevelFive> {
+            self.get_mut_option()
+                .and_then(|level_one| level_one.get_mut_option())
+                .and_then(|level_two| level_two.get_mut_option())
+                .and_then(|level_three| level_three.get_mut_option())
+                .and_then(|level_four| level_four.get_mut_option())
+        }
+    }
+
+    /// A variant of [`GetAffine`], reaching two levels deep in the data structure.
+    ///
+    /// Each level can be a lens ([`Get`], [`GetRef`]) or a prism ([`GetOption`]), the `Via` type parameters tell which one (see [`GetAffine`] for the marker types).
+    ///
+    /// [`GetAffine`]: ../trait.GetAffine.html
+    /// [`Get`]: ../trait.Get.html
+    /// [`GetRef`]: ../trait.GetRef.html
+    /// [`GetOption`]: ../trait.GetOption.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(GetOption)]
+    /// pub enum Payment { Card(Card), Cash(u32) }
+    ///
+    /// #[derive(Get)]
+    /// pub struct Card { pub last4: u16, pub holder: String }
+    ///
+    /// impl GetAffineSecond<
+    ///     Card, u16,
+    ///     ViaGetOption, ViaGet,
+    /// > for Payment {}
+    ///
+    /// let card = || Card { last4: 1234, holder: String::from("John Doe") };
+    ///
+    /// assert_eq!(Some(1234), Payment::Card(card()).get_affine_second());
+    /// assert_eq!(None, Payment::Cash(20).get_affine_second());
+    ///
+    ///```
+    pub trait GetAffineSecond<LevelOne, LevelTwo, ViaOne, ViaTwo>
+        where
+            LevelOne: GetAffine<LevelTwo, ViaTwo>,
+            Self: GetAffine<LevelOne, ViaOne> + Sized {
+        fn get_affine_second(self) -> Option<LevelTwo> {
+            self.get_affine()
+                .and_then(|level_one| level_one.get_affine())
+        }
+    }
+
+    /// A variant of [`GetAffine`], reaching three levels deep in the data structure.
+    ///
+    /// Each level can be a lens ([`Get`], [`GetRef`]) or a prism ([`GetOption`]), the `Via` type parameters tell which one (see [`GetAffine`] for the marker types).
+    ///
+    /// [`GetAffine`]: ../trait.GetAffine.html
+    /// [`Get`]: ../trait.Get.html
+    /// [`GetRef`]: ../trait.GetRef.html
+    /// [`GetOption`]: ../trait.GetOption.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Get)]
+    /// pub struct Order { pub id: u32, pub payment: Option<Payment> }
+    ///
+    /// #[derive(GetOption)]
+    /// pub enum Payment { Card(Card), Cash(u32) }
+    ///
+    /// #[derive(Get)]
+    /// pub struct Card { pub last4: u16, pub holder: String }
+    ///
+    /// impl GetAffineThird<
+    ///     Option<Payment>, Payment, u32,
+    ///     ViaGet, ViaGetOption, ViaGetOption,
+    /// > for Order {}
+    ///
+    /// let card = || Card { last4: 1234, holder: String::from("John Doe") };
+    ///
+    /// assert_eq!(
+    ///     Some(20),
+    ///     Order { id: 1, payment: Some(Payment::Cash(20)) }.get_affine_third()
+    /// );
+    /// assert_eq!(None, Order { id: 2, payment: None }.get_affine_third());
+    ///
+    ///```
+    pub trait GetAffineThird<LevelOne, LevelTwo, LevelThree, ViaOne, ViaTwo, ViaThree>
+        where
+            LevelTwo: GetAffine<LevelThree, ViaThree>,
+            LevelOne: GetAffine<LevelTwo, ViaTwo>,
+            Self: GetAffine<LevelOne, ViaOne> + Sized {
+        fn get_affine_third(self) -> Option<LevelThree> {
+            self.get_affine()
+                .and_then(|level_one| level_one.get_affine())
+                .and_then(|level_two| level_two.get_affine())
+        }
+    }
+
+    /// A variant of [`GetAffine`], reaching four levels deep in the data structure.
+    ///
+    /// Each level can be a lens ([`Get`], [`GetRef`]) or a prism ([`GetOption`]), the `Via` type parameters tell which one (see [`GetAffine`] for the marker types).
+    ///
+    /// [`GetAffine`]: ../trait.GetAffine.html
+    /// [`Get`]: ../trait.Get.html
+    /// [`GetRef`]: ../trait.GetRef.html
+    /// [`GetOption`]: ../trait.GetOption.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Get)]
+    /// pub struct Order { pub id: u32, pub payment: Option<Payment> }
+    ///
+    /// #[derive(GetOption)]
+    /// pub enum Payment { Card(Card), Cash(u32) }
+    ///
+    /// #[derive(Get)]
+    /// pub struct Card { pub last4: u16, pub holder: String }
+    ///
+    /// impl GetAffineFourth<
+    ///     Option<Payment>, Payment, Card, u16,
+    ///     ViaGet, ViaGetOption, ViaGetOption, ViaGet,
+    /// > for Order {}
+    ///
+    /// let card = || Card { last4: 1234, holder: String::from("John Doe") };
+    ///
+    /// assert_eq!(
+    ///     Some(1234),
+    ///     Order { id: 1, payment: Some(Payment::Card(card())) }.get_affine_fourth()
+    /// );
+    /// assert_eq!(None, Order { id: 2, payment: Some(Payment::Cash(20)) }.get_affine_fourth());
+    ///
+    ///```
+    pub trait GetAffineFourth<LevelOne, LevelTwo, LevelThree, LevelFour, ViaOne, ViaTwo, ViaThree, ViaFour>
+        where
+            LevelThree: GetAffine<LevelFour, ViaFour>,
+            LevelTwo: GetAffine<LevelThree, ViaThree>,
+            LevelOne: GetAffine<LevelTwo, ViaTwo>,
+            Self: GetAffine<LevelOne, ViaOne> + Sized {
+        fn get_affine_fourth(self) -> Option<LevelFour> {
+            self.get_affine()
+                .and_then(|level_one| level_one.get_affine())
+                .and_then(|level_two| level_two.get_affine())
+                .and_then(|level_three| level_three.get_affine())
+        }
+    }
+
+    /// A variant of [`GetAffine`], reaching five levels deep in the data structure.
+    ///
+    /// Each level can be a lens ([`Get`], [`GetRef`]) or a prism ([`GetOption`]), the `Via` type parameters tell which one (see [`GetAffine`] for the marker types).
+    ///
+    /// [`GetAffine`]: ../trait.GetAffine.html
+    /// [`Get`]: ../trait.Get.html
+    /// [`GetRef`]: ../trait.GetRef.html
+    /// [`GetOption`]: ../trait.GetOption.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(GetRef)]
+    /// pub struct Customer { pub name: String, pub order: Order }
+    ///
+    /// #[derive(Clone, Get)]
+    /// pub struct Order { pub id: u32, pub payment: Option<Payment> }
+    ///
+    /// #[derive(Clone, GetOption)]
+    /// pub enum Payment { Card(Card), Cash(u32) }
+    ///
+    /// #[derive(Clone, Get)]
+    /// pub struct Card { pub last4: u16, pub holder: String }
+    ///
+    /// impl GetAffineFifth<
+    ///     Order, Option<Payment>, Payment, Card, u16,
+    ///     ViaGetRef, ViaGet, ViaGetOption, ViaGetOption, ViaGet,
+    /// > for Customer {}
+    ///
+    /// let card = || Card { last4: 1234, holder: String::from("John Doe") };
+    ///
+    /// assert_eq!(
+    ///     Some(1234),
+    ///     Customer { name: String::from("Jane"), order: Order { id: 1, payment: Some(Payment::Card(card())) } }.get_affine_fifth()
+    /// );
+    /// assert_eq!(
+    ///     None,
+    ///     Customer { name: String::from("Jim"), order: Order { id: 2, payment: None } }.get_affine_fifth()
+    /// );
+    ///
+    ///```
+    pub trait GetAffineFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive, ViaOne, ViaTwo, ViaThree, ViaFour, ViaFive>
+        where
+            LevelFour: GetAffine<LevelFive, ViaFive>,
+            LevelThree: GetAffine<LevelFour, ViaFour>,
+            LevelTwo: GetAffine<LevelThree, ViaThree>,
+            LevelOne: GetAffine<LevelTwo, ViaTwo>,
+            Self: GetAffine<LevelOne, ViaOne> + Sized {
+        fn get_affine_fifth(self) -> Option<LevelFive> {
+            self.get_affine()
+                .and_then(|level_one| level_one.get_affine())
+                .and_then(|level_two| level_two.get_affine())
+                .and_then(|level_three| level_three.get_affine())
+                .and_then(|level_four| level_four.get_affine())
+        }
+    }
+
+    /// A variant of [`GetIndex`], reaching two levels deep in the data structure: the element at the given position, then its fields.
+    ///
+    /// It is implemented for `Vec`, `VecDeque`, arrays, and boxed slices, so there is no need to write impl blocks.
+    ///
+    /// [`GetIndex`]: ../trait.GetIndex.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(GetRef)]
+    /// pub struct Company { pub name: String, pub headcount: u32 }
+    ///
+    /// let element = || Company { name: String::from("Acme Corporation"), headcount: 100 };
+    ///
+    /// let elements = vec![element(), element()];
+    ///
+    /// let target = GetIndexSecond::<Company, u32>::get_index_second(&elements, 1);
+    ///
+    /// assert_eq!(Some(&100), target);
+    /// assert!(GetIndexSecond::<Company, u32>::get_index_second(&elements, 2).is_none());
+    ///
+    ///```
+    pub trait GetIndexSecond<'a, LevelOne, LevelTwo>
+        where
+            LevelOne: GetRef<LevelTwo> + 'a,
+            Self: GetIndex<LevelOne> {
+        fn get_index_second(&'a self, index: usize) -> Option<&'a LevelTwo> {
+            self.get_index(index)
+                .map(|level_one| level_one.get_ref())
+        }
+    }
+
+    /// A variant of [`SetIndex`], reaching two levels deep in the data structure: the element at the given position, then its fields.
+    ///
+    /// It is implemented for `Vec`, `VecDeque`, arrays, and boxed slices, so there is no need to write impl blocks.
+    ///
+    /// [`SetIndex`]: ../trait.SetIndex.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Set, Modify)]
+    /// pub struct Company { pub name: String, pub headcount: u32 }
+    ///
+    /// let element = || Company { name: String::from("Acme Corporation"), headcount: 100 };
+    ///
+    /// let elements = vec![element(), element()];
+    ///
+    /// let updated = elements.set_index_second(1, 200).unwrap();
+    ///
+    /// assert_eq!(200, updated[1].headcount);
+    /// assert_eq!(100, updated[0].headcount);
+    /// assert!(updated.set_index_second(2, 200).is_none());
+    ///
+    ///```
+    pub trait SetIndexSecond<LevelOne, LevelTwo>
+        where
+            LevelOne: Set<LevelTwo>,
+            Self: ModifyIndex<LevelOne> {
+        fn set_index_second(self, index: usize, new_value: LevelTwo) -> Option<Self> {
+            self.modify_index(index, |level_one| level_one.set(new_value))
+        }
+    }
+
+    /// A variant of [`ModifyIndex`], reaching two levels deep in the data structure: the element at the given position, then its fields.
+    ///
+    /// It is implemented for `Vec`, `VecDeque`, arrays, and boxed slices, so there is no need to write impl blocks.
+    ///
+    /// [`ModifyIndex`]: ../trait.ModifyIndex.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Modify)]
+    /// pub struct Company { pub name: String, pub headcount: u32 }
+    ///
+    /// let element = || Company { name: String::from("Acme Corporation"), headcount: 100 };
+    ///
+    /// let elements = vec![element(), element()];
+    ///
+    /// let updated = elements.modify_index_second(0, |headcount: u32| headcount + 1).unwrap();
+    ///
+    /// assert_eq!(101, updated[0].headcount);
+    /// assert_eq!(100, updated[1].headcount);
+    /// assert!(updated.modify_index_second(2, |headcount: u32| headcount + 1).is_none());
+    ///
+    ///```
+    pub trait ModifyIndexSecond<LevelOne, LevelTwo>
+        where
+            LevelOne: Modify<LevelTwo>,
+            Self: ModifyIndex<LevelOne> {
+        fn modify_index_second(self, index: usize, f: impl FnOnce(LevelTwo) -> LevelTwo) -> Option<Self> {
+            self.modify_index(index, |level_one| level_one.modify(f))
+        }
+    }
+
+    /// A variant of [`GetIndex`], reaching three levels deep in the data structure: the element at the given position, then its fields.
+    ///
+    /// It is implemented for `Vec`, `VecDeque`, arrays, and boxed slices, so there is no need to write impl blocks.
+    ///
+    /// [`GetIndex`]: ../trait.GetIndex.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(GetRef)]
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// #[derive(GetRef)]
+    /// pub struct Company { pub name: String, pub headcount: u32 }
+    ///
+    /// let element = || Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         headcount: 100,
+    ///         }
+    ///     };
+    ///
+    /// let elements = vec![element(), element()];
+    ///
+    /// let target = GetIndexThird::<Employee, Company, u32>::get_index_third(&elements, 1);
+    ///
+    /// assert_eq!(Some(&100), target);
+    /// assert!(GetIndexThird::<Employee, Company, u32>::get_index_third(&elements, 2).is_none());
+    ///
+    ///```
+    pub trait GetIndexThird<'a, LevelOne, LevelTwo, LevelThree>
+        where
+            LevelTwo: GetRef<LevelThree> + 'a,
+            LevelOne: GetRef<LevelTwo> + 'a,
+            Self: GetIndex<LevelOne> {
+        fn get_index_third(&'a self, index: usize) -> Option<&'a LevelThree> {
+            self.get_index(index)
+                .map(|level_one| level_one.get_ref().get_ref())
+        }
+    }
+
+    /// A variant of [`SetIndex`], reaching three levels deep in the data structure: the element at the given position, then its fields.
+    ///
+    /// It is implemented for `Vec`, `VecDeque`, arrays, and boxed slices, so there is no need to write impl blocks.
+    ///
+    /// [`SetIndex`]: ../trait.SetIndex.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Set, Modify)]
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// #[derive(Set, Modify)]
+    /// pub struct Company { pub name: String, pub headcount: u32 }
+    ///
+    /// let element = || Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         headcount: 100,
+    ///         }
+    ///     };
+    ///
+    /// let elements = vec![element(), element()];
+    ///
+    /// let updated = SetIndexThird::<Employee, Company, u32>::set_index_third(elements, 1, 200).unwrap();
+    ///
+    /// assert_eq!(200, updated[1].company.headcount);
+    /// assert_eq!(100, updated[0].company.headcount);
+    /// assert!(SetIndexThird::<Employee, Company, u32>::set_index_third(updated, 2, 200).is_none());
+    ///
+    ///```
+    pub trait SetIndexThird<LevelOne, LevelTwo, LevelThree>
+        where
+            LevelTwo: Set<LevelThree>,
+            LevelOne: Modify<LevelTwo>,
+            Self: ModifyIndex<LevelOne> {
+        fn set_index_third(self, index: usize, new_value: LevelThree) -> Option<Self> {
+            self.modify_index(index, |level_one| level_one.modify(|level_two| level_two.set(new_value)))
+        }
+    }
+
+    /// A variant of [`ModifyIndex`], reaching three levels deep in the data structure: the element at the given position, then its fields.
+    ///
+    /// It is implemented for `Vec`, `VecDeque`, arrays, and boxed slices, so there is no need to write impl blocks.
+    ///
+    /// [`ModifyIndex`]: ../trait.ModifyIndex.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Modify)]
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// #[derive(Modify)]
+    /// pub struct Company { pub name: String, pub headcount: u32 }
+    ///
+    /// let element = || Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         headcount: 100,
+    ///         }
+    ///     };
+    ///
+    /// let elements = vec![element(), element()];
+    ///
+    /// let updated = ModifyIndexThird::<Employee, Company, u32>::modify_index_third(elements, 0, |headcount: u32| headcount + 1).unwrap();
+    ///
+    /// assert_eq!(101, updated[0].company.headcount);
+    /// assert_eq!(100, updated[1].company.headcount);
+    /// assert!(ModifyIndexThird::<Employee, Company, u32>::modify_index_third(updated, 2, |headcount: u32| headcount + 1).is_none());
+    ///
+    ///```
+    pub trait ModifyIndexThird<LevelOne, LevelTwo, LevelThree>
+        where
+            LevelTwo: Modify<LevelThree>,
+            LevelOne: Modify<LevelTwo>,
+            Self: ModifyIndex<LevelOne> {
+        fn modify_index_third(self, index: usize, f: impl FnOnce(LevelThree) -> LevelThree) -> Option<Self> {
+            self.modify_index(index, |level_one| level_one.modify(|level_two| level_two.modify(f)))
+        }
+    }
+
+    /// A variant of [`GetIndex`], reaching four levels deep in the data structure: the element at the given position, then its fields.
+    ///
+    /// It is implemented for `Vec`, `VecDeque`, arrays, and boxed slices, so there is no need to write impl blocks.
+    ///
+    /// [`GetIndex`]: ../trait.GetIndex.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(GetRef)]
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// #[derive(GetRef)]
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// #[derive(GetRef)]
+    /// pub struct Address { pub city: String }
+    ///
+    /// let element = || Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address { city: String::from("Bar City") },
+    ///         }
+    ///     };
+    ///
+    /// let elements = vec![element(), element()];
+    ///
+    /// let target = GetIndexFourth::<Employee, Company, Address, String>::get_index_fourth(&elements, 1);
+    ///
+    /// assert_eq!(Some(&String::from("Bar City")), target);
+    /// assert!(GetIndexFourth::<Employee, Company, Address, String>::get_index_fourth(&elements, 2).is_none());
+    ///
+    ///```
+    pub trait GetIndexFourth<'a, LevelOne, LevelTwo, LevelThree, LevelFour>
+        where
+            LevelThree: GetRef<LevelFour> + 'a,
+            LevelTwo: GetRef<LevelThree> + 'a,
+            LevelOne: GetRef<LevelTwo> + 'a,
+            Self: GetIndex<LevelOne> {
+        fn get_index_fourth(&'a self, index: usize) -> Option<&'a LevelFour> {
+            self.get_index(index)
+                .map(|level_one| level_one.get_ref().get_ref().get_ref())
+        }
+    }
+
+    /// A variant of [`SetIndex`], reaching four levels deep in the data structure: the element at the given position, then its fields.
+    ///
+    /// It is implemented for `Vec`, `VecDeque`, arrays, and boxed slices, so there is no need to write impl blocks.
+    ///
+    /// [`SetIndex`]: ../trait.SetIndex.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Set, Modify)]
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// #[derive(Set, Modify)]
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// #[derive(Set, Modify)]
+    /// pub struct Address { pub city: String }
+    ///
+    /// let element = || Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address { city: String::from("Bar City") },
+    ///         }
+    ///     };
+    ///
+    /// let elements = vec![element(), element()];
+    ///
+    /// let updated = SetIndexFourth::<Employee, Company, Address, String>::set_index_fourth(elements, 1, String::from("Paris")).unwrap();
+    ///
+    /// assert_eq!(String::from("Paris"), updated[1].company.address.city);
+    /// assert_eq!(String::from("Bar City"), updated[0].company.address.city);
+    /// assert!(SetIndexFourth::<Employee, Company, Address, String>::set_index_fourth(updated, 2, String::from("Paris")).is_none());
+    ///
+    ///```
+    pub trait SetIndexFourth<LevelOne, LevelTwo, LevelThree, LevelFour>
+        where
+            LevelThree: Set<LevelFour>,
+            LevelTwo: Modify<LevelThree>,
+            LevelOne: Modify<LevelTwo>,
+            Self: ModifyIndex<LevelOne> {
+        fn set_index_fourth(self, index: usize, new_value: LevelFour) -> Option<Self> {
+            self.modify_index(index, |level_one| level_one.modify(|level_two| level_two.modify(|level_three| level_three.set(new_value))))
+        }
+    }
+
+    /// A variant of [`ModifyIndex`], reaching four levels deep in the data structure: the element at the given position, then its fields.
+    ///
+    /// It is implemented for `Vec`, `VecDeque`, arrays, and boxed slices, so there is no need to write impl blocks.
+    ///
+    /// [`ModifyIndex`]: ../trait.ModifyIndex.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Modify)]
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// #[derive(Modify)]
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// #[derive(Modify)]
+    /// pub struct Address { pub city: String }
+    ///
+    /// let element = || Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address { city: String::from("Bar City") },
+    ///         }
+    ///     };
+    ///
+    /// let elements = vec![element(), element()];
+    ///
+    /// let updated = ModifyIndexFourth::<Employee, Company, Address, String>::modify_index_fourth(elements, 0, |city: String| city.to_uppercase()).unwrap();
+    ///
+    /// assert_eq!(String::from("BAR CITY"), updated[0].company.address.city);
+    /// assert_eq!(String::from("Bar City"), updated[1].company.address.city);
+    /// assert!(ModifyIndexFourth::<Employee, Company, Address, String>::modify_index_fourth(updated, 2, |city: String| city.to_uppercase()).is_none());
+    ///
+    ///```
+    pub trait ModifyIndexFourth<LevelOne, LevelTwo, LevelThree, LevelFour>
+        where
+            LevelThree: Modify<LevelFour>,
+            LevelTwo: Modify<LevelThree>,
+            LevelOne: Modify<LevelTwo>,
+            Self: ModifyIndex<LevelOne> {
+        fn modify_index_fourth(self, index: usize, f: impl FnOnce(LevelFour) -> LevelFour) -> Option<Self> {
+            self.modify_index(index, |level_one| level_one.modify(|level_two| level_two.modify(|level_three| level_three.modify(f))))
+        }
+    }
+
+    /// A variant of [`GetIndex`], reaching five levels deep in the data structure: the element at the given position, then its fields.
+    ///
+    /// It is implemented for `Vec`, `VecDeque`, arrays, and boxed slices, so there is no need to write impl blocks.
+    ///
+    /// [`GetIndex`]: ../trait.GetIndex.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(GetRef)]
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// #[derive(GetRef)]
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// #[derive(GetRef)]
+    /// pub struct Address { pub city: String, pub street: Street }
+    ///
+    /// #[derive(GetRef)]
+    /// pub struct Street { pub number: u16, pub name: String }
+    ///
+    /// let element = || Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address {
+    ///                 city: String::from("London"),
+    ///                 street: Street {
+    ///                     number: 23,
+    ///                     name: String::from("High street"),
+    ///                 }
+    ///             },
+    ///         }
+    ///     };
+    ///
+    /// let elements = vec![element(), element()];
+    ///
+    /// let target = GetIndexFifth::<Employee, Company, Address, Street, u16>::get_index_fifth(&elements, 1);
+    ///
+    /// assert_eq!(Some(&23), target);
+    /// assert!(GetIndexFifth::<Employee, Company, Address, Street, u16>::get_index_fifth(&elements, 2).is_none());
+    ///
+    ///```
+    pub trait GetIndexFifth<'a, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        where
+            LevelFour: GetRef<LevelFive> + 'a,
+            LevelThree: GetRef<LevelFour> + 'a,
+            LevelTwo: GetRef<LevelThree> + 'a,
+            LevelOne: GetRef<LevelTwo> + 'a,
+            Self: GetIndex<LevelOne> {
+        fn get_index_fifth(&'a self, index: usize) -> Option<&'a LevelFive> {
+            self.get_index(index)
+                .map(|level_one| level_one.get_ref().get_ref().get_ref().get_ref())
+        }
+    }
+
+    /// A variant of [`SetIndex`], reaching five levels deep in the data structure: the element at the given position, then its fields.
+    ///
+    /// It is implemented for `Vec`, `VecDeque`, arrays, and boxed slices, so there is no need to write impl blocks.
+    ///
+    /// [`SetIndex`]: ../trait.SetIndex.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Set, Modify)]
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// #[derive(Set, Modify)]
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// #[derive(Set, Modify)]
+    /// pub struct Address { pub city: String, pub street: Street }
+    ///
+    /// #[derive(Set, Modify)]
+    /// pub struct Street { pub number: u16, pub name: String }
+    ///
+    /// let element = || Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address {
+    ///                 city: String::from("London"),
+    ///                 street: Street {
+    ///                     number: 23,
+    ///                     name: String::from("High street"),
+    ///                 }
+    ///             },
+    ///         }
+    ///     };
+    ///
+    /// let elements = vec![element(), element()];
+    ///
+    /// let updated = SetIndexFifth::<Employee, Company, Address, Street, u16>::set_index_fifth(elements, 1, 42).unwrap();
+    ///
+    /// assert_eq!(42, updated[1].company.address.street.number);
+    /// assert_eq!(23, updated[0].company.address.street.number);
+    /// assert!(SetIndexFifth::<Employee, Company, Address, Street, u16>::set_index_fifth(updated, 2, 42).is_none());
+    ///
+    ///```
+    pub trait SetIndexFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        where
+            LevelFour: Set<LevelFive>,
+            LevelThree: Modify<LevelFour>,
+            LevelTwo: Modify<LevelThree>,
+            LevelOne: Modify<LevelTwo>,
+            Self: ModifyIndex<LevelOne> {
+        fn set_index_fifth(self, index: usize, new_value: LevelFive) -> Option<Self> {
+            self.modify_index(index, |level_one| level_one.modify(|level_two| level_two.modify(|level_three| level_three.modify(|level_four| level_four.set(new_value)))))
+        }
+    }
+
+    /// A variant of [`ModifyIndex`], reaching five levels deep in the data structure: the element at the given position, then its fields.
+    ///
+    /// It is implemented for `Vec`, `VecDeque`, arrays, and boxed slices, so there is no need to write impl blocks.
+    ///
+    /// [`ModifyIndex`]: ../trait.ModifyIndex.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// #[derive(Modify)]
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// #[derive(Modify)]
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// #[derive(Modify)]
+    /// pub struct Address { pub city: String, pub street: Street }
+    ///
+    /// #[derive(Modify)]
+    /// pub struct Street { pub number: u16, pub name: String }
+    ///
+    /// let element = || Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address {
+    ///                 city: String::from("London"),
+    ///                 street: Street {
+    ///                     number: 23,
+    ///                     name: String::from("High street"),
+    ///                 }
+    ///             },
+    ///         }
+    ///     };
+    ///
+    /// let elements = vec![element(), element()];
+    ///
+    /// let updated = ModifyIndexFifth::<Employee, Company, Address, Street, u16>::modify_index_fifth(elements, 0, |number: u16| number + 1).unwrap();
+    ///
+    /// assert_eq!(24, updated[0].company.address.street.number);
+    /// assert_eq!(23, updated[1].company.address.street.number);
+    /// assert!(ModifyIndexFifth::<Employee, Company, Address, Street, u16>::modify_index_fifth(updated, 2, |number: u16| number + 1).is_none());
+    ///
+    ///```
+    pub trait ModifyIndexFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        where
+            LevelFour: Modify<LevelFive>,
+            LevelThree: Modify<LevelFour>,
+            LevelTwo: Modify<LevelThree>,
+            LevelOne: Modify<LevelTwo>,
+            Self: ModifyIndex<LevelOne> {
+        fn modify_index_fifth(self, index: usize, f: impl FnOnce(LevelFive) -> LevelFive) -> Option<Self> {
+            self.modify_index(index, |level_one| level_one.modify(|level_two| level_two.modify(|level_three| level_three.modify(|level_four| level_four.modify(f)))))
+        }
+    }
+
+    /// A variant of [`GetKey`], reaching two levels deep in the data structure: the entry at the given key, then its fields (by reference).
+    ///
+    /// It is implemented for all the containers having [`GetKey`], so there is no need to write impl blocks.
+    ///
+    /// [`GetKey`]: ../trait.GetKey.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// # use std::collections::HashMap;
+    /// #[derive(GetRef)]
+    /// pub struct Company { pub name: String, pub headcount: u32 }
+    ///
+    /// let element = || Company { name: String::from("Acme Corporation"), headcount: 100 };
+    ///
+    /// let mut entries = HashMap::new();
+    /// entries.insert("first", element());
+    ///
+    /// assert_eq!(Some(&100), GetKeySecond::<&str, Company, u32>::get_key_second(&entries, &"first"));
+    /// assert_eq!(None, GetKeySecond::<&str, Company, u32>::get_key_second(&entries, &"second"));
+    ///
+    ///```
+    pub trait GetKeySecond<'a, Key, LevelOne, LevelTwo>
+        where
+            Key: ?Sized,
+            LevelOne: GetRef<LevelTwo> + 'a,
+            Self: GetKey<Key, LevelOne> + 'a {
+        fn get_key_second(&'a self, key: &Key) -> Option<&'a LevelTwo> {
+            self.get_key(key)
+                .map(|level_one| level_one.get_ref())
+        }
+    }
+
+    /// A variant of [`At`], reaching two levels deep in the data structure: the entry at the given key, then its fields. Missing entries are left untouched.
+    ///
+    /// It is implemented for all the maps having [`At`], so there is no need to write impl blocks.
+    ///
+    /// [`At`]: ../trait.At.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// # use std::collections::HashMap;
+    /// #[derive(Set, Modify)]
+    /// pub struct Company { pub name: String, pub headcount: u32 }
+    ///
+    /// let element = || Company { name: String::from("Acme Corporation"), headcount: 100 };
+    ///
+    /// let mut entries = HashMap::new();
+    /// entries.insert("first", element());
+    ///
+    /// let updated = SetKeySecond::<&str, Company, u32>::set_key_second(entries, "first", 200);
+    /// let updated = SetKeySecond::<&str, Company, u32>::set_key_second(updated, "second", 200);
+    ///
+    /// assert_eq!(200, updated["first"].headcount);
+    /// assert!(!updated.contains_key("second"));
+    ///
+    ///```
+    pub trait SetKeySecond<Key, LevelOne, LevelTwo>
+        where
+            LevelOne: Set<LevelTwo>,
+            Self: At<Key, Option<LevelOne>> {
+        fn set_key_second(self, key: Key, new_value: LevelTwo) -> Self {
+            self.modify_key(key, |entry| entry.map(|level_one| level_one.set(new_value)))
+        }
+    }
+
+    /// A variant of [`At`], reaching two levels deep in the data structure: the entry at the given key, then its fields. Missing entries are left untouched.
+    ///
+    /// It is implemented for all the maps having [`At`], so there is no need to write impl blocks.
+    ///
+    /// [`At`]: ../trait.At.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// # use std::collections::HashMap;
+    /// #[derive(Modify)]
+    /// pub struct Company { pub name: String, pub headcount: u32 }
+    ///
+    /// let element = || Company { name: String::from("Acme Corporation"), headcount: 100 };
+    ///
+    /// let mut entries = HashMap::new();
+    /// entries.insert("first", element());
+    ///
+    /// let updated = ModifyKeySecond::<&str, Company, u32>::modify_key_second(entries, "first", |headcount: u32| headcount + 1);
+    /// let updated = ModifyKeySecond::<&str, Company, u32>::modify_key_second(updated, "second", |headcount: u32| headcount + 1);
+    ///
+    /// assert_eq!(101, updated["first"].headcount);
+    /// assert!(!updated.contains_key("second"));
+    ///
+    ///```
+    pub trait ModifyKeySecond<Key, LevelOne, LevelTwo>
+        where
+            LevelOne: Modify<LevelTwo>,
+            Self: At<Key, Option<LevelOne>> {
+        fn modify_key_second(self, key: Key, f: impl FnOnce(LevelTwo) -> LevelTwo) -> Self {
+            self.modify_key(key, |entry| entry.map(|level_one| level_one.modify(f)))
+        }
+    }
+
+    /// A variant of [`GetKey`], reaching three levels deep in the data structure: the entry at the given key, then its fields (by reference).
+    ///
+    /// It is implemented for all the containers having [`GetKey`], so there is no need to write impl blocks.
+    ///
+    /// [`GetKey`]: ../trait.GetKey.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// # use std::collections::HashMap;
+    /// #[derive(GetRef)]
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// #[derive(GetRef)]
+    /// pub struct Company { pub name: String, pub headcount: u32 }
+    ///
+    /// let element = || Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         headcount: 100,
+    ///         }
+    ///     };
+    ///
+    /// let mut entries = HashMap::new();
+    /// entries.insert("first", element());
+    ///
+    /// assert_eq!(Some(&100), GetKeyThird::<&str, Employee, Company, u32>::get_key_third(&entries, &"first"));
+    /// assert_eq!(None, GetKeyThird::<&str, Employee, Company, u32>::get_key_third(&entries, &"second"));
+    ///
+    ///```
+    pub trait GetKeyThird<'a, Key, LevelOne, LevelTwo, LevelThree>
+        where
+            Key: ?Sized,
+            LevelTwo: GetRef<LevelThree> + 'a,
+            LevelOne: GetRef<LevelTwo> + 'a,
+            Self: GetKey<Key, LevelOne> + 'a {
+        fn get_key_third(&'a self, key: &Key) -> Option<&'a LevelThree> {
+            self.get_key(key)
+                .map(|level_one| level_one.get_ref().get_ref())
+        }
+    }
+
+    /// A variant of [`At`], reaching three levels deep in the data structure: the entry at the given key, then its fields. Missing entries are left untouched.
+    ///
+    /// It is implemented for all the maps having [`At`], so there is no need to write impl blocks.
+    ///
+    /// [`At`]: ../trait.At.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// # use std::collections::HashMap;
+    /// #[derive(Set, Modify)]
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// #[derive(Set, Modify)]
+    /// pub struct Company { pub name: String, pub headcount: u32 }
+    ///
+    /// let element = || Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         headcount: 100,
+    ///         }
+    ///     };
+    ///
+    /// let mut entries = HashMap::new();
+    /// entries.insert("first", element());
+    ///
+    /// let updated = SetKeyThird::<&str, Employee, Company, u32>::set_key_third(entries, "first", 200);
+    /// let updated = SetKeyThird::<&str, Employee, Company, u32>::set_key_third(updated, "second", 200);
+    ///
+    /// assert_eq!(200, updated["first"].company.headcount);
+    /// assert!(!updated.contains_key("second"));
+    ///
+    ///```
+    pub trait SetKeyThird<Key, LevelOne, LevelTwo, LevelThree>
+        where
+            LevelTwo: Set<LevelThree>,
+            LevelOne: Modify<LevelTwo>,
+            Self: At<Key, Option<LevelOne>> {
+        fn set_key_third(self, key: Key, new_value: LevelThree) -> Self {
+            self.modify_key(key, |entry| entry.map(|level_one| level_one.modify(|level_two| level_two.set(new_value))))
+        }
+    }
+
+    /// A variant of [`At`], reaching three levels deep in the data structure: the entry at the given key, then its fields. Missing entries are left untouched.
+    ///
+    /// It is implemented for all the maps having [`At`], so there is no need to write impl blocks.
+    ///
+    /// [`At`]: ../trait.At.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// # use std::collections::HashMap;
+    /// #[derive(Modify)]
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// #[derive(Modify)]
+    /// pub struct Company { pub name: String, pub headcount: u32 }
+    ///
+    /// let element = || Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         headcount: 100,
+    ///         }
+    ///     };
+    ///
+    /// let mut entries = HashMap::new();
+    /// entries.insert("first", element());
+    ///
+    /// let updated = ModifyKeyThird::<&str, Employee, Company, u32>::modify_key_third(entries, "first", |headcount: u32| headcount + 1);
+    /// let updated = ModifyKeyThird::<&str, Employee, Company, u32>::modify_key_third(updated, "second", |headcount: u32| headcount + 1);
+    ///
+    /// assert_eq!(101, updated["first"].company.headcount);
+    /// assert!(!updated.contains_key("second"));
+    ///
+    ///```
+    pub trait ModifyKeyThird<Key, LevelOne, LevelTwo, LevelThree>
+        where
+            LevelTwo: Modify<LevelThree>,
+            LevelOne: Modify<LevelTwo>,
+            Self: At<Key, Option<LevelOne>> {
+        fn modify_key_third(self, key: Key, f: impl FnOnce(LevelThree) -> LevelThree) -> Self {
+            self.modify_key(key, |entry| entry.map(|level_one| level_one.modify(|level_two| level_two.modify(f))))
+        }
+    }
+
+    /// A variant of [`GetKey`], reaching four levels deep in the data structure: the entry at the given key, then its fields (by reference).
+    ///
+    /// It is implemented for all the containers having [`GetKey`], so there is no need to write impl blocks.
+    ///
+    /// [`GetKey`]: ../trait.GetKey.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// # use std::collections::HashMap;
+    /// #[derive(GetRef)]
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// #[derive(GetRef)]
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// #[derive(GetRef)]
+    /// pub struct Address { pub city: String }
+    ///
+    /// let element = || Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address { city: String::from("Bar City") },
+    ///         }
+    ///     };
+    ///
+    /// let mut entries = HashMap::new();
+    /// entries.insert("first", element());
+    ///
+    /// assert_eq!(Some(&String::from("Bar City")), GetKeyFourth::<&str, Employee, Company, Address, String>::get_key_fourth(&entries, &"first"));
+    /// assert_eq!(None, GetKeyFourth::<&str, Employee, Company, Address, String>::get_key_fourth(&entries, &"second"));
+    ///
+    ///```
+    pub trait GetKeyFourth<'a, Key, LevelOne, LevelTwo, LevelThree, LevelFour>
+        where
+            Key: ?Sized,
+            LevelThree: GetRef<LevelFour> + 'a,
+            LevelTwo: GetRef<LevelThree> + 'a,
+            LevelOne: GetRef<LevelTwo> + 'a,
+            Self: GetKey<Key, LevelOne> + 'a {
+        fn get_key_fourth(&'a self, key: &Key) -> Option<&'a LevelFour> {
+            self.get_key(key)
+                .map(|level_one| level_one.get_ref().get_ref().get_ref())
+        }
+    }
+
+    /// A variant of [`At`], reaching four levels deep in the data structure: the entry at the given key, then its fields. Missing entries are left untouched.
+    ///
+    /// It is implemented for all the maps having [`At`], so there is no need to write impl blocks.
+    ///
+    /// [`At`]: ../trait.At.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// # use std::collections::HashMap;
+    /// #[derive(Set, Modify)]
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// #[derive(Set, Modify)]
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// #[derive(Set, Modify)]
+    /// pub struct Address { pub city: String }
+    ///
+    /// let element = || Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address { city: String::from("Bar City") },
+    ///         }
+    ///     };
+    ///
+    /// let mut entries = HashMap::new();
+    /// entries.insert("first", element());
+    ///
+    /// let updated = SetKeyFourth::<&str, Employee, Company, Address, String>::set_key_fourth(entries, "first", String::from("Paris"));
+    /// let updated = SetKeyFourth::<&str, Employee, Company, Address, String>::set_key_fourth(updated, "second", String::from("Paris"));
+    ///
+    /// assert_eq!(String::from("Paris"), updated["first"].company.address.city);
+    /// assert!(!updated.contains_key("second"));
+    ///
+    ///```
+    pub trait SetKeyFourth<Key, LevelOne, LevelTwo, LevelThree, LevelFour>
+        where
+            LevelThree: Set<LevelFour>,
+            LevelTwo: Modify<LevelThree>,
+            LevelOne: Modify<LevelTwo>,
+            Self: At<Key, Option<LevelOne>> {
+        fn set_key_fourth(self, key: Key, new_value: LevelFour) -> Self {
+            self.modify_key(key, |entry| entry.map(|level_one| level_one.modify(|level_two| level_two.modify(|level_three| level_three.set(new_value)))))
+        }
+    }
+
+    /// A variant of [`At`], reaching four levels deep in the data structure: the entry at the given key, then its fields. Missing entries are left untouched.
+    ///
+    /// It is implemented for all the maps having [`At`], so there is no need to write impl blocks.
+    ///
+    /// [`At`]: ../trait.At.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// # use std::collections::HashMap;
+    /// #[derive(Modify)]
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// #[derive(Modify)]
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// #[derive(Modify)]
+    /// pub struct Address { pub city: String }
+    ///
+    /// let element = || Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address { city: String::from("Bar City") },
+    ///         }
+    ///     };
+    ///
+    /// let mut entries = HashMap::new();
+    /// entries.insert("first", element());
+    ///
+    /// let updated = ModifyKeyFourth::<&str, Employee, Company, Address, String>::modify_key_fourth(entries, "first", |city: String| city.to_uppercase());
+    /// let updated = ModifyKeyFourth::<&str, Employee, Company, Address, String>::modify_key_fourth(updated, "second", |city: String| city.to_uppercase());
+    ///
+    /// assert_eq!(String::from("BAR CITY"), updated["first"].company.address.city);
+    /// assert!(!updated.contains_key("second"));
+    ///
+    ///```
+    pub trait ModifyKeyFourth<Key, LevelOne, LevelTwo, LevelThree, LevelFour>
+        where
+            LevelThree: Modify<LevelFour>,
+            LevelTwo: Modify<LevelThree>,
+            LevelOne: Modify<LevelTwo>,
+            Self: At<Key, Option<LevelOne>> {
+        fn modify_key_fourth(self, key: Key, f: impl FnOnce(LevelFour) -> LevelFour) -> Self {
+            self.modify_key(key, |entry| entry.map(|level_one| level_one.modify(|level_two| level_two.modify(|level_three| level_three.modify(f)))))
+        }
+    }
+
+    /// A variant of [`GetKey`], reaching five levels deep in the data structure: the entry at the given key, then its fields (by reference).
+    ///
+    /// It is implemented for all the containers having [`GetKey`], so there is no need to write impl blocks.
+    ///
+    /// [`GetKey`]: ../trait.GetKey.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// # use std::collections::HashMap;
+    /// #[derive(GetRef)]
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// #[derive(GetRef)]
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// #[derive(GetRef)]
+    /// pub struct Address { pub city: String, pub street: Street }
+    ///
+    /// #[derive(GetRef)]
+    /// pub struct Street { pub number: u16, pub name: String }
+    ///
+    /// let element = || Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address {
+    ///                 city: String::from("London"),
+    ///                 street: Street {
+    ///                     number: 23,
+    ///                     name: String::from("High street"),
+    ///                 }
+    ///             },
+    ///         }
+    ///     };
+    ///
+    /// let mut entries = HashMap::new();
+    /// entries.insert("first", element());
+    ///
+    /// assert_eq!(Some(&23), GetKeyFifth::<&str, Employee, Company, Address, Street, u16>::get_key_fifth(&entries, &"first"));
+    /// assert_eq!(None, GetKeyFifth::<&str, Employee, Company, Address, Street, u16>::get_key_fifth(&entries, &"second"));
+    ///
+    ///```
+    pub trait GetKeyFifth<'a, Key, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        where
+            Key: ?Sized,
+            LevelFour: GetRef<LevelFive> + 'a,
+            LevelThree: GetRef<LevelFour> + 'a,
+            LevelTwo: GetRef<LevelThree> + 'a,
+            LevelOne: GetRef<LevelTwo> + 'a,
+            Self: GetKey<Key, LevelOne> + 'a {
+        fn get_key_fifth(&'a self, key: &Key) -> Option<&'a LevelFive> {
+            self.get_key(key)
+                .map(|level_one| level_one.get_ref().get_ref().get_ref().get_ref())
+        }
+    }
+
+    /// A variant of [`At`], reaching five levels deep in the data structure: the entry at the given key, then its fields. Missing entries are left untouched.
+    ///
+    /// It is implemented for all the maps having [`At`], so there is no need to write impl blocks.
+    ///
+    /// [`At`]: ../trait.At.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// # use std::collections::HashMap;
+    /// #[derive(Set, Modify)]
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// #[derive(Set, Modify)]
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// #[derive(Set, Modify)]
+    /// pub struct Address { pub city: String, pub street: Street }
+    ///
+    /// #[derive(Set, Modify)]
+    /// pub struct Street { pub number: u16, pub name: String }
+    ///
+    /// let element = || Employee {
+    ///     name: String::from("John Doe"),
+    ///     company: Company {
+    ///         name: String::from("Acme Corporation"),
+    ///         address: Address {
+    ///                 city: String::from("London"),
+    ///                 street: Street {
+    ///                     number: 23,
+    ///                     name: String::from("High street"),
+    ///                 }
+    ///             },
+    ///         }
+    ///     };
+    ///
+    /// let mut entries = HashMap::new();
+    /// entries.insert("first", element());
+    ///
+    /// let updated = SetKeyFifth::<&str, Employee, Company, Address, Street, u16>::set_key_fifth(entries, "first", 42);
+    /// let updated = SetKeyFifth::<&str, Employee, Company, Address, Street, u16>::set_key_fifth(updated, "second", 42);
+    ///
+    /// assert_eq!(42, updated["first"].company.address.street.number);
+    /// assert!(!updated.contains_key("second"));
+    ///
+    ///```
+    pub trait SetKeyFifth<Key, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        where
+            LevelFour: Set<LevelFive>,
+            LevelThree: Modify<LevelFour>,
+            LevelTwo: Modify<LevelThree>,
+            LevelOne: Modify<LevelTwo>,
+            Self: At<Key, Option<LevelOne>> {
+        fn set_key_fifth(self, key: Key, new_value: LevelFive) -> Self {
+            self.modify_key(key, |entry| entry.map(|level_one| level_one.modify(|level_two| level_two.modify(|level_three| level_three.modify(|level_four| level_four.set(new_value))))))
+        }
+    }
+
+    /// A variant of [`At`], reaching five levels deep in the data structure: the entry at the given key, then its fields. Missing entries are left untouched.
+    ///
+    /// It is implemented for all the maps having [`At`], so there is no need to write impl blocks.
+    ///
+    /// [`At`]: ../trait.At.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// # use std::collections::HashMap;
+    /// #[derive(Modify)]
+    /// pub struct Employee { pub name: String, pub company: Company }
+    ///
+    /// #[derive(Modify)]
+    /// pub struct Company { pub name: String, pub address: Address }
+    ///
+    /// #[derive(Modify)]
+    /// pub struct Address { pub city: String, pub street: Street }
+    ///
+    /// #[derive(Modify)]
+    /// pub struct Street { pub number: u16, pub name: String }
+    ///
+    /// let element = || Employee {
     ///     name: String::from("John Doe"),
     ///     company: Company {
     ///         name: String::from("Acme Corporation"),
@@ -726,550 +6078,2035 @@ pub mod composites {
     ///         }
     ///     };
     ///
-    /// //                      Level 1   Level 2   Level 3 Level 4     Parent type
-    /// //                         |         |        |       |           |
-    /// impl<'a> GetRefFourth<'a, Company, Address, Street, String> for Employee {}
+    /// let mut entries = HashMap::new();
+    /// entries.insert("first", element());
+    ///
+    /// let updated = ModifyKeyFifth::<&str, Employee, Company, Address, Street, u16>::modify_key_fifth(entries, "first", |number: u16| number + 1);
+    /// let updated = ModifyKeyFifth::<&str, Employee, Company, Address, Street, u16>::modify_key_fifth(updated, "second", |number: u16| number + 1);
+    ///
+    /// assert_eq!(24, updated["first"].company.address.street.number);
+    /// assert!(!updated.contains_key("second"));
+    ///
+    ///```
+    pub trait ModifyKeyFifth<Key, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        where
+            LevelFour: Modify<LevelFive>,
+            LevelThree: Modify<LevelFour>,
+            LevelTwo: Modify<LevelThree>,
+            LevelOne: Modify<LevelTwo>,
+            Self: At<Key, Option<LevelOne>> {
+        fn modify_key_fifth(self, key: Key, f: impl FnOnce(LevelFive) -> LevelFive) -> Self {
+            self.modify_key(key, |entry| entry.map(|level_one| level_one.modify(|level_two| level_two.modify(|level_three| level_three.modify(|level_four| level_four.modify(f))))))
+        }
+    }
+
+    /// A variant of [`SetPoly`], reaching two levels deep in the data structure, and propagating the changed type through all the levels.
+    ///
+    /// It is implemented for all the containers having [`ModifyPoly`] instances along the chain, so there is no need to write impl blocks.
+    ///
+    /// [`SetPoly`]: ../trait.SetPoly.html
+    /// [`ModifyPoly`]: ../trait.ModifyPoly.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// let lines = || Some(vec!["1", "2"]);
+    ///
+    /// assert_eq!(Some(vec![None, None]), lines().set_poly_second(None::<u8>));
+    ///```
+    pub trait SetPolySecond<LevelOne, LevelTwo, Value>
+        where
+            LevelOne: SetPoly<LevelTwo, Value>,
+            Value: Clone,
+            Self: ModifyPoly<LevelOne, SetPolyOutput<LevelOne, LevelTwo, Value>> + Sized {
+        fn set_poly_second(self, new_value: Value) -> ModifyPolyOutput<Self, LevelOne, SetPolyOutput<LevelOne, LevelTwo, Value>> {
+            self.modify_poly(|level_one| level_one.set_poly(new_value.clone()))
+        }
+    }
+
+    /// A variant of [`ModifyPoly`], reaching two levels deep in the data structure, and propagating the changed type through all the levels.
+    ///
+    /// It is implemented for all the containers having [`ModifyPoly`] instances along the chain, so there is no need to write impl blocks.
+    ///
+    /// [`ModifyPoly`]: ../trait.ModifyPoly.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// let lines = || Some(vec!["1", "2"]);
+    ///
+    /// assert_eq!(Some(vec![1, 2]), lines().modify_poly_second(|line: &str| line.parse::<u8>().unwrap()));
+    ///```
+    pub trait ModifyPolySecond<LevelOne, LevelTwo, Value>
+        where
+            LevelOne: ModifyPoly<LevelTwo, Value>,
+            Self: ModifyPoly<LevelOne, ModifyPolyOutput<LevelOne, LevelTwo, Value>> + Sized {
+        fn modify_poly_second(self, mut f: impl FnMut(LevelTwo) -> Value) -> ModifyPolyOutput<Self, LevelOne, ModifyPolyOutput<LevelOne, LevelTwo, Value>> {
+            self.modify_poly(|level_one| level_one.modify_poly(&mut f))
+        }
+    }
+
+    /// A variant of [`SetPoly`], reaching three levels deep in the data structure, and propagating the changed type through all the levels.
+    ///
+    /// It is implemented for all the containers having [`ModifyPoly`] instances along the chain, so there is no need to write impl blocks.
+    ///
+    /// [`SetPoly`]: ../trait.SetPoly.html
+    /// [`ModifyPoly`]: ../trait.ModifyPoly.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// let pairs = || vec![Some((1, 2)), None];
+    ///
+    /// assert_eq!(vec![Some(("?", "?")), None], pairs().set_poly_third("?"));
+    ///```
+    pub trait SetPolyThird<LevelOne, LevelTwo, LevelThree, Value>
+        where
+            LevelTwo: SetPoly<LevelThree, Value>,
+            LevelOne: ModifyPoly<LevelTwo, SetPolyOutput<LevelTwo, LevelThree, Value>>,
+            Value: Clone,
+            Self: ModifyPoly<LevelOne, ModifyPolyOutput<LevelOne, LevelTwo, SetPolyOutput<LevelTwo, LevelThree, Value>>> + Sized {
+        #[allow(clippy::type_complexity)]
+        fn set_poly_third(self, new_value: Value) -> ModifyPolyOutput<Self, LevelOne, ModifyPolyOutput<LevelOne, LevelTwo, SetPolyOutput<LevelTwo, LevelThree, Value>>> {
+            self.modify_poly(|level_one| level_one.modify_poly(|level_two| level_two.set_poly(new_value.clone())))
+        }
+    }
+
+    /// A variant of [`ModifyPoly`], reaching three levels deep in the data structure, and propagating the changed type through all the levels.
+    ///
+    /// It is implemented for all the containers having [`ModifyPoly`] instances along the chain, so there is no need to write impl blocks.
+    ///
+    /// [`ModifyPoly`]: ../trait.ModifyPoly.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// let pairs = || vec![Some((1, 2)), None];
+    ///
+    /// assert_eq!(vec![Some((String::from("1"), String::from("2"))), None], pairs().modify_poly_third(|n: i32| n.to_string()));
+    ///```
+    pub trait ModifyPolyThird<LevelOne, LevelTwo, LevelThree, Value>
+        where
+            LevelTwo: ModifyPoly<LevelThree, Value>,
+            LevelOne: ModifyPoly<LevelTwo, ModifyPolyOutput<LevelTwo, LevelThree, Value>>,
+            Self: ModifyPoly<LevelOne, ModifyPolyOutput<LevelOne, LevelTwo, ModifyPolyOutput<LevelTwo, LevelThree, Value>>> + Sized {
+        #[allow(clippy::type_complexity)]
+        fn modify_poly_third(self, mut f: impl FnMut(LevelThree) -> Value) -> ModifyPolyOutput<Self, LevelOne, ModifyPolyOutput<LevelOne, LevelTwo, ModifyPolyOutput<LevelTwo, LevelThree, Value>>> {
+            self.modify_poly(|level_one| level_one.modify_poly(|level_two| level_two.modify_poly(&mut f)))
+        }
+    }
+
+    /// A variant of [`SetPoly`], reaching four levels deep in the data structure, and propagating the changed type through all the levels.
+    ///
+    /// It is implemented for all the containers having [`ModifyPoly`] instances along the chain, so there is no need to write impl blocks.
+    ///
+    /// [`SetPoly`]: ../trait.SetPoly.html
+    /// [`ModifyPoly`]: ../trait.ModifyPoly.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// let pairs = || Ok::<_, String>(vec![Some((1, 2)), None]);
+    ///
+    /// assert_eq!(Ok(vec![Some(((), ())), None]), pairs().set_poly_fourth(()));
+    ///```
+    pub trait SetPolyFourth<LevelOne, LevelTwo, LevelThree, LevelFour, Value>
+        where
+            LevelThree: SetPoly<LevelFour, Value>,
+            LevelTwo: ModifyPoly<LevelThree, SetPolyOutput<LevelThree, LevelFour, Value>>,
+            LevelOne: ModifyPoly<LevelTwo, ModifyPolyOutput<LevelTwo, LevelThree, SetPolyOutput<LevelThree, LevelFour, Value>>>,
+            Value: Clone,
+            Self: ModifyPoly<LevelOne, ModifyPolyOutput<LevelOne, LevelTwo, ModifyPolyOutput<LevelTwo, LevelThree, SetPolyOutput<LevelThree, LevelFour, Value>>>> + Sized {
+        #[allow(clippy::type_complexity)]
+        fn set_poly_fourth(self, new_value: Value) -> ModifyPolyOutput<Self, LevelOne, ModifyPolyOutput<LevelOne, LevelTwo, ModifyPolyOutput<LevelTwo, LevelThree, SetPolyOutput<LevelThree, LevelFour, Value>>>> {
+            self.modify_poly(|level_one| level_one.modify_poly(|level_two| level_two.modify_poly(|level_three| level_three.set_poly(new_value.clone()))))
+        }
+    }
+
+    /// A variant of [`ModifyPoly`], reaching four levels deep in the data structure, and propagating the changed type through all the levels.
+    ///
+    /// It is implemented for all the containers having [`ModifyPoly`] instances along the chain, so there is no need to write impl blocks.
+    ///
+    /// [`ModifyPoly`]: ../trait.ModifyPoly.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// let pairs = || Ok::<_, String>(vec![Some((1, 2)), None]);
+    ///
+    /// assert_eq!(Ok(vec![Some((0.5, 1.0)), None]), pairs().modify_poly_fourth(|n: i32| n as f64 / 2.0));
+    ///```
+    pub trait ModifyPolyFourth<LevelOne, LevelTwo, LevelThree, LevelFour, Value>
+        where
+            LevelThree: ModifyPoly<LevelFour, Value>,
+            LevelTwo: ModifyPoly<LevelThree, ModifyPolyOutput<LevelThree, LevelFour, Value>>,
+            LevelOne: ModifyPoly<LevelTwo, ModifyPolyOutput<LevelTwo, LevelThree, ModifyPolyOutput<LevelThree, LevelFour, Value>>>,
+            Self: ModifyPoly<LevelOne, ModifyPolyOutput<LevelOne, LevelTwo, ModifyPolyOutput<LevelTwo, LevelThree, ModifyPolyOutput<LevelThree, LevelFour, Value>>>> + Sized {
+        #[allow(clippy::type_complexity)]
+        fn modify_poly_fourth(self, mut f: impl FnMut(LevelFour) -> Value) -> ModifyPolyOutput<Self, LevelOne, ModifyPolyOutput<LevelOne, LevelTwo, ModifyPolyOutput<LevelTwo, LevelThree, ModifyPolyOutput<LevelThree, LevelFour, Value>>>> {
+            self.modify_poly(|level_one| level_one.modify_poly(|level_two| level_two.modify_poly(|level_three| level_three.modify_poly(&mut f))))
+        }
+    }
+
+    /// A variant of [`SetPoly`], reaching five levels deep in the data structure, and propagating the changed type through all the levels.
+    ///
+    /// It is implemented for all the containers having [`ModifyPoly`] instances along the chain, so there is no need to write impl blocks.
+    ///
+    /// [`SetPoly`]: ../trait.SetPoly.html
+    /// [`ModifyPoly`]: ../trait.ModifyPoly.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// let pairs = || Some(Ok::<_, String>(vec![Some((1, 2)), None]));
+    ///
+    /// assert_eq!(Some(Ok(vec![Some(("even", "even")), None])), pairs().set_poly_fifth("even"));
+    ///```
+    pub trait SetPolyFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive, Value>
+        where
+            LevelFour: SetPoly<LevelFive, Value>,
+            LevelThree: ModifyPoly<LevelFour, SetPolyOutput<LevelFour, LevelFive, Value>>,
+            LevelTwo: ModifyPoly<LevelThree, ModifyPolyOutput<LevelThree, LevelFour, SetPolyOutput<LevelFour, LevelFive, Value>>>,
+            LevelOne: ModifyPoly<LevelTwo, ModifyPolyOutput<LevelTwo, LevelThree, ModifyPolyOutput<LevelThree, LevelFour, SetPolyOutput<LevelFour, LevelFive, Value>>>>,
+            Value: Clone,
+            Self: ModifyPoly<LevelOne, ModifyPolyOutput<LevelOne, LevelTwo, ModifyPolyOutput<LevelTwo, LevelThree, ModifyPolyOutput<LevelThree, LevelFour, SetPolyOutput<LevelFour, LevelFive, Value>>>>> + Sized {
+        #[allow(clippy::type_complexity)]
+        fn set_poly_fifth(self, new_value: Value) -> ModifyPolyOutput<Self, LevelOne, ModifyPolyOutput<LevelOne, LevelTwo, ModifyPolyOutput<LevelTwo, LevelThree, ModifyPolyOutput<LevelThree, LevelFour, SetPolyOutput<LevelFour, LevelFive, Value>>>>> {
+            self.modify_poly(|level_one| level_one.modify_poly(|level_two| level_two.modify_poly(|level_three| level_three.modify_poly(|level_four| level_four.set_poly(new_value.clone())))))
+        }
+    }
+
+    /// A variant of [`ModifyPoly`], reaching five levels deep in the data structure, and propagating the changed type through all the levels.
+    ///
+    /// It is implemented for all the containers having [`ModifyPoly`] instances along the chain, so there is no need to write impl blocks.
+    ///
+    /// [`ModifyPoly`]: ../trait.ModifyPoly.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// let pairs = || Some(Ok::<_, String>(vec![Some((1, 2)), None]));
+    ///
+    /// assert_eq!(Some(Ok(vec![Some((false, true)), None])), pairs().modify_poly_fifth(|n: i32| n % 2 == 0));
+    ///```
+    pub trait ModifyPolyFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive, Value>
+        where
+            LevelFour: ModifyPoly<LevelFive, Value>,
+            LevelThree: ModifyPoly<LevelFour, ModifyPolyOutput<LevelFour, LevelFive, Value>>,
+            LevelTwo: ModifyPoly<LevelThree, ModifyPolyOutput<LevelThree, LevelFour, ModifyPolyOutput<LevelFour, LevelFive, Value>>>,
+            LevelOne: ModifyPoly<LevelTwo, ModifyPolyOutput<LevelTwo, LevelThree, ModifyPolyOutput<LevelThree, LevelFour, ModifyPolyOutput<LevelFour, LevelFive, Value>>>>,
+            Self: ModifyPoly<LevelOne, ModifyPolyOutput<LevelOne, LevelTwo, ModifyPolyOutput<LevelTwo, LevelThree, ModifyPolyOutput<LevelThree, LevelFour, ModifyPolyOutput<LevelFour, LevelFive, Value>>>>> + Sized {
+        #[allow(clippy::type_complexity)]
+        fn modify_poly_fifth(self, mut f: impl FnMut(LevelFive) -> Value) -> ModifyPolyOutput<Self, LevelOne, ModifyPolyOutput<LevelOne, LevelTwo, ModifyPolyOutput<LevelTwo, LevelThree, ModifyPolyOutput<LevelThree, LevelFour, ModifyPolyOutput<LevelFour, LevelFive, Value>>>>> {
+            self.modify_poly(|level_one| level_one.modify_poly(|level_two| level_two.modify_poly(|level_three| level_three.modify_poly(|level_four| level_four.modify_poly(&mut f)))))
+        }
+    }
+
+    /// A variant of [`GetField`], reaching two levels deep in the data structure, following one tag per level.
+    ///
+    /// It is implemented for all the containers having tagged fields along the chain, so there is no need to write impl blocks.
+    ///
+    /// [`GetField`]: ../trait.GetField.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub struct Employee { pub legal_name: Name, pub preferred_name: Name }
+    /// pub struct Name { pub given: String, pub family: String }
+    ///
+    /// tagged! {
+    ///     Employee { legal_name: Name => LegalName, preferred_name: Name => PreferredName }
+    ///     Name { given: String => Given, family: String => Family }
+    /// }
+    ///
+    /// let name = |given: &str| Name { given: String::from(given), family: String::from("Doe") };
+    /// let employee = |given: &str| Employee { legal_name: name(given), preferred_name: name("Jo") };
+    ///
+    /// assert_eq!("Jane", employee("Jane").get_field_second(LegalName, Given));
+    ///
+    ///```
+    pub trait GetFieldSecond<TagOne, TagTwo, LevelOne, LevelTwo>
+        where
+            LevelOne: GetField<TagTwo, LevelTwo>,
+            Self: GetField<TagOne, LevelOne> + Sized {
+        fn get_field_second(self, tag_one: TagOne, tag_two: TagTwo) -> LevelTwo {
+            self.get_field(tag_one).get_field(tag_two)
+        }
+    }
+
+    /// A variant of [`GetRefField`], reaching two levels deep in the data structure, following one tag per level.
+    ///
+    /// It is implemented for all the containers having tagged fields along the chain, so there is no need to write impl blocks.
+    ///
+    /// [`GetRefField`]: ../trait.GetRefField.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub struct Employee { pub legal_name: Name, pub preferred_name: Name }
+    /// pub struct Name { pub given: String, pub family: String }
+    ///
+    /// tagged! {
+    ///     Employee { legal_name: Name => LegalName, preferred_name: Name => PreferredName }
+    ///     Name { given: String => Given, family: String => Family }
+    /// }
+    ///
+    /// let name = |given: &str| Name { given: String::from(given), family: String::from("Doe") };
+    /// let employee = |given: &str| Employee { legal_name: name(given), preferred_name: name("Jo") };
+    ///
+    /// assert_eq!("Jane", employee("Jane").get_ref_field_second(LegalName, Given).as_str());
+    ///
+    ///```
+    pub trait GetRefFieldSecond<'a, TagOne, TagTwo, LevelOne, LevelTwo>
+        where
+            LevelOne: GetRefField<TagTwo, LevelTwo> + 'a,
+            Self: GetRefField<TagOne, LevelOne> + 'a {
+        fn get_ref_field_second(&'a self, tag_one: TagOne, tag_two: TagTwo) -> &'a LevelTwo {
+            self.get_ref_field(tag_one).get_ref_field(tag_two)
+        }
+    }
+
+    /// A variant of [`SetField`], reaching two levels deep in the data structure, following one tag per level.
+    ///
+    /// It is implemented for all the containers having tagged fields along the chain, so there is no need to write impl blocks.
+    ///
+    /// [`SetField`]: ../trait.SetField.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub struct Employee { pub legal_name: Name, pub preferred_name: Name }
+    /// pub struct Name { pub given: String, pub family: String }
+    ///
+    /// tagged! {
+    ///     Employee { legal_name: Name => LegalName, preferred_name: Name => PreferredName }
+    ///     Name { given: String => Given, family: String => Family }
+    /// }
+    ///
+    /// let name = |given: &str| Name { given: String::from(given), family: String::from("Doe") };
+    /// let employee = |given: &str| Employee { legal_name: name(given), preferred_name: name("Jo") };
+    ///
+    /// let updated = employee("Jane").set_field_second(LegalName, Given, String::from("Janet"));
+    ///
+    /// assert_eq!(("Janet", "Jo"), (updated.legal_name.given.as_str(), updated.preferred_name.given.as_str()));
+    ///
+    ///```
+    pub trait SetFieldSecond<TagOne, TagTwo, LevelOne, LevelTwo>
+        where
+            LevelOne: SetField<TagTwo, LevelTwo>,
+            Self: ModifyField<TagOne, LevelOne> + Sized {
+        fn set_field_second(self, tag_one: TagOne, tag_two: TagTwo, new_value: LevelTwo) -> Self {
+            self.modify_field(tag_one, |level_one| level_one.set_field(tag_two, new_value))
+        }
+    }
+
+    /// A variant of [`ModifyField`], reaching two levels deep in the data structure, following one tag per level.
+    ///
+    /// It is implemented for all the containers having tagged fields along the chain, so there is no need to write impl blocks.
     ///
-    /// assert_eq!("High street", john_doe.get_ref_fourth().as_str());
+    /// [`ModifyField`]: ../trait.ModifyField.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub struct Employee { pub legal_name: Name, pub preferred_name: Name }
+    /// pub struct Name { pub given: String, pub family: String }
+    ///
+    /// tagged! {
+    ///     Employee { legal_name: Name => LegalName, preferred_name: Name => PreferredName }
+    ///     Name { given: String => Given, family: String => Family }
+    /// }
+    ///
+    /// let name = |given: &str| Name { given: String::from(given), family: String::from("Doe") };
+    /// let employee = |given: &str| Employee { legal_name: name(given), preferred_name: name("Jo") };
+    ///
+    /// let updated = employee("Jane").modify_field_second(LegalName, Given, |given| given.to_uppercase());
+    ///
+    /// assert_eq!(("JANE", "Jo"), (updated.legal_name.given.as_str(), updated.preferred_name.given.as_str()));
     ///
     ///```
-    pub trait GetRefFourth<'a, LevelOne, LevelTwo, LevelThree, LevelFour>
+    pub trait ModifyFieldSecond<TagOne, TagTwo, LevelOne, LevelTwo>
         where
-            LevelThree: GetRef<LevelFour> + 'a,
-            LevelTwo: GetRef<LevelThree> + 'a,
-            LevelOne: GetRef<LevelTwo> + 'a,
-            Self: GetRef<LevelOne> + 'a {
-        fn get_ref_fourth(&'a self) -> &'a LevelFour {
-            self.get_ref().get_ref().get_ref().get_ref()
+            LevelOne: ModifyField<TagTwo, LevelTwo>,
+            Self: ModifyField<TagOne, LevelOne> + Sized {
+        fn modify_field_second(self, tag_one: TagOne, tag_two: TagTwo, f: impl FnOnce(LevelTwo) -> LevelTwo) -> Self {
+            self.modify_field(tag_one, |level_one| level_one.modify_field(tag_two, f))
         }
     }
 
-    /// A variant of [`Set`], reaching four levels deep in the data structure.
+    /// A variant of [`GetField`], reaching three levels deep in the data structure, following one tag per level.
     ///
-    /// [`Set`]: ../trait.Set.html
+    /// It is implemented for all the containers having tagged fields along the chain, so there is no need to write impl blocks.
+    ///
+    /// [`GetField`]: ../trait.GetField.html
     ///
     /// # Examples
     ///```
-    ///  # use photonix::*;
-    ///  #[derive(Get, Modify)]
-    ///  pub struct Employee { pub name: String, pub company: Company }
+    /// # use photonix::*;
+    /// pub struct Team { pub lead: Employee, pub deputy: Employee }
+    /// pub struct Employee { pub legal_name: Name, pub preferred_name: Name }
+    /// pub struct Name { pub given: String, pub family: String }
     ///
-    ///  #[derive(Get, Modify)]
-    ///  pub struct Company { pub name: String, pub address: Address }
+    /// tagged! {
+    ///     Team { lead: Employee => Lead, deputy: Employee => Deputy }
+    ///     Employee { legal_name: Name => LegalName, preferred_name: Name => PreferredName }
+    ///     Name { given: String => Given, family: String => Family }
+    /// }
     ///
-    ///  #[derive(Get, Modify)]
-    ///  pub struct Address { pub city: String, pub street: Street }
+    /// let name = |given: &str| Name { given: String::from(given), family: String::from("Doe") };
+    /// let employee = |given: &str| Employee { legal_name: name(given), preferred_name: name("Jo") };
+    /// let team = || Team { lead: employee("John"), deputy: employee("Jane") };
     ///
-    ///  #[derive(Get, Set)]
-    ///  pub struct Street { pub number: u16, pub name: String }
+    /// assert_eq!("Jane", team().get_field_third(Deputy, LegalName, Given));
     ///
-    /// let john_doe = Employee {
-    ///     name: String::from("John Doe"),
-    ///     company: Company {
-    ///         name: String::from("Acme Corporation"),
-    ///         address: Address {
-    ///                 city: String::from("London"),
-    ///                 street: Street {
-    ///                     number: 23,
-    ///                     name: String::from("High street"),
-    ///                 }
-    ///             },
-    ///         }
-    ///     };
+    ///```
+    pub trait GetFieldThird<TagOne, TagTwo, TagThree, LevelOne, LevelTwo, LevelThree>
+        where
+            LevelTwo: GetField<TagThree, LevelThree>,
+            LevelOne: GetField<TagTwo, LevelTwo>,
+            Self: GetField<TagOne, LevelOne> + Sized {
+        fn get_field_third(self, tag_one: TagOne, tag_two: TagTwo, tag_three: TagThree) -> LevelThree {
+            self.get_field(tag_one).get_field(tag_two).get_field(tag_three)
+        }
+    }
+
+    /// A variant of [`GetRefField`], reaching three levels deep in the data structure, following one tag per level.
     ///
-    /// //            Level 1   Level 2   Level 3 Level 4     Parent type
-    /// //               |         |        |       |           |
-    /// impl SetFourth<Company, Address, Street, String> for Employee {}
-    /// impl GetFourth<Company, Address, Street, String> for Employee {}
+    /// It is implemented for all the containers having tagged fields along the chain, so there is no need to write impl blocks.
     ///
-    /// let john_at_new_street = john_doe.set_fourth(String::from("Low street"));
+    /// [`GetRefField`]: ../trait.GetRefField.html
     ///
-    /// assert_eq!("Low street", john_at_new_street.get_fourth().as_str());
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub struct Team { pub lead: Employee, pub deputy: Employee }
+    /// pub struct Employee { pub legal_name: Name, pub preferred_name: Name }
+    /// pub struct Name { pub given: String, pub family: String }
+    ///
+    /// tagged! {
+    ///     Team { lead: Employee => Lead, deputy: Employee => Deputy }
+    ///     Employee { legal_name: Name => LegalName, preferred_name: Name => PreferredName }
+    ///     Name { given: String => Given, family: String => Family }
+    /// }
+    ///
+    /// let name = |given: &str| Name { given: String::from(given), family: String::from("Doe") };
+    /// let employee = |given: &str| Employee { legal_name: name(given), preferred_name: name("Jo") };
+    /// let team = || Team { lead: employee("John"), deputy: employee("Jane") };
+    ///
+    /// assert_eq!("Jane", team().get_ref_field_third(Deputy, LegalName, Given).as_str());
     ///
     ///```
-    pub trait SetFourth<LevelOne, LevelTwo, LevelThree, LevelFour>
+    pub trait GetRefFieldThird<'a, TagOne, TagTwo, TagThree, LevelOne, LevelTwo, LevelThree>
         where
-            LevelThree: Set<LevelFour>,
-            LevelTwo: Modify<LevelThree>,
-            LevelOne: Modify<LevelTwo>,
-            Self: Modify<LevelOne> + Sized {
-        fn set_fourth(self, new_value: LevelFour) -> Self {
-            self.modify(
-                |level_one| level_one.modify(
-                    |level_two| level_two.modify(
-                        |level_three| level_three.set(new_value)
-                    )
-                )
-            )
+            LevelTwo: GetRefField<TagThree, LevelThree> + 'a,
+            LevelOne: GetRefField<TagTwo, LevelTwo> + 'a,
+            Self: GetRefField<TagOne, LevelOne> + 'a {
+        fn get_ref_field_third(&'a self, tag_one: TagOne, tag_two: TagTwo, tag_three: TagThree) -> &'a LevelThree {
+            self.get_ref_field(tag_one).get_ref_field(tag_two).get_ref_field(tag_three)
         }
     }
 
-    /// A variant of [`Modify`], reaching four levels deep in the data structure.
+    /// A variant of [`SetField`], reaching three levels deep in the data structure, following one tag per level.
     ///
-    /// [`Modify`]: ../trait.Modify.html
+    /// It is implemented for all the containers having tagged fields along the chain, so there is no need to write impl blocks.
+    ///
+    /// [`SetField`]: ../trait.SetField.html
     ///
     /// # Examples
     ///```
-    ///  # use photonix::*;
-    ///  #[derive(Get, Modify)]
-    ///  pub struct Employee { pub name: String, pub company: Company }
+    /// # use photonix::*;
+    /// pub struct Team { pub lead: Employee, pub deputy: Employee }
+    /// pub struct Employee { pub legal_name: Name, pub preferred_name: Name }
+    /// pub struct Name { pub given: String, pub family: String }
     ///
-    ///  #[derive(Get, Modify)]
-    ///  pub struct Company { pub name: String, pub address: Address }
+    /// tagged! {
+    ///     Team { lead: Employee => Lead, deputy: Employee => Deputy }
+    ///     Employee { legal_name: Name => LegalName, preferred_name: Name => PreferredName }
+    ///     Name { given: String => Given, family: String => Family }
+    /// }
     ///
-    ///  #[derive(Get, Modify)]
-    ///  pub struct Address { pub city: String, pub street: Street }
+    /// let name = |given: &str| Name { given: String::from(given), family: String::from("Doe") };
+    /// let employee = |given: &str| Employee { legal_name: name(given), preferred_name: name("Jo") };
+    /// let team = || Team { lead: employee("John"), deputy: employee("Jane") };
     ///
-    ///  #[derive(Get, Modify)]
-    ///  pub struct Street { pub number: u16, pub name: String }
+    /// let updated = team().set_field_third(Deputy, LegalName, Given, String::from("Janet"));
     ///
-    /// let john_doe = Employee {
-    ///     name: String::from("John Doe"),
-    ///     company: Company {
-    ///         name: String::from("Acme Corporation"),
-    ///         address: Address {
-    ///                 city: String::from("London"),
-    ///                 street: Street {
-    ///                     number: 23,
-    ///                     name: String::from("High street"),
-    ///                 }
-    ///             },
-    ///         }
-    ///     };
+    /// assert_eq!(("Janet", "Jo"), (updated.deputy.legal_name.given.as_str(), updated.deputy.preferred_name.given.as_str()));
     ///
-    /// //              Level 1   Level 2   Level 3 Level 4     Parent type
-    /// //                 |         |        |       |           |
-    /// impl ModifyFourth<Company, Address, Street, String> for Employee {}
-    /// impl GetFourth<Company, Address, Street, String> for Employee {}
+    ///```
+    pub trait SetFieldThird<TagOne, TagTwo, TagThree, LevelOne, LevelTwo, LevelThree>
+        where
+            LevelTwo: SetField<TagThree, LevelThree>,
+            LevelOne: ModifyField<TagTwo, LevelTwo>,
+            Self: ModifyField<TagOne, LevelOne> + Sized {
+        fn set_field_third(self, tag_one: TagOne, tag_two: TagTwo, tag_three: TagThree, new_value: LevelThree) -> Self {
+            self.modify_field(tag_one, |level_one| level_one.modify_field(tag_two, |level_two| level_two.set_field(tag_three, new_value)))
+        }
+    }
+
+    /// A variant of [`ModifyField`], reaching three levels deep in the data structure, following one tag per level.
     ///
-    /// let john_at_new_street =
-    ///     john_doe.modify_fourth(|street_name| street_name.replace("street", "road"));
+    /// It is implemented for all the containers having tagged fields along the chain, so there is no need to write impl blocks.
     ///
-    /// assert_eq!("High road", john_at_new_street.get_fourth().as_str());
+    /// [`ModifyField`]: ../trait.ModifyField.html
     ///
+    /// # Examples
     ///```
-    pub trait ModifyFourth<LevelOne, LevelTwo, LevelThree, LevelFour>
+    /// # use photonix::*;
+    /// pub struct Team { pub lead: Employee, pub deputy: Employee }
+    /// pub struct Employee { pub legal_name: Name, pub preferred_name: Name }
+    /// pub struct Name { pub given: String, pub family: String }
+    ///
+    /// tagged! {
+    ///     Team { lead: Employee => Lead, deputy: Employee => Deputy }
+    ///     Employee { legal_name: Name => LegalName, preferred_name: Name => PreferredName }
+    ///     Name { given: String => Given, family: String => Family }
+    /// }
+    ///
+    /// let name = |given: &str| Name { given: String::from(given), family: String::from("Doe") };
+    /// let employee = |given: &str| Employee { legal_name: name(given), preferred_name: name("Jo") };
+    /// let team = || Team { lead: employee("John"), deputy: employee("Jane") };
+    ///
+    /// let updated = team().modify_field_third(Deputy, LegalName, Given, |given| given.to_uppercase());
+    ///
+    /// assert_eq!(("JANE", "Jo"), (updated.deputy.legal_name.given.as_str(), updated.deputy.preferred_name.given.as_str()));
+    ///
+    ///```
+    pub trait ModifyFieldThird<TagOne, TagTwo, TagThree, LevelOne, LevelTwo, LevelThree>
         where
-            LevelThree: Modify<LevelFour>,
-            LevelTwo: Modify<LevelThree>,
-            LevelOne: Modify<LevelTwo>,
-            Self: Modify<LevelOne> + Sized {
-        fn modify_fourth(self, f: impl FnOnce(LevelFour) -> LevelFour) -> Self {
-            self.modify(
-                |level_one| level_one.modify(
-                    |level_two| level_two.modify(
-                        |level_three| level_three.modify(f)
-                    )
-                )
-            )
+            LevelTwo: ModifyField<TagThree, LevelThree>,
+            LevelOne: ModifyField<TagTwo, LevelTwo>,
+            Self: ModifyField<TagOne, LevelOne> + Sized {
+        fn modify_field_third(self, tag_one: TagOne, tag_two: TagTwo, tag_three: TagThree, f: impl FnOnce(LevelThree) -> LevelThree) -> Self {
+            self.modify_field(tag_one, |level_one| level_one.modify_field(tag_two, |level_two| level_two.modify_field(tag_three, f)))
         }
     }
 
-    /// A variant of [`Get`], reaching five levels deep in the data structure.
+    /// A variant of [`GetField`], reaching four levels deep in the data structure, following one tag per level.
     ///
-    /// [`Get`]: ../trait.Get.html
+    /// It is implemented for all the containers having tagged fields along the chain, so there is no need to write impl blocks.
+    ///
+    /// [`GetField`]: ../trait.GetField.html
     ///
     /// # Examples
     ///```
-    ///  # use photonix::*;
-    ///  #[derive(Get)]
-    ///  pub struct Employee { pub name: String, pub company: Company }
+    /// # use photonix::*;
+    /// pub struct Department { pub primary: Team, pub backup: Team }
+    /// pub struct Team { pub lead: Employee, pub deputy: Employee }
+    /// pub struct Employee { pub legal_name: Name, pub preferred_name: Name }
+    /// pub struct Name { pub given: String, pub family: String }
     ///
-    ///  #[derive(Get)]
-    ///  pub struct Company { pub name: String, pub address: Address }
+    /// tagged! {
+    ///     Department { primary: Team => Primary, backup: Team => Backup }
+    ///     Team { lead: Employee => Lead, deputy: Employee => Deputy }
+    ///     Employee { legal_name: Name => LegalName, preferred_name: Name => PreferredName }
+    ///     Name { given: String => Given, family: String => Family }
+    /// }
     ///
-    ///  #[derive(Get)]
-    ///  pub struct Address { pub city: String, pub street: Street }
+    /// let name = |given: &str| Name { given: String::from(given), family: String::from("Doe") };
+    /// let employee = |given: &str| Employee { legal_name: name(given), preferred_name: name("Jo") };
+    /// let team = || Team { lead: employee("John"), deputy: employee("Jane") };
+    /// let department = || Department { primary: team(), backup: team() };
     ///
-    ///  #[derive(Get)]
-    ///  pub struct Street { pub number: StreetNumber, pub name: String }
+    /// assert_eq!("Jane", department().get_field_fourth(Backup, Deputy, LegalName, Given));
     ///
-    ///  #[derive(Get)]
-    ///  pub struct StreetNumber(u16);
+    ///```
+    pub trait GetFieldFourth<TagOne, TagTwo, TagThree, TagFour, LevelOne, LevelTwo, LevelThree, LevelFour>
+        where
+            LevelThree: GetField<TagFour, LevelFour>,
+            LevelTwo: GetField<TagThree, LevelThree>,
+            LevelOne: GetField<TagTwo, LevelTwo>,
+            Self: GetField<TagOne, LevelOne> + Sized {
+        fn get_field_fourth(self, tag_one: TagOne, tag_two: TagTwo, tag_three: TagThree, tag_four: TagFour) -> LevelFour {
+            self.get_field(tag_one).get_field(tag_two).get_field(tag_three).get_field(tag_four)
+        }
+    }
+
+    /// A variant of [`GetRefField`], reaching four levels deep in the data structure, following one tag per level.
     ///
-    /// let john_doe = Employee {
-    ///     name: String::from("John Doe"),
-    ///     company: Company {
-    ///         name: String::from("Acme Corporation"),
-    ///         address: Address {
-    ///                 city: String::from("London"),
-    ///                 street: Street {
-    ///                     number: StreetNumber(23),
-    ///                     name: String::from("High street"),
-    ///                 }
-    ///             },
-    ///         }
-    ///     };
+    /// It is implemented for all the containers having tagged fields along the chain, so there is no need to write impl blocks.
     ///
-    /// //            Level 1   Level 2   Level 3 Level     Level 5   Parent type
-    /// //               |         |        |       |           |         |
-    /// impl GetFifth<Company, Address, Street, StreetNumber, u16> for Employee {}
+    /// [`GetRefField`]: ../trait.GetRefField.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub struct Department { pub primary: Team, pub backup: Team }
+    /// pub struct Team { pub lead: Employee, pub deputy: Employee }
+    /// pub struct Employee { pub legal_name: Name, pub preferred_name: Name }
+    /// pub struct Name { pub given: String, pub family: String }
+    ///
+    /// tagged! {
+    ///     Department { primary: Team => Primary, backup: Team => Backup }
+    ///     Team { lead: Employee => Lead, deputy: Employee => Deputy }
+    ///     Employee { legal_name: Name => LegalName, preferred_name: Name => PreferredName }
+    ///     Name { given: String => Given, family: String => Family }
+    /// }
+    ///
+    /// let name = |given: &str| Name { given: String::from(given), family: String::from("Doe") };
+    /// let employee = |given: &str| Employee { legal_name: name(given), preferred_name: name("Jo") };
+    /// let team = || Team { lead: employee("John"), deputy: employee("Jane") };
+    /// let department = || Department { primary: team(), backup: team() };
+    ///
+    /// assert_eq!("Jane", department().get_ref_field_fourth(Backup, Deputy, LegalName, Given).as_str());
+    ///
+    ///```
+    pub trait GetRefFieldFourth<'a, TagOne, TagTwo, TagThree, TagFour, LevelOne, LevelTwo, LevelThree, LevelFour>
+        where
+            LevelThree: GetRefField<TagFour, LevelFour> + 'a,
+            LevelTwo: GetRefField<TagThree, LevelThree> + 'a,
+            LevelOne: GetRefField<TagTwo, LevelTwo> + 'a,
+            Self: GetRefField<TagOne, LevelOne> + 'a {
+        fn get_ref_field_fourth(&'a self, tag_one: TagOne, tag_two: TagTwo, tag_three: TagThree, tag_four: TagFour) -> &'a LevelFour {
+            self.get_ref_field(tag_one).get_ref_field(tag_two).get_ref_field(tag_three).get_ref_field(tag_four)
+        }
+    }
+
+    /// A variant of [`SetField`], reaching four levels deep in the data structure, following one tag per level.
+    ///
+    /// It is implemented for all the containers having tagged fields along the chain, so there is no need to write impl blocks.
+    ///
+    /// [`SetField`]: ../trait.SetField.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub struct Department { pub primary: Team, pub backup: Team }
+    /// pub struct Team { pub lead: Employee, pub deputy: Employee }
+    /// pub struct Employee { pub legal_name: Name, pub preferred_name: Name }
+    /// pub struct Name { pub given: String, pub family: String }
+    ///
+    /// tagged! {
+    ///     Department { primary: Team => Primary, backup: Team => Backup }
+    ///     Team { lead: Employee => Lead, deputy: Employee => Deputy }
+    ///     Employee { legal_name: Name => LegalName, preferred_name: Name => PreferredName }
+    ///     Name { given: String => Given, family: String => Family }
+    /// }
+    ///
+    /// let name = |given: &str| Name { given: String::from(given), family: String::from("Doe") };
+    /// let employee = |given: &str| Employee { legal_name: name(given), preferred_name: name("Jo") };
+    /// let team = || Team { lead: employee("John"), deputy: employee("Jane") };
+    /// let department = || Department { primary: team(), backup: team() };
+    ///
+    /// let updated = department().set_field_fourth(Backup, Deputy, LegalName, Given, String::from("Janet"));
+    ///
+    /// assert_eq!(("Janet", "Jo"), (updated.backup.deputy.legal_name.given.as_str(), updated.backup.deputy.preferred_name.given.as_str()));
+    ///
+    ///```
+    pub trait SetFieldFourth<TagOne, TagTwo, TagThree, TagFour, LevelOne, LevelTwo, LevelThree, LevelFour>
+        where
+            LevelThree: SetField<TagFour, LevelFour>,
+            LevelTwo: ModifyField<TagThree, LevelThree>,
+            LevelOne: ModifyField<TagTwo, LevelTwo>,
+            Self: ModifyField<TagOne, LevelOne> + Sized {
+        fn set_field_fourth(self, tag_one: TagOne, tag_two: TagTwo, tag_three: TagThree, tag_four: TagFour, new_value: LevelFour) -> Self {
+            self.modify_field(tag_one, |level_one| level_one.modify_field(tag_two, |level_two| level_two.modify_field(tag_three, |level_three| level_three.set_field(tag_four, new_value))))
+        }
+    }
+
+    /// A variant of [`ModifyField`], reaching four levels deep in the data structure, following one tag per level.
+    ///
+    /// It is implemented for all the containers having tagged fields along the chain, so there is no need to write impl blocks.
+    ///
+    /// [`ModifyField`]: ../trait.ModifyField.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub struct Department { pub primary: Team, pub backup: Team }
+    /// pub struct Team { pub lead: Employee, pub deputy: Employee }
+    /// pub struct Employee { pub legal_name: Name, pub preferred_name: Name }
+    /// pub struct Name { pub given: String, pub family: String }
+    ///
+    /// tagged! {
+    ///     Department { primary: Team => Primary, backup: Team => Backup }
+    ///     Team { lead: Employee => Lead, deputy: Employee => Deputy }
+    ///     Employee { legal_name: Name => LegalName, preferred_name: Name => PreferredName }
+    ///     Name { given: String => Given, family: String => Family }
+    /// }
+    ///
+    /// let name = |given: &str| Name { given: String::from(given), family: String::from("Doe") };
+    /// let employee = |given: &str| Employee { legal_name: name(given), preferred_name: name("Jo") };
+    /// let team = || Team { lead: employee("John"), deputy: employee("Jane") };
+    /// let department = || Department { primary: team(), backup: team() };
+    ///
+    /// let updated = department().modify_field_fourth(Backup, Deputy, LegalName, Given, |given| given.to_uppercase());
+    ///
+    /// assert_eq!(("JANE", "Jo"), (updated.backup.deputy.legal_name.given.as_str(), updated.backup.deputy.preferred_name.given.as_str()));
+    ///
+    ///```
+    pub trait ModifyFieldFourth<TagOne, TagTwo, TagThree, TagFour, LevelOne, LevelTwo, LevelThree, LevelFour>
+        where
+            LevelThree: ModifyField<TagFour, LevelFour>,
+            LevelTwo: ModifyField<TagThree, LevelThree>,
+            LevelOne: ModifyField<TagTwo, LevelTwo>,
+            Self: ModifyField<TagOne, LevelOne> + Sized {
+        fn modify_field_fourth(self, tag_one: TagOne, tag_two: TagTwo, tag_three: TagThree, tag_four: TagFour, f: impl FnOnce(LevelFour) -> LevelFour) -> Self {
+            self.modify_field(tag_one, |level_one| level_one.modify_field(tag_two, |level_two| level_two.modify_field(tag_three, |level_three| level_three.modify_field(tag_four, f))))
+        }
+    }
+
+    /// A variant of [`GetField`], reaching five levels deep in the data structure, following one tag per level.
+    ///
+    /// It is implemented for all the containers having tagged fields along the chain, so there is no need to write impl blocks.
+    ///
+    /// [`GetField`]: ../trait.GetField.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub struct Company { pub north: Department, pub south: Department }
+    /// pub struct Department { pub primary: Team, pub backup: Team }
+    /// pub struct Team { pub lead: Employee, pub deputy: Employee }
+    /// pub struct Employee { pub legal_name: Name, pub preferred_name: Name }
+    /// pub struct Name { pub given: String, pub family: String }
+    ///
+    /// tagged! {
+    ///     Company { north: Department => North, south: Department => South }
+    ///     Department { primary: Team => Primary, backup: Team => Backup }
+    ///     Team { lead: Employee => Lead, deputy: Employee => Deputy }
+    ///     Employee { legal_name: Name => LegalName, preferred_name: Name => PreferredName }
+    ///     Name { given: String => Given, family: String => Family }
+    /// }
+    ///
+    /// let name = |given: &str| Name { given: String::from(given), family: String::from("Doe") };
+    /// let employee = |given: &str| Employee { legal_name: name(given), preferred_name: name("Jo") };
+    /// let team = || Team { lead: employee("John"), deputy: employee("Jane") };
+    /// let department = || Department { primary: team(), backup: team() };
+    /// let company = || Company { north: department(), south: department() };
+    ///
+    /// assert_eq!("Jane", company().get_field_fifth(South, Backup, Deputy, LegalName, Given));
+    ///
+    ///```
+    pub trait GetFieldFifth<TagOne, TagTwo, TagThree, TagFour, TagFive, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        where
+            LevelFour: GetField<TagFive, LevelFive>,
+            LevelThree: GetField<TagFour, LevelFour>,
+            LevelTwo: GetField<TagThree, LevelThree>,
+            LevelOne: GetField<TagTwo, LevelTwo>,
+            Self: GetField<TagOne, LevelOne> + Sized {
+        fn get_field_fifth(self, tag_one: TagOne, tag_two: TagTwo, tag_three: TagThree, tag_four: TagFour, tag_five: TagFive) -> LevelFive {
+            self.get_field(tag_one).get_field(tag_two).get_field(tag_three).get_field(tag_four).get_field(tag_five)
+        }
+    }
+
+    /// A variant of [`GetRefField`], reaching five levels deep in the data structure, following one tag per level.
+    ///
+    /// It is implemented for all the containers having tagged fields along the chain, so there is no need to write impl blocks.
+    ///
+    /// [`GetRefField`]: ../trait.GetRefField.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub struct Company { pub north: Department, pub south: Department }
+    /// pub struct Department { pub primary: Team, pub backup: Team }
+    /// pub struct Team { pub lead: Employee, pub deputy: Employee }
+    /// pub struct Employee { pub legal_name: Name, pub preferred_name: Name }
+    /// pub struct Name { pub given: String, pub family: String }
+    ///
+    /// tagged! {
+    ///     Company { north: Department => North, south: Department => South }
+    ///     Department { primary: Team => Primary, backup: Team => Backup }
+    ///     Team { lead: Employee => Lead, deputy: Employee => Deputy }
+    ///     Employee { legal_name: Name => LegalName, preferred_name: Name => PreferredName }
+    ///     Name { given: String => Given, family: String => Family }
+    /// }
+    ///
+    /// let name = |given: &str| Name { given: String::from(given), family: String::from("Doe") };
+    /// let employee = |given: &str| Employee { legal_name: name(given), preferred_name: name("Jo") };
+    /// let team = || Team { lead: employee("John"), deputy: employee("Jane") };
+    /// let department = || Department { primary: team(), backup: team() };
+    /// let company = || Company { north: department(), south: department() };
+    ///
+    /// assert_eq!("Jane", company().get_ref_field_fifth(South, Backup, Deputy, LegalName, Given).as_str());
+    ///
+    ///```
+    pub trait GetRefFieldFifth<'a, TagOne, TagTwo, TagThree, TagFour, TagFive, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        where
+            LevelFour: GetRefField<TagFive, LevelFive> + 'a,
+            LevelThree: GetRefField<TagFour, LevelFour> + 'a,
+            LevelTwo: GetRefField<TagThree, LevelThree> + 'a,
+            LevelOne: GetRefField<TagTwo, LevelTwo> + 'a,
+            Self: GetRefField<TagOne, LevelOne> + 'a {
+        fn get_ref_field_fifth(&'a self, tag_one: TagOne, tag_two: TagTwo, tag_three: TagThree, tag_four: TagFour, tag_five: TagFive) -> &'a LevelFive {
+            self.get_ref_field(tag_one).get_ref_field(tag_two).get_ref_field(tag_three).get_ref_field(tag_four).get_ref_field(tag_five)
+        }
+    }
+
+    /// A variant of [`SetField`], reaching five levels deep in the data structure, following one tag per level.
+    ///
+    /// It is implemented for all the containers having tagged fields along the chain, so there is no need to write impl blocks.
+    ///
+    /// [`SetField`]: ../trait.SetField.html
+    ///
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub struct Company { pub north: Department, pub south: Department }
+    /// pub struct Department { pub primary: Team, pub backup: Team }
+    /// pub struct Team { pub lead: Employee, pub deputy: Employee }
+    /// pub struct Employee { pub legal_name: Name, pub preferred_name: Name }
+    /// pub struct Name { pub given: String, pub family: String }
+    ///
+    /// tagged! {
+    ///     Company { north: Department => North, south: Department => South }
+    ///     Department { primary: Team => Primary, backup: Team => Backup }
+    ///     Team { lead: Employee => Lead, deputy: Employee => Deputy }
+    ///     Employee { legal_name: Name => LegalName, preferred_name: Name => PreferredName }
+    ///     Name { given: String => Given, family: String => Family }
+    /// }
+    ///
+    /// let name = |given: &str| Name { given: String::from(given), family: String::from("Doe") };
+    /// let employee = |given: &str| Employee { legal_name: name(given), preferred_name: name("Jo") };
+    /// let team = || Team { lead: employee("John"), deputy: employee("Jane") };
+    /// let department = || Department { primary: team(), backup: team() };
+    /// let company = || Company { north: department(), south: department() };
+    ///
+    /// let updated = company().set_field_fifth(South, Backup, Deputy, LegalName, Given, String::from("Janet"));
+    ///
+    /// assert_eq!(("Janet", "Jo"), (updated.south.backup.deputy.legal_name.given.as_str(), updated.south.backup.deputy.preferred_name.given.as_str()));
+    ///
+    ///```
+    pub trait SetFieldFifth<TagOne, TagTwo, TagThree, TagFour, TagFive, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        where
+            LevelFour: SetField<TagFive, LevelFive>,
+            LevelThree: ModifyField<TagFour, LevelFour>,
+            LevelTwo: ModifyField<TagThree, LevelThree>,
+            LevelOne: ModifyField<TagTwo, LevelTwo>,
+            Self: ModifyField<TagOne, LevelOne> + Sized {
+        fn set_field_fifth(self, tag_one: TagOne, tag_two: TagTwo, tag_three: TagThree, tag_four: TagFour, tag_five: TagFive, new_value: LevelFive) -> Self {
+            self.modify_field(tag_one, |level_one| level_one.modify_field(tag_two, |level_two| level_two.modify_field(tag_three, |level_three| level_three.modify_field(tag_four, |level_four| level_four.set_field(tag_five, new_value)))))
+        }
+    }
+
+    /// A variant of [`ModifyField`], reaching five levels deep in the data structure, following one tag per level.
+    ///
+    /// It is implemented for all the containers having tagged fields along the chain, so there is no need to write impl blocks.
+    ///
+    /// [`ModifyField`]: ../trait.ModifyField.html
     ///
-    /// assert_eq!(23, john_doe.get_fifth());
+    /// # Examples
+    ///```
+    /// # use photonix::*;
+    /// pub struct Company { pub north: Department, pub south: Department }
+    /// pub struct Department { pub primary: Team, pub backup: Team }
+    /// pub struct Team { pub lead: Employee, pub deputy: Employee }
+    /// pub struct Employee { pub legal_name: Name, pub preferred_name: Name }
+    /// pub struct Name { pub given: String, pub family: String }
+    ///
+    /// tagged! {
+    ///     Company { north: Department => North, south: Department => South }
+    ///     Department { primary: Team => Primary, backup: Team => Backup }
+    ///     Team { lead: Employee => Lead, deputy: Employee => Deputy }
+    ///     Employee { legal_name: Name => LegalName, preferred_name: Name => PreferredName }
+    ///     Name { given: String => Given, family: String => Family }
+    /// }
+    ///
+    /// let name = |given: &str| Name { given: String::from(given), family: String::from("Doe") };
+    /// let employee = |given: &str| Employee { legal_name: name(given), preferred_name: name("Jo") };
+    /// let team = || Team { lead: employee("John"), deputy: employee("Jane") };
+    /// let department = || Department { primary: team(), backup: team() };
+    /// let company = || Company { north: department(), south: department() };
+    ///
+    /// let updated = company().modify_field_fifth(South, Backup, Deputy, LegalName, Given, |given| given.to_uppercase());
+    ///
+    /// assert_eq!(("JANE", "Jo"), (updated.south.backup.deputy.legal_name.given.as_str(), updated.south.backup.deputy.preferred_name.given.as_str()));
     ///
     ///```
-    pub trait GetFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+    pub trait ModifyFieldFifth<TagOne, TagTwo, TagThree, TagFour, TagFive, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
         where
-            LevelFour: Get<LevelFive>,
-            LevelThree: Get<LevelFour>,
-            LevelTwo: Get<LevelThree>,
-            LevelOne: Get<LevelTwo>,
-            Self: Get<LevelOne> + Sized {
-        fn get_fifth(self) -> LevelFive {
-            self.get().get().get().get().get()
+            LevelFour: ModifyField<TagFive, LevelFive>,
+            LevelThree: ModifyField<TagFour, LevelFour>,
+            LevelTwo: ModifyField<TagThree, LevelThree>,
+            LevelOne: ModifyField<TagTwo, LevelTwo>,
+            Self: ModifyField<TagOne, LevelOne> + Sized {
+        fn modify_field_fifth(self, tag_one: TagOne, tag_two: TagTwo, tag_three: TagThree, tag_four: TagFour, tag_five: TagFive, f: impl FnOnce(LevelFive) -> LevelFive) -> Self {
+            self.modify_field(tag_one, |level_one| level_one.modify_field(tag_two, |level_two| level_two.modify_field(tag_three, |level_three| level_three.modify_field(tag_four, |level_four| level_four.modify_field(tag_five, f)))))
+        }
+    }
+}
+
+/// Auto-implements different [`composites`] of getters, setters, and modifiers.
+///
+/// The requirement is that the elements should have an implementation of [`Get`], [`GetRef`], [`Modify`], and [`Set`] with the target type at the next level (see definitions of [`composites`] for details).
+///
+/// Since [`Get`] and [`GetRef`] are traits for structs, this macro is recommended to use with structs.
+///
+/// Prefixing the path with `mut` additionally implements the [`GetMut`] composites, which requires [`GetMut`] at every level (see also [`zoom!`]).
+///
+/// The path can be longer than five levels. Since there are no named composites beyond [`GetFifth`], in that case the macro only checks at compile time that the path is valid, and the target can be reached with the methods of [`AtPath`] (e.g. `get_ref_at::<path!(...)>()`).
+///
+/// Generic containers are supported by starting with an `impl<...>` header and optionally ending with a where-clause, e.g. `zoom_all![impl<T> Page<T> => Body<T> => T where T: Clone]`. The header may declare lifetimes, and parameters may have several bounds. Generic `Get`, `GetRef`, `Set`, and `Modify` instances can be written with the [`fields!`] macro.
+///
+/// [`Get`]: focus/trait.Get.html
+/// [`GetRef`]: focus/trait.GetRef.html
+/// [`GetMut`]: focus/trait.GetMut.html
+/// [`GetFifth`]: focus/composites/trait.GetFifth.html
+/// [`AtPath`]: focus/trait.AtPath.html
+/// [`Modify`]: focus/trait.Modify.html
+/// [`Set`]: focus/trait.Set.html
+/// [`composites`]: focus/composites/index.html
+/// [`zoom!`]: macro.zoom.html
+/// [`fields!`]: macro.fields.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// #[derive(Get, GetRef, Set, Modify)]
+/// pub struct Employee { pub name: String, pub company: Company }
+/// #[derive(Get, GetRef, Set, Modify)]
+/// pub struct Company { pub name: String, pub address: Address }
+/// #[derive(Get, GetRef, Set, Modify)]
+/// pub struct Address { pub city: String }
+///
+/// let john_doe = Employee {
+///     name: String::from("John Doe"),
+///     company: Company {
+///         name: String::from("Awesome Inc"),
+///         address: Address {
+///             city: String::from("London"),
+///         }
+///     }
+/// };
+///
+/// //      Parent type     Level 1    Level 2
+/// //          |              |         |
+/// zoom_all![Employee => Company => String];
+///
+/// //      Parent type     Level 1    Level 2    Level 3
+/// //          |              |         |          |
+/// zoom_all![Employee => Company => Address => String];
+///
+/// assert_eq!("Awesome Inc", john_doe.get_ref_second().as_str());
+/// assert_eq!("London", john_doe.get_ref_third().as_str());
+///
+/// let john_doe_company_changed_name = john_doe.modify_second(|s| s.replace("Inc", "Corp"));
+///
+/// assert_eq!("Awesome Corp", john_doe_company_changed_name.get_ref_second().as_str());
+///
+/// let john_doe_relocated = john_doe_company_changed_name.set_third(String::from("Paris"));
+///
+/// assert_eq!("Paris", john_doe_relocated.get_ref_third().as_str());
+///
+/// #[derive(Get, GetRef, Set, Modify)]
+/// pub struct Account { pub owner: String, pub balance: Balance }
+/// #[derive(Get, GetRef, Set, Modify)]
+/// pub struct Balance { pub cents: u64 }
+///
+/// impl GetMut<Balance> for Account {
+///     fn get_mut(&mut self) -> &mut Balance { &mut self.balance }
+/// }
+///
+/// impl GetMut<u64> for Balance {
+///     fn get_mut(&mut self) -> &mut u64 { &mut self.cents }
+/// }
+///
+/// zoom_all![mut Account => Balance => u64];
+///
+/// let mut account = Account { owner: String::from("John Doe"), balance: Balance { cents: 100 } };
+///
+/// *account.get_mut_second() += 50;
+///
+/// assert_eq!(150, *account.get_ref_second());
+///
+///```
+#[macro_export]
+macro_rules! zoom_all {
+    (@impl [$($generics:tt)*] [$($where:tt)*] mut $outer:ty => $first:ty => $second:ty) => {
+        impl<'zoom, $($generics)*> GetRefSecond<'zoom, $first, $second> for $outer where $outer: 'zoom, $($where)* {}
+        impl<$($generics)*> GetSecond<$first, $second> for $outer where $($where)* {}
+        zoom!(@impl [$($generics)*] [$($where)*] mut $outer => $first => $second);
+    };
+
+    (@impl [$($generics:tt)*] [$($where:tt)*] $outer:ty => $first:ty => $second:ty) => {
+        impl<'zoom, $($generics)*> GetRefSecond<'zoom, $first, $second> for $outer where $outer: 'zoom, $($where)* {}
+        impl<$($generics)*> GetSecond<$first, $second> for $outer where $($where)* {}
+        zoom!(@impl [$($generics)*] [$($where)*] $outer => $first => $second);
+    };
+
+    (@impl [$($generics:tt)*] [$($where:tt)*] mut $outer:ty => $first:ty => $second:ty => $third:ty) => {
+        impl<'zoom, $($generics)*> GetRefThird<'zoom, $first, $second, $third> for $outer where $outer: 'zoom, $($where)* {}
+        impl<$($generics)*> GetThird<$first, $second, $third> for $outer where $($where)* {}
+        zoom!(@impl [$($generics)*] [$($where)*] mut $outer => $first => $second => $third);
+    };
+
+    (@impl [$($generics:tt)*] [$($where:tt)*] $outer:ty => $first:ty => $second:ty => $third:ty) => {
+        impl<'zoom, $($generics)*> GetRefThird<'zoom, $first, $second, $third> for $outer where $outer: 'zoom, $($where)* {}
+        impl<$($generics)*> GetThird<$first, $second, $third> for $outer where $($where)* {}
+        zoom!(@impl [$($generics)*] [$($where)*] $outer => $first => $second => $third);
+    };
+
+    (@impl [$($generics:tt)*] [$($where:tt)*] mut $outer:ty => $first:ty => $second:ty => $third:ty => $fourth:ty) => {
+        impl<'zoom, $($generics)*> GetRefFourth<'zoom, $first, $second, $third, $fourth> for $outer where $outer: 'zoom, $($where)* {}
+        impl<$($generics)*> GetFourth<$first, $second, $third, $fourth> for $outer where $($where)* {}
+        zoom!(@impl [$($generics)*] [$($where)*] mut $outer => $first => $second => $third => $fourth);
+    };
+
+    (@impl [$($generics:tt)*] [$($where:tt)*] $outer:ty => $first:ty => $second:ty => $third:ty => $fourth:ty) => {
+        impl<'zoom, $($generics)*> GetRefFourth<'zoom, $first, $second, $third, $fourth> for $outer where $outer: 'zoom, $($where)* {}
+        impl<$($generics)*> GetFourth<$first, $second, $third, $fourth> for $outer where $($where)* {}
+        zoom!(@impl [$($generics)*] [$($where)*] $outer => $first => $second => $third => $fourth);
+    };
+
+    (@impl [$($generics:tt)*] [$($where:tt)*] mut $outer:ty => $first:ty => $second:ty => $third:ty => $fourth:ty => $fifth:ty) => {
+        impl<'zoom, $($generics)*> GetRefFifth<'zoom, $first, $second, $third, $fourth, $fifth> for $outer where $outer: 'zoom, $($where)* {}
+        impl<$($generics)*> GetFifth<$first, $second, $third, $fourth, $fifth> for $outer where $($where)* {}
+        zoom!(@impl [$($generics)*] [$($where)*] mut $outer => $first => $second => $third => $fourth => $fifth);
+    };
+
+    (@impl [$($generics:tt)*] [$($where:tt)*] $outer:ty => $first:ty => $second:ty => $third:ty => $fourth:ty => $fifth:ty) => {
+        impl<'zoom, $($generics)*> GetRefFifth<'zoom, $first, $second, $third, $fourth, $fifth> for $outer where $outer: 'zoom, $($where)* {}
+        impl<$($generics)*> GetFifth<$first, $second, $third, $fourth, $fifth> for $outer where $($where)* {}
+        zoom!(@impl [$($generics)*] [$($where)*] $outer => $first => $second => $third => $fourth => $fifth);
+    };
+
+    (@impl [$($generics:tt)*] [$($where:tt)*] mut $outer:ty => $($level:ty)=>+) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn zoom_all<'zoom, $($generics)*>() where $outer: 'zoom, $($where)* {
+                fn check<'b, Container>() where Container: GetMutPath<'b, path!($($level),+)> {}
+                check::<'zoom, $outer>()
+            }
+        };
+        zoom_all!(@impl [$($generics)*] [$($where)*] $outer => $($level)=>+);
+    };
+
+    (@impl [$($generics:tt)*] [$($where:tt)*] $outer:ty => $($level:ty)=>+) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn zoom_all<'zoom, $($generics)*>() where $outer: 'zoom, $($where)* {
+                fn check<'b, Container>() where Container: GetPath<path!($($level),+)> + GetRefPath<'b, path!($($level),+)> {}
+                check::<'zoom, $outer>()
+            }
+        };
+        zoom!(@impl [$($generics)*] [$($where)*] $outer => $($level)=>+);
+    };
+
+    (@generics mut [$($generics:tt)*] $outer:ty => $($level:ty)=>+ $(where $($where:tt)*)?) => {
+        zoom_all!(@impl [$($generics)*] [$($($where)*)?] mut $outer => $($level)=>+);
+    };
+
+    (@generics [$($generics:tt)*] $outer:ty => $($level:ty)=>+ $(where $($where:tt)*)?) => {
+        zoom_all!(@impl [$($generics)*] [$($($where)*)?] $outer => $($level)=>+);
+    };
+
+    (mut impl< $($rest:tt)+) => {
+        split_generics!(zoom_all [mut] [] [] $($rest)+);
+    };
+
+    (impl< $($rest:tt)+) => {
+        split_generics!(zoom_all [] [] [] $($rest)+);
+    };
+
+    (mut $outer:ty => $($level:ty)=>+) => {
+        zoom_all!(@impl [] [] mut $outer => $($level)=>+);
+    };
+
+    ($outer:ty => $($level:ty)=>+) => {
+        zoom_all!(@impl [] [] $outer => $($level)=>+);
+    };
+}
+
+/// Auto-implements [`composites`] of setters and modifiers.
+///
+/// The requirement is that the elements should have an implementation of [`Modify`] and [`Set`] with the target type at the next level (see definitions of [`composites`] for details).
+///
+/// You can use this macro with both structs and enums.
+///
+/// Prefixing the path with `mut` additionally implements the mutable getter composites (e.g. [`GetMutSecond`]) and the in-place composites, which requires [`GetMut`] at every level, and [`ModifyInPlace`] at every level but the last one, and [`SetInPlace`] and [`ModifyInPlace`] at the last one.
+///
+/// The path can be longer than five levels. Since there are no named composites beyond [`SetFifth`], in that case the macro only checks at compile time that the path is valid (with `mut`, that [`GetMut`] is implemented at every level), and the target can be updated with the methods of [`AtPath`] (e.g. `set_at::<path!(...)>(value)`).
+///
+/// Generic containers are supported by starting with an `impl<...>` header and optionally ending with a where-clause, e.g. `zoom![impl<M: Clone> Envelope<M> => Message<M> => M]` (see also [`fields!`]).
+///
+/// [`Modify`]: focus/trait.Modify.html
+/// [`GetMut`]: focus/trait.GetMut.html
+/// [`GetMutSecond`]: focus/composites/trait.GetMutSecond.html
+/// [`SetFifth`]: focus/composites/trait.SetFifth.html
+/// [`AtPath`]: focus/trait.AtPath.html
+/// [`Set`]: focus/trait.Set.html
+/// [`ModifyInPlace`]: focus/trait.ModifyInPlace.html
+/// [`SetInPlace`]: focus/trait.SetInPlace.html
+/// [`composites`]: focus/composites/index.html
+/// [`fields!`]: macro.fields.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// #[derive(GetOption, Set, Modify)]
+/// pub enum User {
+///     Admin,
+///     RegularUser(Employee),
+/// }
+///
+/// #[derive(Get, Set, Modify)]
+/// pub struct Employee { pub name: String, pub company: Company }
+///
+/// #[derive(Get, Set, Modify)]
+/// pub struct Company { pub name: String, pub address: Address }
+///
+/// #[derive(Get, Set, Modify)]
+/// pub struct Address(String);
+///
+/// let john_doe = || Employee {
+///     name: String::from("John Doe"),
+///     company: Company {
+///         name: String::from("Acme Corporation"),
+///         address: Address(String::from("4 Foo Road, Bar City")),
+///         }
+///     };
+///
+/// let john_as_user = || User::RegularUser(john_doe());
+///
+/// //  Parent type   Level 1   Level 2    Level 3    Level 4
+/// //      |           |          |         |         |
+/// zoom![User => Employee => Company => Address => String];
+///
+/// let john_at_new_address = john_as_user().modify_fourth(|s| s.replace("4", "5"));
+///
+/// let maybe_john = john_at_new_address.get_option();
+///
+/// assert_eq!(
+///     Some(String::from("5 Foo Road, Bar City")),
+///     maybe_john.map(|john| john.company.address.0)
+/// );
+///
+/// //  Parent type   Level 1   Level 2
+/// //      |           |        |
+/// zoom![User => Employee => String];
+///
+/// let frank_doe_as_user = john_as_user().set_second(String::from("Frank Doe"));
+///
+/// assert_eq!(
+///     Some(String::from("Frank Doe")),
+///     frank_doe_as_user.get_option().map(|employee| employee.name)
+/// );
+///
+/// #[derive(Set, Modify)]
+/// pub struct Account { pub owner: String, pub balance: Balance }
+///
+/// #[derive(Set, Modify)]
+/// pub struct Balance { pub cents: u64 }
+///
+/// impl GetMut<Balance> for Account {
+///     fn get_mut(&mut self) -> &mut Balance { &mut self.balance }
+/// }
+///
+/// impl GetMut<u64> for Balance {
+///     fn get_mut(&mut self) -> &mut u64 { &mut self.cents }
+/// }
+///
+/// //      Parent type   Level 1  Level 2
+/// //          |           |        |
+/// zoom![mut Account => Balance => u64];
+///
+/// let mut account = Account { owner: String::from("John Doe"), balance: Balance { cents: 100 } };
+///
+/// account.modify_in_place_second(|cents| *cents += 50);
+/// *account.get_mut_second() -= 20;
+///
+/// assert_eq!(130, account.balance.cents);
+///
+///```
+///
+#[macro_export]
+macro_rules! zoom {
+    (@impl [$($generics:tt)*] [$($where:tt)*] mut $outer:ty => $first:ty => $second:ty) => {
+        impl<'zoom, $($generics)*> GetMutSecond<'zoom, $first, $second> for $outer where $outer: 'zoom, $($where)* {}
+        impl<$($generics)*> SetInPlaceSecond<$first, $second> for $outer where $($where)* {}
+        impl<$($generics)*> ModifyInPlaceSecond<$first, $second> for $outer where $($where)* {}
+        zoom!(@impl [$($generics)*] [$($where)*] $outer => $first => $second);
+    };
+
+    (@impl [$($generics:tt)*] [$($where:tt)*] $outer:ty => $first:ty => $second:ty) => {
+        impl<$($generics)*> SetSecond<$first, $second> for $outer where $($where)* {}
+        impl<$($generics)*> ModifySecond<$first, $second> for $outer where $($where)* {}
+    };
+
+    (@impl [$($generics:tt)*] [$($where:tt)*] mut $outer:ty => $first:ty => $second:ty => $third:ty) => {
+        impl<'zoom, $($generics)*> GetMutThird<'zoom, $first, $second, $third> for $outer where $outer: 'zoom, $($where)* {}
+        impl<$($generics)*> SetInPlaceThird<$first, $second, $third> for $outer where $($where)* {}
+        impl<$($generics)*> ModifyInPlaceThird<$first, $second, $third> for $outer where $($where)* {}
+        zoom!(@impl [$($generics)*] [$($where)*] $outer => $first => $second => $third);
+    };
+
+    (@impl [$($generics:tt)*] [$($where:tt)*] $outer:ty => $first:ty => $second:ty => $third:ty) => {
+        impl<$($generics)*> SetThird<$first, $second, $third> for $outer where $($where)* {}
+        impl<$($generics)*> ModifyThird<$first, $second, $third> for $outer where $($where)* {}
+    };
+
+    (@impl [$($generics:tt)*] [$($where:tt)*] mut $outer:ty => $first:ty => $second:ty => $third:ty => $fourth:ty) => {
+        impl<'zoom, $($generics)*> GetMutFourth<'zoom, $first, $second, $third, $fourth> for $outer where $outer: 'zoom, $($where)* {}
+        impl<$($generics)*> SetInPlaceFourth<$first, $second, $third, $fourth> for $outer where $($where)* {}
+        impl<$($generics)*> ModifyInPlaceFourth<$first, $second, $third, $fourth> for $outer where $($where)* {}
+        zoom!(@impl [$($generics)*] [$($where)*] $outer => $first => $second => $third => $fourth);
+    };
+
+    (@impl [$($generics:tt)*] [$($where:tt)*] $outer:ty => $first:ty => $second:ty => $third:ty => $fourth:ty) => {
+        impl<$($generics)*> SetFourth<$first, $second, $third, $fourth> for $outer where $($where)* {}
+        impl<$($generics)*> ModifyFourth<$first, $second, $third, $fourth> for $outer where $($where)* {}
+    };
+
+    (@impl [$($generics:tt)*] [$($where:tt)*] mut $outer:ty => $first:ty => $second:ty => $third:ty => $fourth:ty => $fifth:ty) => {
+        impl<'zoom, $($generics)*> GetMutFifth<'zoom, $first, $second, $third, $fourth, $fifth> for $outer where $outer: 'zoom, $($where)* {}
+        impl<$($generics)*> SetInPlaceFifth<$first, $second, $third, $fourth, $fifth> for $outer where $($where)* {}
+        impl<$($generics)*> ModifyInPlaceFifth<$first, $second, $third, $fourth, $fifth> for $outer where $($where)* {}
+        zoom!(@impl [$($generics)*] [$($where)*] $outer => $first => $second => $third => $fourth => $fifth);
+    };
+
+    (@impl [$($generics:tt)*] [$($where:tt)*] $outer:ty => $first:ty => $second:ty => $third:ty => $fourth:ty => $fifth:ty) => {
+        impl<$($generics)*> SetFifth<$first, $second, $third, $fourth, $fifth> for $outer where $($where)* {}
+        impl<$($generics)*> ModifyFifth<$first, $second, $third, $fourth, $fifth> for $outer where $($where)* {}
+    };
+
+    (@impl [$($generics:tt)*] [$($where:tt)*] mut $outer:ty => $($level:ty)=>+) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn zoom<'zoom, $($generics)*>() where $outer: 'zoom, $($where)* {
+                fn check<'b, Container>() where Container: GetMutPath<'b, path!($($level),+)> {}
+                check::<'zoom, $outer>()
+            }
+        };
+        zoom!(@impl [$($generics)*] [$($where)*] $outer => $($level)=>+);
+    };
+
+    (@impl [$($generics:tt)*] [$($where:tt)*] $outer:ty => $($level:ty)=>+) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn zoom<$($generics)*>() where $($where)* {
+                fn check<Container>() where Container: SetPath<path!($($level),+)> + ModifyPath<path!($($level),+)> {}
+                check::<$outer>()
+            }
+        };
+    };
+
+    (@generics mut [$($generics:tt)*] $outer:ty => $($level:ty)=>+ $(where $($where:tt)*)?) => {
+        zoom!(@impl [$($generics)*] [$($($where)*)?] mut $outer => $($level)=>+);
+    };
+
+    (@generics [$($generics:tt)*] $outer:ty => $($level:ty)=>+ $(where $($where:tt)*)?) => {
+        zoom!(@impl [$($generics)*] [$($($where)*)?] $outer => $($level)=>+);
+    };
+
+    (mut impl< $($rest:tt)+) => {
+        split_generics!(zoom [mut] [] [] $($rest)+);
+    };
+
+    (impl< $($rest:tt)+) => {
+        split_generics!(zoom [] [] [] $($rest)+);
+    };
+
+    (mut $outer:ty => $($level:ty)=>+) => {
+        zoom!(@impl [] [] mut $outer => $($level)=>+);
+    };
+
+    ($outer:ty => $($level:ty)=>+) => {
+        zoom!(@impl [] [] $outer => $($level)=>+);
+    };
+}
+
+/// Splits the generic parameters of an `impl<...>` header off the tokens following it, for the macros that accept such headers ([`fields!`], [`tagged!`], [`zoom!`], and [`zoom_all!`]). The parameters may be lifetimes, and may have several bounds (`T: Clone + Debug`), which the `path` fragment can't express.
+///
+/// It is called with the name of the calling macro, the extra tokens to pass back, and the tokens after `impl<`, and calls the macro back as `name!(@generics extra [parameters] rest)`.
+///
+/// [`fields!`]: macro.fields.html
+/// [`tagged!`]: macro.tagged.html
+/// [`zoom!`]: macro.zoom.html
+/// [`zoom_all!`]: macro.zoom_all.html
+#[doc(hidden)]
+#[macro_export]
+macro_rules! split_generics {
+    ($callback:ident [$($extra:tt)*] [$($generics:tt)*] [] > $($rest:tt)*) => {
+        $callback!(@generics $($extra)* [$($generics)*] $($rest)*);
+    };
+
+    ($callback:ident [$($extra:tt)*] [$($generics:tt)*] [] >> $($rest:tt)*) => {
+        compile_error!("unbalanced `>` in the generic parameters");
+    };
+
+    ($callback:ident [$($extra:tt)*] [$($generics:tt)*] [<] >> $($rest:tt)*) => {
+        $callback!(@generics $($extra)* [$($generics)* >] $($rest)*);
+    };
+
+    ($callback:ident [$($extra:tt)*] [$($generics:tt)*] [< < $($depth:tt)*] >> $($rest:tt)*) => {
+        split_generics!($callback [$($extra)*] [$($generics)* >>] [$($depth)*] $($rest)*);
+    };
+
+    ($callback:ident [$($extra:tt)*] [$($generics:tt)*] [< $($depth:tt)*] > $($rest:tt)*) => {
+        split_generics!($callback [$($extra)*] [$($generics)* >] [$($depth)*] $($rest)*);
+    };
+
+    ($callback:ident [$($extra:tt)*] [$($generics:tt)*] [$($depth:tt)*] < $($rest:tt)*) => {
+        split_generics!($callback [$($extra)*] [$($generics)* <] [< $($depth)*] $($rest)*);
+    };
+
+    ($callback:ident [$($extra:tt)*] [$($generics:tt)*] [$($depth:tt)*] << $($rest:tt)*) => {
+        split_generics!($callback [$($extra)*] [$($generics)* <<] [< < $($depth)*] $($rest)*);
+    };
+
+    ($callback:ident [$($extra:tt)*] [$($generics:tt)*] [$($depth:tt)*] $next:tt $($rest:tt)*) => {
+        split_generics!($callback [$($extra)*] [$($generics)* $next] [$($depth)*] $($rest)*);
+    };
+}
+
+/// Implements [`Get`], [`GetRef`], [`GetMut`], [`Set`], and [`Modify`] for the listed fields of a struct, which can be generic. This is the declarative counterpart of the auto-derives for the cases they don't cover, e.g. fields with generic types. The types of the listed fields should be different. Fields of tuple structs are referred to by their index.
+///
+/// A generic struct is introduced with an `impl<...>` header (lifetimes and type parameters, with any number of bounds), optionally followed by a where-clause. The resulting containers can be used with [`zoom!`] and [`zoom_all!`] with the same header.
+///
+/// [`Get`]: focus/trait.Get.html
+/// [`GetRef`]: focus/trait.GetRef.html
+/// [`GetMut`]: focus/trait.GetMut.html
+/// [`Set`]: focus/trait.Set.html
+/// [`Modify`]: focus/trait.Modify.html
+/// [`zoom!`]: macro.zoom.html
+/// [`zoom_all!`]: macro.zoom_all.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// pub struct Page<T> { pub number: u32, pub body: Body<T> }
+///
+/// pub struct Body<T> { pub items: Vec<T>, pub title: String }
+///
+/// fields! {
+///     impl<T> Page<T> {
+///         number: u32,
+///         body: Body<T>,
+///     }
+/// }
+///
+/// fields! {
+///     impl<T> Body<T> where T: Clone + PartialEq {
+///         items: Vec<T>,
+///         title: String,
+///     }
+/// }
+///
+/// zoom_all![impl<T> Page<T> => Body<T> => Vec<T> where T: Clone + PartialEq];
+///
+/// let page = Page { number: 1, body: Body { items: vec!['a', 'b'], title: String::from("Letters") } };
+///
+/// assert_eq!(&vec!['a', 'b'], page.get_ref_second());
+///
+/// let page = page.modify_second(|mut items: Vec<char>| { items.push('c'); items });
+///
+/// assert_eq!(vec!['a', 'b', 'c'], page.body.items);
+///
+///```
+#[macro_export]
+macro_rules! fields {
+    (@fields [$($generics:tt)*] [$($where:tt)*] $container:ty, $field:tt: $value:ty $(, $($rest:tt)*)?) => {
+        impl<$($generics)*> Get<$value> for $container where $($where)* {
+            fn get(self) -> $value {
+                self.$field
+            }
         }
-    }
 
-    /// A variant of [`GetRef`], reaching five levels deep in the data structure.
-    ///
-    /// [`GetRef`]: ../trait.GetRef.html
-    ///
-    /// # Examples
-    ///```
-    ///  # use photonix::*;
-    ///  #[derive(GetRef)]
-    ///  pub struct Employee { pub name: String, pub company: Company }
-    ///
-    ///  #[derive(GetRef)]
-    ///  pub struct Company { pub name: String, pub address: Address }
-    ///
-    ///  #[derive(GetRef)]
-    ///  pub struct Address { pub city: String, pub street: Street }
-    ///
-    ///  #[derive(GetRef)]
-    ///  pub struct Street { pub number: StreetNumber, pub name: String }
-    ///
-    ///  #[derive(GetRef)]
-    ///  pub struct StreetNumber(u16);
-    ///
-    /// let john_doe = Employee {
-    ///     name: String::from("John Doe"),
-    ///     company: Company {
-    ///         name: String::from("Acme Corporation"),
-    ///         address: Address {
-    ///                 city: String::from("London"),
-    ///                 street: Street {
-    ///                     number: StreetNumber(23),
-    ///                     name: String::from("High street"),
-    ///                 }
-    ///             },
-    ///         }
-    ///     };
-    ///
-    /// //                      Level 1   Level 2   Level 3 Level     Level 5   Parent type
-    /// //                         |         |        |       |           |         |
-    /// impl<'a> GetRefFifth<'a, Company, Address, Street, StreetNumber, u16> for Employee {}
-    ///
-    /// assert_eq!(23, *john_doe.get_ref_fifth());
-    ///
-    ///```
-    pub trait GetRefFifth<'a, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
-        where
-            LevelFour: GetRef<LevelFive> + 'a,
-            LevelThree: GetRef<LevelFour> + 'a,
-            LevelTwo: GetRef<LevelThree> + 'a,
-            LevelOne: GetRef<LevelTwo> + 'a,
-            Self: GetRef<LevelOne> + 'a {
-        fn get_ref_fifth(&'a self) -> &'a LevelFive {
-            self.get_ref().get_ref().get_ref().get_ref().get_ref()
+        impl<$($generics)*> GetRef<$value> for $container where $($where)* {
+            fn get_ref(&self) -> &$value {
+                &self.$field
+            }
         }
-    }
 
-    /// A variant of [`Set`], reaching five levels deep in the data structure.
-    ///
-    /// [`Set`]: ../trait.Set.html
-    ///
-    /// # Examples
-    ///```
-    ///  # use photonix::*;
-    ///  #[derive(Get, Modify)]
-    ///  pub struct Employee { pub name: String, pub company: Company }
-    ///
-    ///  #[derive(Get, Modify)]
-    ///  pub struct Company { pub name: String, pub address: Address }
-    ///
-    ///  #[derive(Get, Modify)]
-    ///  pub struct Address { pub city: String, pub street: Street }
-    ///
-    ///  #[derive(Get, Modify)]
-    ///  pub struct Street { pub number: StreetNumber, pub name: String }
-    ///
-    ///  #[derive(Get, Set)]
-    ///  pub struct StreetNumber(u16);
-    ///
-    /// let john_doe = Employee {
-    ///     name: String::from("John Doe"),
-    ///     company: Company {
-    ///         name: String::from("Acme Corporation"),
-    ///         address: Address {
-    ///                 city: String::from("London"),
-    ///                 street: Street {
-    ///                     number: StreetNumber(23),
-    ///                     name: String::from("High street"),
-    ///                 }
-    ///             },
-    ///         }
-    ///     };
-    ///
-    /// //            Level 1   Level 2   Level 3 Level     Level 5   Parent type
-    /// //               |         |        |       |          |         |
-    /// impl SetFifth<Company, Address, Street, StreetNumber, u16> for Employee {}
-    /// impl GetFifth<Company, Address, Street, StreetNumber, u16> for Employee {}
-    ///
-    /// let john_doe_new_number = john_doe.set_fifth(666);
-    ///
-    /// assert_eq!(666, john_doe_new_number.get_fifth());
-    ///
-    ///```
-    pub trait SetFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
-        where
-            LevelFour: Set<LevelFive>,
-            LevelThree: Modify<LevelFour>,
-            LevelTwo: Modify<LevelThree>,
-            LevelOne: Modify<LevelTwo>,
-            Self: Modify<LevelOne> + Sized {
-        fn set_fifth(self, new_value: LevelFive) -> Self {
-            self.modify(
-                |level_one| level_one.modify(
-                    |level_two| level_two.modify(
-                        |level_three| level_three.modify(
-                            |level_four| level_four.set(new_value)
-                        )
-                    )
-                )
-            )
+        impl<$($generics)*> GetMut<$value> for $container where $($where)* {
+            fn get_mut(&mut self) -> &mut $value {
+                &mut self.$field
+            }
         }
-    }
 
-    /// A variant of [`Modify`], reaching five levels deep in the data structure.
-    ///
-    /// [`Modify`]: ../trait.Modify.html
-    ///
-    /// # Examples
-    ///```
-    ///  # use photonix::*;
-    ///  #[derive(Get, Modify)]
-    ///  pub struct Employee { pub name: String, pub company: Company }
-    ///
-    ///  #[derive(Get, Modify)]
-    ///  pub struct Company { pub name: String, pub address: Address }
-    ///
-    ///  #[derive(Get, Modify)]
-    ///  pub struct Address { pub city: String, pub street: Street }
-    ///
-    ///  #[derive(Get, Modify)]
-    ///  pub struct Street { pub number: StreetNumber, pub name: String }
-    ///
-    ///  #[derive(Get, Modify)]
-    ///  pub struct StreetNumber(u16);
-    ///
-    /// let john_doe = Employee {
-    ///     name: String::from("John Doe"),
-    ///     company: Company {
-    ///         name: String::from("Acme Corporation"),
-    ///         address: Address {
-    ///                 city: String::from("London"),
-    ///                 street: Street {
-    ///                     number: StreetNumber(23),
-    ///                     name: String::from("High street"),
-    ///                 }
-    ///             },
-    ///         }
-    ///     };
-    ///
-    /// //              Level 1   Level 2   Level 3 Level     Level 5   Parent type
-    /// //                 |         |        |       |          |         |
-    /// impl ModifyFifth<Company, Address, Street, StreetNumber, u16> for Employee {}
-    /// impl GetFifth<Company, Address, Street, StreetNumber, u16> for Employee {}
-    ///
-    /// let john_doe_new_number = john_doe.modify_fifth(|num: u16| num * 10);
-    ///
-    /// assert_eq!(230, john_doe_new_number.get_fifth());
-    ///
-    ///```
-    pub trait ModifyFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
-        where
-            LevelFour: Modify<LevelFive>,
-            LevelThree: Modify<LevelFour>,
-            LevelTwo: Modify<LevelThree>,
-            LevelOne: Modify<LevelTwo>,
-            Self: Modify<LevelOne> + Sized {
-        fn modify_fifth(self, f: impl FnOnce(LevelFive) -> LevelFive) -> Self {
-            self.modify(
-                |level_one| level_one.modify(
-                    |level_two| level_two.modify(
-                        |level_three| level_three.modify(
-                            |level_four| level_four.modify(f)
-                        )
-                    )
-                )
-            )
+        impl<$($generics)*> Set<$value> for $container where $($where)* {
+            fn set(mut self, new_value: $value) -> Self {
+                self.$field = new_value;
+                self
+            }
+        }
+
+        impl<$($generics)*> Modify<$value> for $container where $($where)* {
+            fn modify(mut self, f: impl FnOnce($value) -> $value) -> Self {
+                self.$field = f(self.$field);
+                self
+            }
+        }
+
+        fields!(@fields [$($generics)*] [$($where)*] $container, $($($rest)*)?);
+    };
+
+    (@fields [$($generics:tt)*] [$($where:tt)*] $container:ty, ) => {};
+
+    (@where [$($generics:tt)*] $container:ty [$($where:tt)*] { $($fields:tt)* }) => {
+        fields!(@fields [$($generics)*] [$($where)*] $container, $($fields)*);
+    };
+
+    (@where [$($generics:tt)*] $container:ty [$($where:tt)*] $next:tt $($rest:tt)*) => {
+        fields!(@where [$($generics)*] $container [$($where)* $next] $($rest)*);
+    };
+
+    (@generics [$($generics:tt)*] $container:ty where $($rest:tt)+) => {
+        fields!(@where [$($generics)*] $container [] $($rest)+);
+    };
+
+    (@generics [$($generics:tt)*] $container:ty { $($fields:tt)* }) => {
+        fields!(@fields [$($generics)*] [] $container, $($fields)*);
+    };
+
+    (impl< $($rest:tt)+) => {
+        split_generics!(fields [] [] [] $($rest)+);
+    };
+
+    ($container:ty { $($fields:tt)* }) => {
+        fields!(@fields [] [] $container, $($fields)*);
+    };
+}
+
+/// Implements [`Get`], [`GetRef`], [`GetMut`], [`Set`], and [`Modify`] for the listed fields of a struct, without the auto-derives. It is meant for types that can't be derived on, as long as their fields are public (tuple struct fields are referred to by their index).
+///
+/// It is a one-line shorthand for [`fields!`] on a non-generic container; generic ones go through [`fields!`] with an `impl<...>` header.
+///
+/// The orphan rule still applies: for a type defined in another crate, either the container or the value type has to be local.
+///
+/// [`Get`]: focus/trait.Get.html
+/// [`GetRef`]: focus/trait.GetRef.html
+/// [`GetMut`]: focus/trait.GetMut.html
+/// [`Set`]: focus/trait.Set.html
+/// [`Modify`]: focus/trait.Modify.html
+/// [`fields!`]: macro.fields.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// use std::num::Wrapping;
+///
+/// pub struct Point { pub x: f32, pub y: i32 }
+///
+/// #[derive(Debug, PartialEq)]
+/// pub struct Cents(u64);
+///
+/// //  Container  Field  Type
+/// //      |        |     |
+/// lens!(Point => x: f32, y: i32);
+/// lens!(Wrapping<Cents> => 0: Cents);
+///
+/// let mut moved = Point { x: 1.5, y: 2 }.modify(|y: i32| y - 5);
+///
+/// moved.modify_in_place(|x: &mut f32| *x *= 2.0);
+///
+/// assert_eq!((3.0, -3), (*moved.get_ref(), moved.get()));
+/// assert_eq!(Cents(10), Wrapping(Cents(0)).set(Cents(10)).get());
+///
+///```
+#[macro_export]
+macro_rules! lens {
+    ($container:ty => $($field:tt: $value:ty),+ $(,)?) => {
+        fields!($container { $($field: $value),+ });
+    };
+}
+
+/// Implements [`GetOption`], [`SetOption`], [`ModifyOption`], and [`ReverseGet`] for a single-field tuple variant of an enum, without the auto-derives. The variant is given as `Enum::Variant`, or as `<Enum<T>>::Variant` when the enum has generic parameters.
+///
+/// The orphan rule still applies: for a type defined in another crate, either the enum or the value type has to be local.
+///
+/// [`GetOption`]: focus/trait.GetOption.html
+/// [`SetOption`]: focus/trait.SetOption.html
+/// [`ModifyOption`]: focus/trait.ModifyOption.html
+/// [`ReverseGet`]: focus/trait.ReverseGet.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// use std::ops::Bound;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum Shape { Circle(f64), Square(u32) }
+///
+/// #[derive(Debug, PartialEq)]
+/// pub struct Year(u16);
+///
+/// //       Enum   Variant  Type
+/// //        |       |       |
+/// prism!(Shape::Circle => f64);
+/// prism!(Shape::Square => u32);
+/// prism!(<Bound<Year>>::Included => Year);
+///
+/// assert_eq!(Some(2.0), Shape::Circle(2.0).get_option());
+/// assert_eq!(None, Shape::Circle(2.0).set_option(4u32));
+/// assert_eq!(Some(Shape::Square(5)), Shape::Square(4).modify_option(|side: u32| side + 1));
+/// assert_eq!(Shape::Circle(0.5), Shape::reverse_get(0.5));
+///
+/// assert_eq!(Some(Year(1848)), Bound::Included(Year(1848)).get_option());
+/// assert_eq!(None, Bound::Excluded(Year(1848)).get_option());
+///
+///```
+#[macro_export]
+macro_rules! prism {
+    (@impl $enum:ty, $variant:ident, $value:ty) => {
+        impl GetOption<$value> for $enum {
+            #[allow(unreachable_patterns)]
+            fn get_option(self) -> Option<$value> {
+                match self {
+                    Self::$variant(value) => Some(value),
+                    _ => None,
+                }
+            }
         }
-    }
+
+        impl SetOption<$value> for $enum {
+            #[allow(unreachable_patterns)]
+            fn set_option(self, new_value: $value) -> Option<Self> {
+                match self {
+                    Self::$variant(_) => Some(Self::$variant(new_value)),
+                    _ => None,
+                }
+            }
+        }
+
+        impl ModifyOption<$value> for $enum {
+            #[allow(unreachable_patterns)]
+            fn modify_option(self, f: impl FnOnce($value) -> $value) -> Option<Self> {
+                match self {
+                    Self::$variant(value) => Some(Self::$variant(f(value))),
+                    _ => None,
+                }
+            }
+        }
+
+        impl ReverseGet<$value> for $enum {
+            fn reverse_get(value: $value) -> Self {
+                Self::$variant(value)
+            }
+        }
+    };
+
+    (@split [$($enum:tt)+] $variant:ident => $value:ty) => {
+        prism!(@impl $($enum)+, $variant, $value);
+    };
+
+    (@split [$($enum:tt)+] $segment:ident :: $($rest:tt)+) => {
+        prism!(@split [$($enum)+ :: $segment] $($rest)+);
+    };
+
+    (<$enum:ty>::$variant:ident => $value:ty) => {
+        prism!(@impl $enum, $variant, $value);
+    };
+
+    ($first:ident :: $($rest:tt)+) => {
+        prism!(@split [$first] $($rest)+);
+    };
 }
 
-/// Auto-implements different [`composites`] of getters, setters, and modifiers.
+/// Defines a [`Computed`] lens as a constant from a getter and a setter, and implements [`Get`], [`Set`], and [`Modify`] for the container with it. The focus is a value derived from the container (e.g. a unit conversion), so the target type should be distinct from the types of the fields with the same instances.
 ///
-/// The requirement is that the elements should have an implementation of [`Get`], [`GetRef`], [`Modify`], and [`Set`] with the target type at the next level (see definitions of [`composites`] for details).
-///
-/// Since [`Get`] and [`GetRef`] are traits for structs, this macro is recommended to use with structs.
+/// The constant can be used for checking the lens laws (see [`Computed`]).
 ///
+/// [`Computed`]: optics/struct.Computed.html
 /// [`Get`]: focus/trait.Get.html
-/// [`GetRef`]: focus/trait.GetRef.html
-/// [`Modify`]: focus/trait.Modify.html
 /// [`Set`]: focus/trait.Set.html
-/// [`composites`]: focus/composites/index.html
+/// [`Modify`]: focus/trait.Modify.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// use std::time::Duration;
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// pub struct Timeout { pub millis: u64 }
+///
+/// //       Name       Container   Target
+/// //        |            |          |
+/// computed!(pub DURATION: Timeout => Duration {
+///     get: |timeout| Duration::from_millis(timeout.millis),
+///     set: |_, duration| Timeout { millis: duration.as_millis() as u64 },
+/// });
+///
+/// let timeout = Timeout { millis: 1500 };
+///
+/// assert_eq!(Duration::from_secs_f32(1.5), timeout.clone().get());
+/// assert_eq!(Timeout { millis: 3000 }, timeout.clone().modify(|d: Duration| d * 2));
+/// assert_eq!(Ok(()), DURATION.check(vec![timeout, Timeout { millis: 0 }]));
+///
+///```
+#[macro_export]
+macro_rules! computed {
+    ($vis:vis $name:ident: $container:ty => $target:ty { get: $getter:expr, set: $setter:expr $(,)? }) => {
+        $vis const $name: Computed<$container, $target> = Computed::new($getter, $setter);
+
+        impl Get<$target> for $container {
+            fn get(self) -> $target {
+                $name.get(&self)
+            }
+        }
+
+        impl Set<$target> for $container {
+            fn set(self, new_value: $target) -> Self {
+                $name.set(self, new_value)
+            }
+        }
+
+        impl Modify<$target> for $container {
+            fn modify(self, f: impl FnOnce($target) -> $target) -> Self {
+                $name.modify(self, f)
+            }
+        }
+    };
+}
+
+/// Spells out a type-level [`Path`] from the types of the consecutive levels, e.g. `path!(Company, Address, String)` stands for `Path<Company, Path<Address, Path<String, End>>>`.
+///
+/// [`Path`]: focus/struct.Path.html
 ///
 /// # Examples
 ///```
 /// # use photonix::*;
 /// #[derive(Get, GetRef, Set, Modify)]
 /// pub struct Employee { pub name: String, pub company: Company }
+///
 /// #[derive(Get, GetRef, Set, Modify)]
 /// pub struct Company { pub name: String, pub address: Address }
+///
 /// #[derive(Get, GetRef, Set, Modify)]
 /// pub struct Address { pub city: String }
 ///
 /// let john_doe = Employee {
 ///     name: String::from("John Doe"),
 ///     company: Company {
-///         name: String::from("Awesome Inc"),
-///         address: Address {
-///             city: String::from("London"),
-///         }
-///     }
+///         name: String::from("Acme Corporation"),
+///         address: Address { city: String::from("London") },
+///     },
 /// };
 ///
-/// //      Parent type     Level 1    Level 2
-/// //          |              |         |
-/// zoom_all![Employee => Company => String];
+/// type City = path!(Company, Address, String);
 ///
-/// //      Parent type     Level 1    Level 2    Level 3
-/// //          |              |         |          |
-/// zoom_all![Employee => Company => Address => String];
+/// let relocated = john_doe.modify_at::<City>(|city| city.replace("London", "Paris"));
 ///
-/// assert_eq!("Awesome Inc", john_doe.get_ref_second().as_str());
-/// assert_eq!("London", john_doe.get_ref_third().as_str());
+/// assert_eq!("Paris", relocated.get_ref_at::<City>().as_str());
 ///
-/// let john_doe_company_changed_name = john_doe.modify_second(|s| s.replace("Inc", "Corp"));
+///```
+#[macro_export]
+macro_rules! path {
+    ($last:ty) => {
+        Path<$last, End>
+    };
+
+    ($head:ty, $($tail:ty),+) => {
+        Path<$head, path!($($tail),+)>
+    };
+}
+
+
+/// Auto-implements [`composites`] of getters, setters, and modifiers for enums (prisms).
 ///
-/// assert_eq!("Awesome Corp", john_doe_company_changed_name.get_ref_second().as_str());
+/// The requirement is that the elements should have an implementation of [`GetOption`] and [`ReverseGet`] with the type at the next level, and the last but one level should have an implementation of [`GetOption`], [`SetOption`], and [`ModifyOption`] with the target type (see definitions of [`composites`] for details).
 ///
-/// let john_doe_relocated = john_doe_company_changed_name.set_third(String::from("Paris"));
+/// [`GetOption`]: focus/trait.GetOption.html
+/// [`SetOption`]: focus/trait.SetOption.html
+/// [`ModifyOption`]: focus/trait.ModifyOption.html
+/// [`ReverseGet`]: focus/trait.ReverseGet.html
+/// [`composites`]: focus/composites/index.html
 ///
-/// assert_eq!("Paris", john_doe_relocated.get_ref_third().as_str());
+/// # Examples
+///```
+/// # use photonix::*;
+/// #[derive(Debug, GetOption, SetOption, ModifyOption, PartialEq)]
+/// pub enum Event { Payment(Payment), Ping }
+///
+/// #[derive(Debug, GetOption, SetOption, ModifyOption, PartialEq)]
+/// pub enum Payment { Cash(u32), Card(Card) }
+///
+/// #[derive(Debug, GetOption, SetOption, ModifyOption, PartialEq)]
+/// pub enum Card { Debit(u16), Credit(u64) }
+///
+/// impl ReverseGet<Payment> for Event {
+///     fn reverse_get(value: Payment) -> Self { Event::Payment(value) }
+/// }
+///
+/// impl ReverseGet<Card> for Payment {
+///     fn reverse_get(value: Card) -> Self { Payment::Card(value) }
+/// }
+///
+/// // Parent type  Level 1    Level 2
+/// //     |           |         |
+/// zoom_option![Event => Payment => u32];
+///
+/// // Parent type  Level 1    Level 2   Level 3
+/// //     |           |         |         |
+/// zoom_option![Event => Payment => Card => u16];
+///
+/// let debit = || Event::Payment(Payment::Card(Card::Debit(1234)));
+///
+/// assert_eq!(Some(1234u16), debit().get_option_third());
+/// assert_eq!(None, GetOptionSecond::<Payment, u32>::get_option_second(debit()));
+///
+/// assert_eq!(
+///     Some(Event::Payment(Payment::Card(Card::Debit(4321)))),
+///     debit().set_option_third(4321u16)
+/// );
+///
+/// assert_eq!(
+///     Some(Event::Payment(Payment::Cash(40))),
+///     Event::Payment(Payment::Cash(20)).modify_option_second(|cash: u32| cash * 2)
+/// );
+///
+/// assert_eq!(None, Event::Ping.modify_option_second(|cash: u32| cash * 2));
 ///
 ///```
 #[macro_export]
-macro_rules! zoom_all {
+macro_rules! zoom_option {
     ($outer:ty => $first:ty => $second:ty) => {
-        impl<'a> GetRefSecond<'a, $first, $second> for $outer {}
-        impl GetSecond<$first, $second> for $outer {}
-        zoom!($outer => $first => $second);
+        impl GetOptionSecond<$first, $second> for $outer {}
+        impl SetOptionSecond<$first, $second> for $outer {}
+        impl ModifyOptionSecond<$first, $second> for $outer {}
     };
 
     ($outer:ty => $first:ty => $second:ty => $third:ty) => {
-        impl<'a> GetRefThird<'a, $first, $second, $third> for $outer {}
-        impl GetThird<$first, $second, $third> for $outer {}
-        zoom!($outer => $first => $second => $third);
+        impl GetOptionThird<$first, $second, $third> for $outer {}
+        impl SetOptionThird<$first, $second, $third> for $outer {}
+        impl ModifyOptionThird<$first, $second, $third> for $outer {}
     };
 
     ($outer:ty => $first:ty => $second:ty => $third:ty => $fourth:ty) => {
-        impl<'a> GetRefFourth<'a, $first, $second, $third, $fourth> for $outer {}
-        impl GetFourth<$first, $second, $third, $fourth> for $outer {}
-        zoom!($outer => $first => $second => $third => $fourth);
+        impl GetOptionFourth<$first, $second, $third, $fourth> for $outer {}
+        impl SetOptionFourth<$first, $second, $third, $fourth> for $outer {}
+        impl ModifyOptionFourth<$first, $second, $third, $fourth> for $outer {}
     };
 
     ($outer:ty => $first:ty => $second:ty => $third:ty => $fourth:ty => $fifth:ty) => {
-        impl<'a> GetRefFifth<'a, $first, $second, $third, $fourth, $fifth> for $outer {}
-        impl GetFifth<$first, $second, $third, $fourth, $fifth> for $outer {}
-        zoom!($outer => $first => $second => $third => $fourth => $fifth);
+        impl GetOptionFifth<$first, $second, $third, $fourth, $fifth> for $outer {}
+        impl SetOptionFifth<$first, $second, $third, $fourth, $fifth> for $outer {}
+        impl ModifyOptionFifth<$first, $second, $third, $fourth, $fifth> for $outer {}
     };
 }
 
-/// Auto-implements [`composites`] of setters and modifiers.
+/// Auto-implements affine [`composites`] (mixed chains of structs and enums).
 ///
-/// The requirement is that the elements should have an implementation of [`Modify`] and [`Set`] with the target type at the next level (see definitions of [`composites`] for details).
+/// Prefix a level with `?` if the previous level reaches it via [`GetOption`] (enum variant), or with `&` if it should be retrieved via [`GetRef`] and cloned. Unmarked levels are reached via [`Get`]. Besides `GetAffineSecond` … `GetAffineFifth`, the macro implements the corresponding [`SetSecond`] and [`ModifySecond`] variants, so every level should implement [`Modify`] with the next one, and the last but one level should implement [`Set`] as well. The derived [`Set`] and [`Modify`] of enums leave other variants untouched, therefore updates behave like affine traversals: they take effect only if every level is present.
 ///
-/// You can use this macro with both structs and enums.
+/// [`Get`]: focus/trait.Get.html
+/// [`GetRef`]: focus/trait.GetRef.html
+/// [`GetOption`]: focus/trait.GetOption.html
+/// [`Set`]: focus/trait.Set.html
+/// [`Modify`]: focus/trait.Modify.html
+/// [`SetSecond`]: focus/composites/trait.SetSecond.html
+/// [`ModifySecond`]: focus/composites/trait.ModifySecond.html
+/// [`composites`]: focus/composites/index.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// #[derive(Debug, Get, Set, Modify, PartialEq)]
+/// pub struct Order { pub id: u32, pub payment: Option<Payment> }
+///
+/// #[derive(Debug, GetOption, Set, Modify, PartialEq)]
+/// pub enum Payment { Card(Card), Cash(u32) }
+///
+/// #[derive(Debug, Get, Set, Modify, PartialEq)]
+/// pub struct Card { pub last4: u16, pub holder: String }
+///
+/// // Parent type     Level 1            Level 2     Level 3   Level 4
+/// //     |              |                  |           |         |
+/// zoom_affine![Order => Option<Payment> => ?Payment => ?Card => u16];
+///
+/// let card = || Card { last4: 1234, holder: String::from("John Doe") };
+/// let paid_by_card = || Order { id: 1, payment: Some(Payment::Card(card())) };
+/// let paid_in_cash = || Order { id: 2, payment: Some(Payment::Cash(20)) };
+/// let unpaid = || Order { id: 3, payment: None };
+///
+/// assert_eq!(Some(1234), paid_by_card().get_affine_fourth());
+/// assert_eq!(None, paid_in_cash().get_affine_fourth());
+/// assert_eq!(None, unpaid().get_affine_fourth());
+///
+/// assert_eq!(Some(4321), paid_by_card().set_fourth(4321).get_affine_fourth());
+/// assert_eq!(paid_in_cash(), paid_in_cash().modify_fourth(|last4| last4 + 1));
+/// assert_eq!(unpaid(), unpaid().set_fourth(4321));
+///
+///```
+#[macro_export]
+macro_rules! zoom_affine {
+    (@hops $outer:ty; [$($hops:tt)*]; ? $level:ty => $($rest:tt)+) => {
+        zoom_affine!(@hops $outer; [$($hops)* ($level, ViaGetOption)]; $($rest)+);
+    };
+
+    (@hops $outer:ty; [$($hops:tt)*]; & $level:ty => $($rest:tt)+) => {
+        zoom_affine!(@hops $outer; [$($hops)* ($level, ViaGetRef)]; $($rest)+);
+    };
+
+    (@hops $outer:ty; [$($hops:tt)*]; $level:ty => $($rest:tt)+) => {
+        zoom_affine!(@hops $outer; [$($hops)* ($level, ViaGet)]; $($rest)+);
+    };
+
+    (@hops $outer:ty; [$($hops:tt)*]; ? $level:ty) => {
+        zoom_affine!(@impl $outer; $($hops)* ($level, ViaGetOption));
+    };
+
+    (@hops $outer:ty; [$($hops:tt)*]; & $level:ty) => {
+        zoom_affine!(@impl $outer; $($hops)* ($level, ViaGetRef));
+    };
+
+    (@hops $outer:ty; [$($hops:tt)*]; $level:ty) => {
+        zoom_affine!(@impl $outer; $($hops)* ($level, ViaGet));
+    };
+
+    (@impl $outer:ty; ($first:ty, $via1:ident) ($second:ty, $via2:ident)) => {
+        impl GetAffineSecond<$first, $second, $via1, $via2> for $outer {}
+        impl SetSecond<$first, $second> for $outer {}
+        impl ModifySecond<$first, $second> for $outer {}
+    };
+
+    (@impl $outer:ty; ($first:ty, $via1:ident) ($second:ty, $via2:ident) ($third:ty, $via3:ident)) => {
+        impl GetAffineThird<$first, $second, $third, $via1, $via2, $via3> for $outer {}
+        impl SetThird<$first, $second, $third> for $outer {}
+        impl ModifyThird<$first, $second, $third> for $outer {}
+    };
+
+    (@impl $outer:ty; ($first:ty, $via1:ident) ($second:ty, $via2:ident) ($third:ty, $via3:ident)
+        ($fourth:ty, $via4:ident)) => {
+        impl GetAffineFourth<$first, $second, $third, $fourth, $via1, $via2, $via3, $via4> for $outer {}
+        impl SetFourth<$first, $second, $third, $fourth> for $outer {}
+        impl ModifyFourth<$first, $second, $third, $fourth> for $outer {}
+    };
+
+    (@impl $outer:ty; ($first:ty, $via1:ident) ($second:ty, $via2:ident) ($third:ty, $via3:ident)
+        ($fourth:ty, $via4:ident) ($fifth:ty, $via5:ident)) => {
+        impl GetAffineFifth<$first, $second, $third, $fourth, $fifth, $via1, $via2, $via3, $via4, $via5>
+        for $outer {}
+        impl SetFifth<$first, $second, $third, $fourth, $fifth> for $outer {}
+        impl ModifyFifth<$first, $second, $third, $fourth, $fifth> for $outer {}
+    };
+
+    ($outer:ty => $($rest:tt)+) => {
+        zoom_affine!(@hops $outer; []; $($rest)+);
+    };
+}
+
+/// Auto-implements [`GetAll`] and [`ModifyAll`] for struct fields, so that they can take part in traversal [`composites`] as a level with exactly one target.
 ///
+/// The requirement is that the container should have an implementation of [`GetRef`] and [`Modify`] with the target type.
+///
+/// [`GetAll`]: focus/trait.GetAll.html
+/// [`ModifyAll`]: focus/trait.ModifyAll.html
+/// [`GetRef`]: focus/trait.GetRef.html
 /// [`Modify`]: focus/trait.Modify.html
-/// [`Set`]: focus/trait.Set.html
 /// [`composites`]: focus/composites/index.html
 ///
 /// # Examples
 ///```
 /// # use photonix::*;
-/// #[derive(GetOption, Set, Modify)]
-/// pub enum User {
-///     Admin,
-///     RegularUser(Employee),
+/// #[derive(GetRef, Modify)]
+/// pub struct Order { pub id: String, pub lines: Vec<Line> }
+///
+/// #[derive(GetRef, Modify)]
+/// pub struct Line { pub sku: String, pub price: u32 }
+///
+/// //  Container   Target     Container  Target
+/// //      |         |            |        |
+/// traverse![Order => Vec<Line>, Line => u32];
+///
+/// impl<'a> GetAllThird<'a, Vec<Line>, Line, u32> for Order {}
+/// impl ModifyAllThird<Vec<Line>, Line, u32> for Order {}
+///
+/// let order = Order {
+///     id: String::from("order-1"),
+///     lines: vec![
+///         Line { sku: String::from("apple"), price: 3 },
+///         Line { sku: String::from("pear"), price: 4 },
+///     ],
+/// };
+///
+/// let repriced = order.modify_all_third(|price| price + 1);
+///
+/// assert_eq!(vec![&4, &5], repriced.get_all_third().collect::<Vec<&u32>>());
+///
+///```
+#[macro_export]
+macro_rules! traverse {
+    ($($outer:ty => $inner:ty),+ $(,)?) => {
+        $(
+            impl GetAll<$inner> for $outer {
+                fn get_all(&self) -> Box<dyn Iterator<Item = &$inner> + '_> {
+                    Box::new(std::iter::once(GetRef::<$inner>::get_ref(self)))
+                }
+            }
+
+            impl ModifyAll<$inner> for $outer {
+                fn modify_all(self, f: impl FnMut($inner) -> $inner) -> Self {
+                    Modify::<$inner>::modify(self, f)
+                }
+            }
+        )+
+    };
+}
+
+/// Auto-implements [`Get`], [`Set`], [`Modify`], and [`ReverseGet`] for a container based on its [`Iso`] implementation.
+///
+/// [`Get`]: focus/trait.Get.html
+/// [`Set`]: focus/trait.Set.html
+/// [`Modify`]: focus/trait.Modify.html
+/// [`ReverseGet`]: focus/trait.ReverseGet.html
+/// [`Iso`]: focus/trait.Iso.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// #[derive(Debug, PartialEq)]
+/// pub struct Celsius(f64);
+///
+/// impl Iso<f64> for Celsius {
+///     fn to(self) -> f64 { self.0 }
+///     fn from(target: f64) -> Self { Celsius(target) }
 /// }
 ///
-/// #[derive(Get, Set, Modify)]
-/// pub struct Employee { pub name: String, pub company: Company }
+/// //  Container  Target
+/// //      |        |
+/// iso![Celsius => f64];
 ///
-/// #[derive(Get, Set, Modify)]
-/// pub struct Company { pub name: String, pub address: Address }
+/// assert_eq!(21.5, Celsius(21.5).get());
+/// assert_eq!(Celsius(0.0), Celsius(21.5).set(0.0));
+/// assert_eq!(Celsius(22.5), Celsius(21.5).modify(|t: f64| t + 1.0));
+/// assert_eq!(Celsius(-4.0), Celsius::reverse_get(-4.0));
 ///
-/// #[derive(Get, Set, Modify)]
-/// pub struct Address(String);
+///```
+#[macro_export]
+macro_rules! iso {
+    ($($source:ty => $target:ty),+ $(,)?) => {
+        $(
+            impl Get<$target> for $source {
+                fn get(self) -> $target {
+                    Iso::<$target>::to(self)
+                }
+            }
+
+            impl Set<$target> for $source {
+                fn set(self, new_value: $target) -> Self {
+                    <Self as Iso<$target>>::from(new_value)
+                }
+            }
+
+            impl Modify<$target> for $source {
+                fn modify(self, f: impl FnOnce($target) -> $target) -> Self {
+                    Iso::<$target>::over(self, f)
+                }
+            }
+
+            impl ReverseGet<$target> for $source {
+                fn reverse_get(value: $target) -> Self {
+                    <Self as Iso<$target>>::from(value)
+                }
+            }
+        )+
+    };
+}
+
+/// Generates a zero-sized tag type for each listed field, and implements [`GetField`], [`GetRefField`], [`GetMutField`], [`SetField`], and [`ModifyField`] for the container with it. Tags make it possible to target fields of the same type unambiguously, also through the tagged composites (e.g. [`GetRefFieldSecond`]), which need no impl blocks.
 ///
-/// let john_doe = || Employee {
-///     name: String::from("John Doe"),
-///     company: Company {
-///         name: String::from("Acme Corporation"),
-///         address: Address(String::from("4 Foo Road, Bar City")),
-///         }
-///     };
+/// The tags take the visibility written before the container (private by default). A generic container is introduced with an `impl<...>` header, optionally followed by a where-clause, like in [`fields!`]. The tags themselves are never generic.
 ///
-/// let john_as_user = || User::RegularUser(john_doe());
+/// Tagged fields have no [`zoom!`] counterpart, and need none: the tagged composites are implemented for every container whose levels have the tagged traits, so any chain of tags can be used right away.
 ///
-/// //  Parent type   Level 1   Level 2    Level 3    Level 4
-/// //      |           |          |         |         |
-/// zoom![User => Employee => Company => Address => String];
+/// [`GetField`]: focus/trait.GetField.html
+/// [`GetRefField`]: focus/trait.GetRefField.html
+/// [`GetMutField`]: focus/trait.GetMutField.html
+/// [`SetField`]: focus/trait.SetField.html
+/// [`ModifyField`]: focus/trait.ModifyField.html
+/// [`GetRefFieldSecond`]: focus/composites/trait.GetRefFieldSecond.html
+/// [`fields!`]: macro.fields.html
+/// [`zoom!`]: macro.zoom.html
 ///
-/// let john_at_new_address = john_as_user().modify_fourth(|s| s.replace("4", "5"));
+/// # Examples
+///```
+/// # use photonix::*;
+/// pub struct Route<T> { pub origin: City, pub destination: City, pub cargo: T }
 ///
-/// let maybe_john = john_at_new_address.get_option();
+/// pub struct City { pub name: String, pub country: String }
 ///
-/// assert_eq!(
-///     Some(String::from("5 Foo Road, Bar City")),
-///     maybe_john.map(|john| john.company.address.0)
-/// );
+/// //   Visibility  Container  Field  Type     Tag
+/// //       |           |        |     |        |
+/// tagged! {
+///     pub impl<T> Route<T> { origin: City => Origin, destination: City => Destination }
+///     pub City { name: String => Name, country: String => Country }
+/// }
 ///
-/// //  Parent type   Level 1   Level 2
-/// //      |           |        |
-/// zoom![User => Employee => String];
+/// let city = |name: &str| City { name: String::from(name), country: String::from("Hungary") };
+/// let route = Route { origin: city("Budapest"), destination: city("Szeged"), cargo: () };
 ///
-/// let frank_doe_as_user = john_as_user().set_second(String::from("Frank Doe"));
+/// assert_eq!("Szeged", route.get_ref_field_second(Destination, Name).as_str());
+///
+/// let route = route.modify_field_second(Origin, Country, |country| country.to_uppercase());
 ///
 /// assert_eq!(
-///     Some(String::from("Frank Doe")),
-///     frank_doe_as_user.get_option().map(|employee| employee.name)
+///     ("HUNGARY", "Hungary"),
+///     (route.origin.country.as_str(), route.destination.country.as_str())
 /// );
 ///
 ///```
-///
 #[macro_export]
-macro_rules! zoom {
-    ($outer:ty => $first:ty => $second:ty) => {
-        impl SetSecond<$first, $second> for $outer {}
-        impl ModifySecond<$first, $second> for $outer {}
+macro_rules! tagged {
+    (@tags [$vis:vis] [$($generics:tt)*] [$($where:tt)*] $container:ty, $field:ident: $value:ty => $tag:ident $(, $($rest:tt)*)?) => {
+        #[doc = concat!("Tag of the `", stringify!($field), "` field of `", stringify!($container), "`.")]
+        #[derive(Clone, Copy, Debug, Default)]
+        $vis struct $tag;
+
+        impl<$($generics)*> GetField<$tag, $value> for $container where $($where)* {
+            fn get_field(self, _tag: $tag) -> $value {
+                self.$field
+            }
+        }
+
+        impl<$($generics)*> GetRefField<$tag, $value> for $container where $($where)* {
+            fn get_ref_field(&self, _tag: $tag) -> &$value {
+                &self.$field
+            }
+        }
+
+        impl<$($generics)*> GetMutField<$tag, $value> for $container where $($where)* {
+            fn get_mut_field(&mut self, _tag: $tag) -> &mut $value {
+                &mut self.$field
+            }
+        }
+
+        impl<$($generics)*> SetField<$tag, $value> for $container where $($where)* {
+            fn set_field(mut self, _tag: $tag, new_value: $value) -> Self {
+                self.$field = new_value;
+                self
+            }
+        }
+
+        impl<$($generics)*> ModifyField<$tag, $value> for $container where $($where)* {
+            fn modify_field(mut self, _tag: $tag, f: impl FnOnce($value) -> $value) -> Self {
+                self.$field = f(self.$field);
+                self
+            }
+        }
+
+        tagged!(@tags [$vis] [$($generics)*] [$($where)*] $container, $($($rest)*)?);
     };
 
-    ($outer:ty => $first:ty => $second:ty => $third:ty) => {
-        impl SetThird<$first, $second, $third> for $outer {}
-        impl ModifyThird<$first, $second, $third> for $outer {}
+    (@tags [$vis:vis] [$($generics:tt)*] [$($where:tt)*] $container:ty, ) => {};
+
+    (@where [$vis:vis] [$($generics:tt)*] $container:ty [$($where:tt)*] { $($fields:tt)* } $($rest:tt)*) => {
+        tagged!(@tags [$vis] [$($generics)*] [$($where)*] $container, $($fields)*);
+        tagged!($($rest)*);
     };
 
-    ($outer:ty => $first:ty => $second:ty => $third:ty => $fourth:ty) => {
-        impl SetFourth<$first, $second, $third, $fourth> for $outer {}
-        impl ModifyFourth<$first, $second, $third, $fourth> for $outer {}
+    (@where [$vis:vis] [$($generics:tt)*] $container:ty [$($where:tt)*] $next:tt $($rest:tt)*) => {
+        tagged!(@where [$vis] [$($generics)*] $container [$($where)* $next] $($rest)*);
     };
 
-    ($outer:ty => $first:ty => $second:ty => $third:ty => $fourth:ty => $fifth:ty) => {
-        impl SetFifth<$first, $second, $third, $fourth, $fifth> for $outer {}
-        impl ModifyFifth<$first, $second, $third, $fourth, $fifth> for $outer {}
+    (@generics [$vis:vis] [$($generics:tt)*] $container:ty where $($rest:tt)+) => {
+        tagged!(@where [$vis] [$($generics)*] $container [] $($rest)+);
+    };
+
+    (@generics [$vis:vis] [$($generics:tt)*] $container:ty { $($fields:tt)* } $($rest:tt)*) => {
+        tagged!(@tags [$vis] [$($generics)*] [] $container, $($fields)*);
+        tagged!($($rest)*);
+    };
+
+    () => {};
+
+    ($vis:vis impl< $($rest:tt)+) => {
+        split_generics!(tagged [[$vis]] [] [] $($rest)+);
+    };
+
+    ($vis:vis $container:ty { $($fields:tt)* } $($rest:tt)*) => {
+        tagged!(@tags [$vis] [] [] $container, $($fields)*);
+        tagged!($($rest)*);
     };
 }
@@ -1,4 +1,368 @@
-use crate::*;
+use crate::{*, nth::*};
+use std::{
+    borrow::{Borrow, Cow},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+    hash::{BuildHasher, Hash},
+    rc::Rc,
+    sync::Arc,
+};
+
+impl<Container, Value> SetInPlace<Value> for Container
+    where Container: GetMut<Value> {
+    fn set_in_place(&mut self, new_value: Value) {
+        *self.get_mut() = new_value;
+    }
+}
+
+impl<Container, Value> ModifyInPlace<Value> for Container
+    where Container: GetMut<Value> {
+    fn modify_in_place(&mut self, f: impl FnOnce(&mut Value)) {
+        f(self.get_mut())
+    }
+}
+
+impl<Container, Value> GetAffine<Value, ViaGet> for Container
+    where Container: Get<Value> {
+    fn get_affine(self) -> Option<Value> {
+        Some(self.get())
+    }
+}
+
+impl<Container, Value> GetAffine<Value, ViaGetRef> for Container
+    where Container: GetRef<Value>,
+          Value: Clone {
+    fn get_affine(self) -> Option<Value> {
+        Some(self.get_ref().clone())
+    }
+}
+
+impl<Container, Value> GetAffine<Value, ViaGetOption> for Container
+    where Container: GetOption<Value> {
+    fn get_affine(self) -> Option<Value> {
+        self.get_option()
+    }
+}
+
+impl<Container, Head> GetPath<Path<Head, End>> for Container
+    where Container: Get<Head> {
+    type Target = Head;
+
+    fn get_path(self) -> Head {
+        self.get()
+    }
+}
+
+impl<Container, Head, Next, Tail> GetPath<Path<Head, Path<Next, Tail>>> for Container
+    where Container: Get<Head>,
+          Head: GetPath<Path<Next, Tail>> {
+    type Target = Head::Target;
+
+    fn get_path(self) -> Self::Target {
+        self.get().get_path()
+    }
+}
+
+impl<'a, Container, Head> GetRefPath<'a, Path<Head, End>> for Container
+    where Container: GetRef<Head> + 'a,
+          Head: 'a {
+    type Target = Head;
+
+    fn get_ref_path(&'a self) -> &'a Head {
+        self.get_ref()
+    }
+}
+
+impl<'a, Container, Head, Next, Tail> GetRefPath<'a, Path<Head, Path<Next, Tail>>> for Container
+    where Container: GetRef<Head> + 'a,
+          Head: GetRefPath<'a, Path<Next, Tail>> + 'a {
+    type Target = Head::Target;
+
+    fn get_ref_path(&'a self) -> &'a Self::Target {
+        self.get_ref().get_ref_path()
+    }
+}
+
+impl<'a, Container, Head> GetMutPath<'a, Path<Head, End>> for Container
+    where Container: GetMut<Head> + 'a,
+          Head: 'a {
+    type Target = Head;
+
+    fn get_mut_path(&'a mut self) -> &'a mut Head {
+        self.get_mut()
+    }
+}
+
+impl<'a, Container, Head, Next, Tail> GetMutPath<'a, Path<Head, Path<Next, Tail>>> for Container
+    where Container: GetMut<Head> + 'a,
+          Head: GetMutPath<'a, Path<Next, Tail>> + 'a {
+    type Target = Head::Target;
+
+    fn get_mut_path(&'a mut self) -> &'a mut Self::Target {
+        self.get_mut().get_mut_path()
+    }
+}
+
+impl<Container, Head> SetPath<Path<Head, End>> for Container
+    where Container: Set<Head> {
+    type Target = Head;
+
+    fn set_path(self, new_value: Head) -> Self {
+        self.set(new_value)
+    }
+}
+
+impl<Container, Head, Next, Tail> SetPath<Path<Head, Path<Next, Tail>>> for Container
+    where Container: Modify<Head>,
+          Head: SetPath<Path<Next, Tail>> {
+    type Target = Head::Target;
+
+    fn set_path(self, new_value: Self::Target) -> Self {
+        self.modify(|head| head.set_path(new_value))
+    }
+}
+
+impl<Container, Head> ModifyPath<Path<Head, End>> for Container
+    where Container: Modify<Head> {
+    type Target = Head;
+
+    fn modify_path(self, f: impl FnOnce(Head) -> Head) -> Self {
+        self.modify(f)
+    }
+}
+
+impl<Container, Head, Next, Tail> ModifyPath<Path<Head, Path<Next, Tail>>> for Container
+    where Container: Modify<Head>,
+          Head: ModifyPath<Path<Next, Tail>> {
+    type Target = Head::Target;
+
+    fn modify_path(self, f: impl FnOnce(Self::Target) -> Self::Target) -> Self {
+        self.modify(|head| head.modify_path(f))
+    }
+}
+
+impl<Suffix> Append<Suffix> for End {
+    type Output = Suffix;
+}
+
+impl<Head, Tail, Suffix> Append<Suffix> for Path<Head, Tail>
+    where Tail: Append<Suffix> {
+    type Output = Path<Head, Tail::Output>;
+}
+
+impl<Container, Head> GetOptionPath<Path<Head, End>> for Container
+    where Container: GetOption<Head> {
+    type Target = Head;
+
+    fn get_option_path(self) -> Option<Head> {
+        self.get_option()
+    }
+}
+
+impl<Container, Head, Next, Tail> GetOptionPath<Path<Head, Path<Next, Tail>>> for Container
+    where Container: GetOption<Head>,
+          Head: GetOptionPath<Path<Next, Tail>> {
+    type Target = Head::Target;
+
+    fn get_option_path(self) -> Option<Self::Target> {
+        self.get_option().and_then(|head| head.get_option_path())
+    }
+}
+
+impl<Container, Head> ReverseGetPath<Path<Head, End>> for Container
+    where Container: ReverseGet<Head> {
+    type Target = Head;
+
+    fn reverse_get_path(value: Head) -> Self {
+        Container::reverse_get(value)
+    }
+}
+
+impl<Container, Head, Next, Tail> ReverseGetPath<Path<Head, Path<Next, Tail>>> for Container
+    where Container: ReverseGet<Head>,
+          Head: ReverseGetPath<Path<Next, Tail>> {
+    type Target = Head::Target;
+
+    fn reverse_get_path(value: Self::Target) -> Self {
+        Container::reverse_get(Head::reverse_get_path(value))
+    }
+}
+
+impl<Container, Head> SetOptionPath<Path<Head, End>> for Container
+    where Container: SetOption<Head> {
+    type Target = Head;
+
+    fn set_option_path(self, new_value: Head) -> Option<Self> {
+        self.set_option(new_value)
+    }
+}
+
+impl<Container, Head, Next, Tail> SetOptionPath<Path<Head, Path<Next, Tail>>> for Container
+    where Container: GetOption<Head> + ReverseGet<Head>,
+          Head: SetOptionPath<Path<Next, Tail>> {
+    type Target = Head::Target;
+
+    fn set_option_path(self, new_value: Self::Target) -> Option<Self> {
+        self.get_option()
+            .and_then(|head| head.set_option_path(new_value))
+            .map(<Container as ReverseGet<Head>>::reverse_get)
+    }
+}
+
+impl<Container, Head> ModifyOptionPath<Path<Head, End>> for Container
+    where Container: ModifyOption<Head> {
+    type Target = Head;
+
+    fn modify_option_path(self, f: impl FnOnce(Head) -> Head) -> Option<Self> {
+        self.modify_option(f)
+    }
+}
+
+impl<Container, Head, Next, Tail> ModifyOptionPath<Path<Head, Path<Next, Tail>>> for Container
+    where Container: GetOption<Head> + ReverseGet<Head>,
+          Head: ModifyOptionPath<Path<Next, Tail>> {
+    type Target = Head::Target;
+
+    fn modify_option_path(self, f: impl FnOnce(Self::Target) -> Self::Target) -> Option<Self> {
+        self.get_option()
+            .and_then(|head| head.modify_option_path(f))
+            .map(<Container as ReverseGet<Head>>::reverse_get)
+    }
+}
+
+impl<'a, Container, Head> GetAllPath<'a, Path<Head, End>> for Container
+    where Container: GetAll<Head> + 'a,
+          Head: 'a {
+    type Target = Head;
+
+    fn get_all_path(&'a self) -> Box<dyn Iterator<Item = &'a Head> + 'a> {
+        self.get_all()
+    }
+}
+
+impl<'a, Container, Head, Next, Tail> GetAllPath<'a, Path<Head, Path<Next, Tail>>> for Container
+    where Container: GetAll<Head> + 'a,
+          Head: GetAllPath<'a, Path<Next, Tail>> + 'a {
+    type Target = Head::Target;
+
+    fn get_all_path(&'a self) -> Box<dyn Iterator<Item = &'a Self::Target> + 'a> {
+        Box::new(self.get_all().flat_map(|head| head.get_all_path()))
+    }
+}
+
+impl<Container, Head> ModifyAllPath<Path<Head, End>> for Container
+    where Container: ModifyAll<Head> {
+    type Target = Head;
+
+    fn modify_all_path(self, f: impl FnMut(Head) -> Head) -> Self {
+        self.modify_all(f)
+    }
+}
+
+impl<Container, Head, Next, Tail> ModifyAllPath<Path<Head, Path<Next, Tail>>> for Container
+    where Container: ModifyAll<Head>,
+          Head: ModifyAllPath<Path<Next, Tail>> {
+    type Target = Head::Target;
+
+    fn modify_all_path(self, mut f: impl FnMut(Self::Target) -> Self::Target) -> Self {
+        self.modify_all(|head| head.modify_all_path(&mut f))
+    }
+}
+
+impl<Container> AtPath for Container {}
+
+macro_rules! update_composites {
+    ([$($generics:tt),*] $container:ty $(, $($bounds:tt)+)?) => {
+        impl<$($generics,)* LevelOne, LevelTwo>
+        SetSecond<LevelOne, LevelTwo>
+        for $container
+            where LevelOne: Set<LevelTwo>,
+                  $($($bounds)+)? {}
+
+        impl<$($generics,)* LevelOne, LevelTwo, LevelThree>
+        SetThird<LevelOne, LevelTwo, LevelThree>
+        for $container
+            where LevelOne: Modify<LevelTwo>,
+                  LevelTwo: Set<LevelThree>,
+                  $($($bounds)+)? {}
+
+        impl<$($generics,)* LevelOne, LevelTwo, LevelThree, LevelFour>
+        SetFourth<LevelOne, LevelTwo, LevelThree, LevelFour>
+        for $container
+            where LevelOne: Modify<LevelTwo>,
+                  LevelTwo: Modify<LevelThree>,
+                  LevelThree: Set<LevelFour>,
+                  $($($bounds)+)? {}
+
+        impl<$($generics,)* LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        SetFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        for $container
+            where LevelOne: Modify<LevelTwo>,
+                  LevelTwo: Modify<LevelThree>,
+                  LevelThree: Modify<LevelFour>,
+                  LevelFour: Set<LevelFive>,
+                  $($($bounds)+)? {}
+
+        impl<$($generics,)* LevelOne, LevelTwo>
+        ModifySecond<LevelOne, LevelTwo>
+        for $container
+            where LevelOne: Modify<LevelTwo>,
+                  $($($bounds)+)? {}
+
+        impl<$($generics,)* LevelOne, LevelTwo, LevelThree>
+        ModifyThird<LevelOne, LevelTwo, LevelThree>
+        for $container
+            where LevelOne: Modify<LevelTwo>,
+                  LevelTwo: Modify<LevelThree>,
+                  $($($bounds)+)? {}
+
+        impl<$($generics,)* LevelOne, LevelTwo, LevelThree, LevelFour>
+        ModifyFourth<LevelOne, LevelTwo, LevelThree, LevelFour>
+        for $container
+            where LevelOne: Modify<LevelTwo>,
+                  LevelTwo: Modify<LevelThree>,
+                  LevelThree: Modify<LevelFour>,
+                  $($($bounds)+)? {}
+
+        impl<$($generics,)* LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        ModifyFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        for $container
+            where LevelOne: Modify<LevelTwo>,
+                  LevelTwo: Modify<LevelThree>,
+                  LevelThree: Modify<LevelFour>,
+                  LevelFour: Modify<LevelFive>,
+                  $($($bounds)+)? {}
+
+        impl<$($generics,)* LevelOne, LevelTwo>
+        TryModifySecond<LevelOne, LevelTwo>
+        for $container
+            where LevelOne: TryModify<LevelTwo>,
+                  $($($bounds)+)? {}
+
+        impl<$($generics,)* LevelOne, LevelTwo, LevelThree>
+        TryModifyThird<LevelOne, LevelTwo, LevelThree>
+        for $container
+            where LevelOne: TryModify<LevelTwo>,
+                  LevelTwo: TryModify<LevelThree>,
+                  $($($bounds)+)? {}
+
+        impl<$($generics,)* LevelOne, LevelTwo, LevelThree, LevelFour>
+        TryModifyFourth<LevelOne, LevelTwo, LevelThree, LevelFour>
+        for $container
+            where LevelOne: TryModify<LevelTwo>,
+                  LevelTwo: TryModify<LevelThree>,
+                  LevelThree: TryModify<LevelFour>,
+                  $($($bounds)+)? {}
+
+        impl<$($generics,)* LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        TryModifyFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        for $container
+            where LevelOne: TryModify<LevelTwo>,
+                  LevelTwo: TryModify<LevelThree>,
+                  LevelThree: TryModify<LevelFour>,
+                  LevelFour: TryModify<LevelFive>,
+                  $($($bounds)+)? {}
+    };
+}
 
 impl<T> Set<T> for Option<T> {
     fn set(self, new_value: T) -> Self {
@@ -12,56 +376,1066 @@ impl<T> Modify<T> for Option<T> {
     }
 }
 
-impl<LevelOne, LevelTwo>
-SetSecond<LevelOne, LevelTwo>
-for Option<LevelOne>
-    where LevelOne: Set<LevelTwo> {}
+impl<T> GetOption<T> for Option<T> {
+    fn get_option(self) -> Option<T> {
+        self
+    }
+}
+
+impl<T> GetRefOption<T> for Option<T> {
+    fn get_ref_option(&self) -> Option<&T> {
+        self.as_ref()
+    }
+}
+
+impl<T> GetMutOption<T> for Option<T> {
+    fn get_mut_option(&mut self) -> Option<&mut T> {
+        self.as_mut()
+    }
+}
+
+impl<T> SetOption<T> for Option<T> {
+    fn set_option(self, new_value: T) -> Option<Self> {
+        self.map(|_| Some(new_value))
+    }
+}
+
+impl<T> ModifyOption<T> for Option<T> {
+    fn modify_option(self, f: impl FnOnce(T) -> T) -> Option<Self> {
+        self.map(|value| Some(f(value)))
+    }
+}
+
+impl<T> ReverseGet<T> for Option<T> {
+    fn reverse_get(value: T) -> Self {
+        Some(value)
+    }
+}
 
-impl<LevelOne, LevelTwo, LevelThree>
-SetThird<LevelOne, LevelTwo, LevelThree>
-for Option<LevelOne>
-    where LevelOne: Modify<LevelTwo>,
+impl<T> TryModify<T> for Option<T> {
+    fn try_modify<E>(self, f: impl FnOnce(T) -> Result<T, E>) -> Result<Self, E> {
+        self.map(f).transpose()
+    }
+}
+
+update_composites!([] Option<LevelOne>);
+
+impl<T, E> Set<T> for Result<T, E> {
+    fn set(self, new_value: T) -> Self {
+        self.map(|_| new_value)
+    }
+}
+
+impl<T, E> Modify<T> for Result<T, E> {
+    fn modify(self, f: impl FnOnce(T) -> T) -> Self {
+        self.map(f)
+    }
+}
+
+impl<T, E> GetOption<T> for Result<T, E> {
+    fn get_option(self) -> Option<T> {
+        self.ok()
+    }
+}
+
+impl<T, E> GetRefOption<T> for Result<T, E> {
+    fn get_ref_option(&self) -> Option<&T> {
+        self.as_ref().ok()
+    }
+}
+
+impl<T, E> GetMutOption<T> for Result<T, E> {
+    fn get_mut_option(&mut self) -> Option<&mut T> {
+        self.as_mut().ok()
+    }
+}
+
+impl<T, E> SetOption<T> for Result<T, E> {
+    fn set_option(self, new_value: T) -> Option<Self> {
+        self.ok().map(|_| Ok(new_value))
+    }
+}
+
+impl<T, E> ModifyOption<T> for Result<T, E> {
+    fn modify_option(self, f: impl FnOnce(T) -> T) -> Option<Self> {
+        self.ok().map(|value| Ok(f(value)))
+    }
+}
+
+impl<T, E> ReverseGet<T> for Result<T, E> {
+    fn reverse_get(value: T) -> Self {
+        Ok(value)
+    }
+}
+
+impl<T, E> TryModify<T> for Result<T, E> {
+    fn try_modify<F>(self, f: impl FnOnce(T) -> Result<T, F>) -> Result<Self, F> {
+        match self {
+            Ok(value) => f(value).map(Ok),
+            Err(error) => Ok(Err(error)),
+        }
+    }
+}
+
+impl<T, E> SetErr<E> for Result<T, E> {
+    fn set_err(self, new_value: E) -> Self {
+        self.map_err(|_| new_value)
+    }
+}
+
+impl<T, E> ModifyErr<E> for Result<T, E> {
+    fn modify_err(self, f: impl FnOnce(E) -> E) -> Self {
+        self.map_err(f)
+    }
+}
+
+impl<T, E> GetOptionErr<E> for Result<T, E> {
+    fn get_option_err(self) -> Option<E> {
+        self.err()
+    }
+}
+
+impl<T, E> ReverseGetErr<E> for Result<T, E> {
+    fn reverse_get_err(value: E) -> Self {
+        Err(value)
+    }
+}
+
+update_composites!([E] Result<LevelOne, E>);
+
+impl<T> Get<T> for Box<T> {
+    fn get(self) -> T {
+        *self
+    }
+}
+
+impl<T> GetRef<T> for Box<T> {
+    fn get_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<T> GetMut<T> for Box<T> {
+    fn get_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+impl<T> Set<T> for Box<T> {
+    fn set(mut self, new_value: T) -> Self {
+        *self = new_value;
+        self
+    }
+}
+
+impl<T> Modify<T> for Box<T> {
+    fn modify(mut self, f: impl FnOnce(T) -> T) -> Self {
+        *self = f(*self);
+        self
+    }
+}
+
+impl<T> TryModify<T> for Box<T> {
+    fn try_modify<E>(mut self, f: impl FnOnce(T) -> Result<T, E>) -> Result<Self, E> {
+        *self = f(*self)?;
+        Ok(self)
+    }
+}
+
+update_composites!([] Box<LevelOne>);
+
+macro_rules! shared_pointer_instances {
+    ($pointer:ident) => {
+        impl<T> Get<T> for $pointer<T>
+            where T: Clone {
+            fn get(self) -> T {
+                $pointer::try_unwrap(self).unwrap_or_else(|shared| (*shared).clone())
+            }
+        }
+
+        impl<T> GetRef<T> for $pointer<T> {
+            fn get_ref(&self) -> &T {
+                self
+            }
+        }
+
+        impl<T> GetMut<T> for $pointer<T>
+            where T: Clone {
+            fn get_mut(&mut self) -> &mut T {
+                $pointer::make_mut(self)
+            }
+        }
+
+        // Unique pointers are updated in place, shared ones are detached first (clone-on-write).
+        impl<T> Set<T> for $pointer<T> {
+            fn set(mut self, new_value: T) -> Self {
+                match $pointer::get_mut(&mut self) {
+                    Some(value) => {
+                        *value = new_value;
+                        self
+                    }
+                    None => $pointer::new(new_value),
+                }
+            }
+        }
+
+        // The function takes the target by value, so the value has to be moved out of the pointer:
+        // make_mut detaches a shared pointer (the only clone), then the unique value is unwrapped without cloning.
+        // ModifyInPlace (via GetMut) updates the value in the existing allocation instead.
+        impl<T> Modify<T> for $pointer<T>
+            where T: Clone {
+            fn modify(mut self, f: impl FnOnce(T) -> T) -> Self {
+                $pointer::make_mut(&mut self);
+                $pointer::new(f(self.get()))
+            }
+        }
+
+        impl<T> TryModify<T> for $pointer<T>
+            where T: Clone {
+            fn try_modify<E>(mut self, f: impl FnOnce(T) -> Result<T, E>) -> Result<Self, E> {
+                $pointer::make_mut(&mut self);
+                f(self.get()).map($pointer::new)
+            }
+        }
+
+        update_composites!([] $pointer<LevelOne>, LevelOne: Clone);
+    };
+}
+
+shared_pointer_instances!(Rc);
+shared_pointer_instances!(Arc);
+
+impl<'a, B> Get<B::Owned> for Cow<'a, B>
+    where B: ToOwned + ?Sized {
+    fn get(self) -> B::Owned {
+        self.into_owned()
+    }
+}
+
+// Only for sized targets: GetRef<str> for Cow<str> can't be expressed, since the trait's type parameter is sized.
+impl<'a, B> GetRef<B> for Cow<'a, B>
+    where B: ToOwned {
+    fn get_ref(&self) -> &B {
+        self
+    }
+}
+
+impl<'a, B> GetMut<B::Owned> for Cow<'a, B>
+    where B: ToOwned + ?Sized {
+    fn get_mut(&mut self) -> &mut B::Owned {
+        self.to_mut()
+    }
+}
+
+impl<'a, B> Set<B::Owned> for Cow<'a, B>
+    where B: ToOwned + ?Sized {
+    fn set(self, new_value: B::Owned) -> Self {
+        Cow::Owned(new_value)
+    }
+}
+
+impl<'a, B> Modify<B::Owned> for Cow<'a, B>
+    where B: ToOwned + ?Sized {
+    fn modify(self, f: impl FnOnce(B::Owned) -> B::Owned) -> Self {
+        Cow::Owned(f(self.into_owned()))
+    }
+}
+
+impl<'a, B> TryModify<B::Owned> for Cow<'a, B>
+    where B: ToOwned + ?Sized {
+    fn try_modify<E>(self, f: impl FnOnce(B::Owned) -> Result<B::Owned, E>) -> Result<Self, E> {
+        f(self.into_owned()).map(Cow::Owned)
+    }
+}
+
+update_composites!(['a, B] Cow<'a, B>, B: ToOwned<Owned = LevelOne> + ?Sized);
+
+impl<Container, Value> Fold<Value> for Container
+    where Container: GetAll<Value> {}
+
+impl<T> GetAll<T> for Option<T> {
+    fn get_all(&self) -> Box<dyn Iterator<Item = &T> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<T> ModifyAll<T> for Option<T> {
+    fn modify_all(self, f: impl FnMut(T) -> T) -> Self {
+        self.map(f)
+    }
+}
+
+impl<T, E> GetAll<T> for Result<T, E> {
+    fn get_all(&self) -> Box<dyn Iterator<Item = &T> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<T, E> ModifyAll<T> for Result<T, E> {
+    fn modify_all(self, f: impl FnMut(T) -> T) -> Self {
+        self.map(f)
+    }
+}
+
+impl<T> GetAll<T> for Vec<T> {
+    fn get_all(&self) -> Box<dyn Iterator<Item = &T> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<T> ModifyAll<T> for Vec<T> {
+    fn modify_all(self, f: impl FnMut(T) -> T) -> Self {
+        self.into_iter().map(f).collect()
+    }
+}
+
+impl<T, const N: usize> GetAll<T> for [T; N] {
+    fn get_all(&self) -> Box<dyn Iterator<Item = &T> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<T, const N: usize> ModifyAll<T> for [T; N] {
+    fn modify_all(self, f: impl FnMut(T) -> T) -> Self {
+        self.map(f)
+    }
+}
+
+impl<K, V, S> GetAll<V> for HashMap<K, V, S> {
+    fn get_all(&self) -> Box<dyn Iterator<Item = &V> + '_> {
+        Box::new(self.values())
+    }
+}
+
+impl<K, V, S> ModifyAll<V> for HashMap<K, V, S>
+    where K: Eq + Hash,
+          S: BuildHasher + Default {
+    fn modify_all(self, mut f: impl FnMut(V) -> V) -> Self {
+        self.into_iter().map(|(key, value)| (key, f(value))).collect()
+    }
+}
+
+macro_rules! traversal_composites {
+    ([$($generics:tt)*] $container:ty $(, $($bounds:tt)+)?) => {
+        impl<'a, LevelOne, LevelTwo, $($generics)*>
+        GetAllSecond<'a, LevelOne, LevelTwo>
+        for $container
+            where LevelOne: GetAll<LevelTwo> + 'a,
+                  $container: 'a,
+                  $($($bounds)+)? {}
+
+        impl<'a, LevelOne, LevelTwo, LevelThree, $($generics)*>
+        GetAllThird<'a, LevelOne, LevelTwo, LevelThree>
+        for $container
+            where LevelOne: GetAll<LevelTwo> + 'a,
+                  LevelTwo: GetAll<LevelThree> + 'a,
+                  $container: 'a,
+                  $($($bounds)+)? {}
+
+        impl<'a, LevelOne, LevelTwo, LevelThree, LevelFour, $($generics)*>
+        GetAllFourth<'a, LevelOne, LevelTwo, LevelThree, LevelFour>
+        for $container
+            where LevelOne: GetAll<LevelTwo> + 'a,
+                  LevelTwo: GetAll<LevelThree> + 'a,
+                  LevelThree: GetAll<LevelFour> + 'a,
+                  $container: 'a,
+                  $($($bounds)+)? {}
+
+        impl<'a, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive, $($generics)*>
+        GetAllFifth<'a, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        for $container
+            where LevelOne: GetAll<LevelTwo> + 'a,
+                  LevelTwo: GetAll<LevelThree> + 'a,
+                  LevelThree: GetAll<LevelFour> + 'a,
+                  LevelFour: GetAll<LevelFive> + 'a,
+                  $container: 'a,
+                  $($($bounds)+)? {}
+
+        impl<LevelOne, LevelTwo, $($generics)*>
+        ModifyAllSecond<LevelOne, LevelTwo>
+        for $container
+            where LevelOne: ModifyAll<LevelTwo>,
+                  $($($bounds)+)? {}
+
+        impl<LevelOne, LevelTwo, LevelThree, $($generics)*>
+        ModifyAllThird<LevelOne, LevelTwo, LevelThree>
+        for $container
+            where LevelOne: ModifyAll<LevelTwo>,
+                  LevelTwo: ModifyAll<LevelThree>,
+                  $($($bounds)+)? {}
+
+        impl<LevelOne, LevelTwo, LevelThree, LevelFour, $($generics)*>
+        ModifyAllFourth<LevelOne, LevelTwo, LevelThree, LevelFour>
+        for $container
+            where LevelOne: ModifyAll<LevelTwo>,
+                  LevelTwo: ModifyAll<LevelThree>,
+                  LevelThree: ModifyAll<LevelFour>,
+                  $($($bounds)+)? {}
+
+        impl<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive, $($generics)*>
+        ModifyAllFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        for $container
+            where LevelOne: ModifyAll<LevelTwo>,
+                  LevelTwo: ModifyAll<LevelThree>,
+                  LevelThree: ModifyAll<LevelFour>,
+                  LevelFour: ModifyAll<LevelFive>,
+                  $($($bounds)+)? {}
+    };
+}
+
+traversal_composites!([] Option<LevelOne>);
+traversal_composites!([E] Result<LevelOne, E>);
+traversal_composites!([] Vec<LevelOne>);
+traversal_composites!([const N: usize] [LevelOne; N]);
+traversal_composites!([K, S] HashMap<K, LevelOne, S>, K: Eq + Hash, S: BuildHasher + Default);
+
+impl<T> GetIndex<T> for [T] {
+    fn get_index(&self, index: usize) -> Option<&T> {
+        <[T]>::get(self, index)
+    }
+}
+
+impl<T> GetIndex<T> for Vec<T> {
+    fn get_index(&self, index: usize) -> Option<&T> {
+        <[T]>::get(self, index)
+    }
+}
+
+impl<T, const N: usize> GetIndex<T> for [T; N] {
+    fn get_index(&self, index: usize) -> Option<&T> {
+        <[T]>::get(self, index)
+    }
+}
+
+impl<T> GetIndex<T> for Box<[T]> {
+    fn get_index(&self, index: usize) -> Option<&T> {
+        <[T]>::get(self, index)
+    }
+}
+
+impl<T> GetIndex<T> for VecDeque<T> {
+    fn get_index(&self, index: usize) -> Option<&T> {
+        VecDeque::get(self, index)
+    }
+}
+
+impl<T> SetIndex<T> for Vec<T> {
+    fn set_index(mut self, index: usize, new_value: T) -> Option<Self> {
+        *<[T]>::get_mut(&mut self, index)? = new_value;
+        Some(self)
+    }
+}
+
+impl<T, const N: usize> SetIndex<T> for [T; N] {
+    fn set_index(mut self, index: usize, new_value: T) -> Option<Self> {
+        *<[T]>::get_mut(&mut self, index)? = new_value;
+        Some(self)
+    }
+}
+
+impl<T> SetIndex<T> for Box<[T]> {
+    fn set_index(mut self, index: usize, new_value: T) -> Option<Self> {
+        *<[T]>::get_mut(&mut self, index)? = new_value;
+        Some(self)
+    }
+}
+
+impl<T> SetIndex<T> for VecDeque<T> {
+    fn set_index(mut self, index: usize, new_value: T) -> Option<Self> {
+        *VecDeque::get_mut(&mut self, index)? = new_value;
+        Some(self)
+    }
+}
+
+impl<T> ModifyIndex<T> for Vec<T> {
+    fn modify_index(mut self, index: usize, f: impl FnOnce(T) -> T) -> Option<Self> {
+        if index >= self.len() {
+            return None;
+        }
+        let value = self.swap_remove(index);
+        self.push(f(value));
+        let last = self.len() - 1;
+        self.swap(index, last);
+        Some(self)
+    }
+}
+
+impl<T, const N: usize> ModifyIndex<T> for [T; N] {
+    fn modify_index(self, index: usize, f: impl FnOnce(T) -> T) -> Option<Self> {
+        if index >= N {
+            return None;
+        }
+        let mut f = Some(f);
+        let mut position = 0;
+        Some(self.map(|value| {
+            let value = match f.take() {
+                Some(f) if position == index => f(value),
+                other => {
+                    f = other;
+                    value
+                }
+            };
+            position += 1;
+            value
+        }))
+    }
+}
+
+impl<T> ModifyIndex<T> for Box<[T]> {
+    fn modify_index(self, index: usize, f: impl FnOnce(T) -> T) -> Option<Self> {
+        self.into_vec().modify_index(index, f).map(Vec::into_boxed_slice)
+    }
+}
+
+impl<T> ModifyIndex<T> for VecDeque<T> {
+    fn modify_index(mut self, index: usize, f: impl FnOnce(T) -> T) -> Option<Self> {
+        let value = self.swap_remove_back(index)?;
+        self.push_back(f(value));
+        let last = self.len() - 1;
+        self.swap(index, last);
+        Some(self)
+    }
+}
+
+macro_rules! index_composites {
+    ([$($generics:tt)*] $container:ty) => {
+        impl<'a, LevelOne, LevelTwo, $($generics)*>
+        GetIndexSecond<'a, LevelOne, LevelTwo>
+        for $container
+            where LevelOne: GetRef<LevelTwo> + 'a {}
+
+        impl<'a, LevelOne, LevelTwo, LevelThree, $($generics)*>
+        GetIndexThird<'a, LevelOne, LevelTwo, LevelThree>
+        for $container
+            where LevelOne: GetRef<LevelTwo> + 'a,
+                  LevelTwo: GetRef<LevelThree> + 'a {}
+
+        impl<'a, LevelOne, LevelTwo, LevelThree, LevelFour, $($generics)*>
+        GetIndexFourth<'a, LevelOne, LevelTwo, LevelThree, LevelFour>
+        for $container
+            where LevelOne: GetRef<LevelTwo> + 'a,
+                  LevelTwo: GetRef<LevelThree> + 'a,
+                  LevelThree: GetRef<LevelFour> + 'a {}
+
+        impl<'a, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive, $($generics)*>
+        GetIndexFifth<'a, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        for $container
+            where LevelOne: GetRef<LevelTwo> + 'a,
+                  LevelTwo: GetRef<LevelThree> + 'a,
+                  LevelThree: GetRef<LevelFour> + 'a,
+                  LevelFour: GetRef<LevelFive> + 'a {}
+
+        impl<LevelOne, LevelTwo, $($generics)*>
+        SetIndexSecond<LevelOne, LevelTwo>
+        for $container
+            where LevelOne: Set<LevelTwo> {}
+
+        impl<LevelOne, LevelTwo, LevelThree, $($generics)*>
+        SetIndexThird<LevelOne, LevelTwo, LevelThree>
+        for $container
+            where LevelOne: Modify<LevelTwo>,
+                  LevelTwo: Set<LevelThree> {}
+
+        impl<LevelOne, LevelTwo, LevelThree, LevelFour, $($generics)*>
+        SetIndexFourth<LevelOne, LevelTwo, LevelThree, LevelFour>
+        for $container
+            where LevelOne: Modify<LevelTwo>,
+                  LevelTwo: Modify<LevelThree>,
+                  LevelThree: Set<LevelFour> {}
+
+        impl<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive, $($generics)*>
+        SetIndexFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        for $container
+            where LevelOne: Modify<LevelTwo>,
+                  LevelTwo: Modify<LevelThree>,
+                  LevelThree: Modify<LevelFour>,
+                  LevelFour: Set<LevelFive> {}
+
+        impl<LevelOne, LevelTwo, $($generics)*>
+        ModifyIndexSecond<LevelOne, LevelTwo>
+        for $container
+            where LevelOne: Modify<LevelTwo> {}
+
+        impl<LevelOne, LevelTwo, LevelThree, $($generics)*>
+        ModifyIndexThird<LevelOne, LevelTwo, LevelThree>
+        for $container
+            where LevelOne: Modify<LevelTwo>,
+                  LevelTwo: Modify<LevelThree> {}
+
+        impl<LevelOne, LevelTwo, LevelThree, LevelFour, $($generics)*>
+        ModifyIndexFourth<LevelOne, LevelTwo, LevelThree, LevelFour>
+        for $container
+            where LevelOne: Modify<LevelTwo>,
+                  LevelTwo: Modify<LevelThree>,
+                  LevelThree: Modify<LevelFour> {}
+
+        impl<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive, $($generics)*>
+        ModifyIndexFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
+        for $container
+            where LevelOne: Modify<LevelTwo>,
+                  LevelTwo: Modify<LevelThree>,
+                  LevelThree: Modify<LevelFour>,
+                  LevelFour: Modify<LevelFive> {}
+    };
+}
+
+index_composites!([] Vec<LevelOne>);
+index_composites!([] VecDeque<LevelOne>);
+index_composites!([const N: usize] [LevelOne; N]);
+index_composites!([] Box<[LevelOne]>);
+
+impl<K, Q, V, S> GetKey<Q, V> for HashMap<K, V, S>
+    where K: Borrow<Q> + Eq + Hash,
+          Q: Eq + Hash + ?Sized,
+          S: BuildHasher {
+    fn get_key(&self, key: &Q) -> Option<&V> {
+        self.get(key)
+    }
+}
+
+impl<K, Q, V> GetKey<Q, V> for BTreeMap<K, V>
+    where K: Borrow<Q> + Ord,
+          Q: Ord + ?Sized {
+    fn get_key(&self, key: &Q) -> Option<&V> {
+        self.get(key)
+    }
+}
+
+impl<K, Q, S> GetKey<Q, K> for HashSet<K, S>
+    where K: Borrow<Q> + Eq + Hash,
+          Q: Eq + Hash + ?Sized,
+          S: BuildHasher {
+    fn get_key(&self, key: &Q) -> Option<&K> {
+        self.get(key)
+    }
+}
+
+impl<K, Q> GetKey<Q, K> for BTreeSet<K>
+    where K: Borrow<Q> + Ord,
+          Q: Ord + ?Sized {
+    fn get_key(&self, key: &Q) -> Option<&K> {
+        self.get(key)
+    }
+}
+
+impl<K, V, S> At<K, Option<V>> for HashMap<K, V, S>
+    where K: Eq + Hash,
+          S: BuildHasher {
+    fn set_key(mut self, key: K, new_focus: Option<V>) -> Self {
+        match new_focus {
+            Some(value) => { self.insert(key, value); }
+            None => { self.remove(&key); }
+        }
+        self
+    }
+
+    fn modify_key(mut self, key: K, f: impl FnOnce(Option<V>) -> Option<V>) -> Self {
+        let focus = f(self.remove(&key));
+        self.set_key(key, focus)
+    }
+}
+
+impl<K, V> At<K, Option<V>> for BTreeMap<K, V>
+    where K: Ord {
+    fn set_key(mut self, key: K, new_focus: Option<V>) -> Self {
+        match new_focus {
+            Some(value) => { self.insert(key, value); }
+            None => { self.remove(&key); }
+        }
+        self
+    }
+
+    fn modify_key(mut self, key: K, f: impl FnOnce(Option<V>) -> Option<V>) -> Self {
+        let focus = f(self.remove(&key));
+        self.set_key(key, focus)
+    }
+}
+
+impl<K, S> At<K, bool> for HashSet<K, S>
+    where K: Eq + Hash,
+          S: BuildHasher {
+    fn set_key(mut self, key: K, new_focus: bool) -> Self {
+        if new_focus { self.insert(key); } else { self.remove(&key); }
+        self
+    }
+
+    fn modify_key(self, key: K, f: impl FnOnce(bool) -> bool) -> Self {
+        let focus = f(self.contains(&key));
+        self.set_key(key, focus)
+    }
+}
+
+impl<K> At<K, bool> for BTreeSet<K>
+    where K: Ord {
+    fn set_key(mut self, key: K, new_focus: bool) -> Self {
+        if new_focus { self.insert(key); } else { self.remove(&key); }
+        self
+    }
+
+    fn modify_key(self, key: K, f: impl FnOnce(bool) -> bool) -> Self {
+        let focus = f(self.contains(&key));
+        self.set_key(key, focus)
+    }
+}
+
+impl<'a, Container, Key, LevelOne, LevelTwo> GetKeySecond<'a, Key, LevelOne, LevelTwo> for Container
+    where Container: GetKey<Key, LevelOne> + 'a,
+          Key: ?Sized,
+          LevelOne: GetRef<LevelTwo> + 'a {}
+
+impl<'a, Container, Key, LevelOne, LevelTwo, LevelThree> GetKeyThird<'a, Key, LevelOne, LevelTwo, LevelThree> for Container
+    where Container: GetKey<Key, LevelOne> + 'a,
+          Key: ?Sized,
+          LevelOne: GetRef<LevelTwo> + 'a,
+          LevelTwo: GetRef<LevelThree> + 'a {}
+
+impl<'a, Container, Key, LevelOne, LevelTwo, LevelThree, LevelFour> GetKeyFourth<'a, Key, LevelOne, LevelTwo, LevelThree, LevelFour> for Container
+    where Container: GetKey<Key, LevelOne> + 'a,
+          Key: ?Sized,
+          LevelOne: GetRef<LevelTwo> + 'a,
+          LevelTwo: GetRef<LevelThree> + 'a,
+          LevelThree: GetRef<LevelFour> + 'a {}
+
+impl<'a, Container, Key, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive> GetKeyFifth<'a, Key, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive> for Container
+    where Container: GetKey<Key, LevelOne> + 'a,
+          Key: ?Sized,
+          LevelOne: GetRef<LevelTwo> + 'a,
+          LevelTwo: GetRef<LevelThree> + 'a,
+          LevelThree: GetRef<LevelFour> + 'a,
+          LevelFour: GetRef<LevelFive> + 'a {}
+
+impl<Container, Key, LevelOne, LevelTwo> SetKeySecond<Key, LevelOne, LevelTwo> for Container
+    where Container: At<Key, Option<LevelOne>>,
+          LevelOne: Set<LevelTwo> {}
+
+impl<Container, Key, LevelOne, LevelTwo, LevelThree> SetKeyThird<Key, LevelOne, LevelTwo, LevelThree> for Container
+    where Container: At<Key, Option<LevelOne>>,
+          LevelOne: Modify<LevelTwo>,
           LevelTwo: Set<LevelThree> {}
 
-impl<LevelOne, LevelTwo, LevelThree, LevelFour>
-SetFourth<LevelOne, LevelTwo, LevelThree, LevelFour>
-for Option<LevelOne>
-    where LevelOne: Modify<LevelTwo>,
+impl<Container, Key, LevelOne, LevelTwo, LevelThree, LevelFour> SetKeyFourth<Key, LevelOne, LevelTwo, LevelThree, LevelFour> for Container
+    where Container: At<Key, Option<LevelOne>>,
+          LevelOne: Modify<LevelTwo>,
           LevelTwo: Modify<LevelThree>,
           LevelThree: Set<LevelFour> {}
 
-impl<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
-SetFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
-for Option<LevelOne>
-    where LevelOne: Modify<LevelTwo>,
+impl<Container, Key, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive> SetKeyFifth<Key, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive> for Container
+    where Container: At<Key, Option<LevelOne>>,
+          LevelOne: Modify<LevelTwo>,
           LevelTwo: Modify<LevelThree>,
           LevelThree: Modify<LevelFour>,
           LevelFour: Set<LevelFive> {}
 
+impl<Container, Key, LevelOne, LevelTwo> ModifyKeySecond<Key, LevelOne, LevelTwo> for Container
+    where Container: At<Key, Option<LevelOne>>,
+          LevelOne: Modify<LevelTwo> {}
 
-impl<LevelOne, LevelTwo>
-ModifySecond<LevelOne, LevelTwo>
-for Option<LevelOne>
-    where LevelOne: Modify<LevelTwo> {}
-
-impl<LevelOne, LevelTwo, LevelThree>
-ModifyThird<LevelOne, LevelTwo, LevelThree>
-for Option<LevelOne>
-    where LevelOne: Modify<LevelTwo>,
+impl<Container, Key, LevelOne, LevelTwo, LevelThree> ModifyKeyThird<Key, LevelOne, LevelTwo, LevelThree> for Container
+    where Container: At<Key, Option<LevelOne>>,
+          LevelOne: Modify<LevelTwo>,
           LevelTwo: Modify<LevelThree> {}
 
-impl<LevelOne, LevelTwo, LevelThree, LevelFour>
-ModifyFourth<LevelOne, LevelTwo, LevelThree, LevelFour>
-for Option<LevelOne>
-    where LevelOne: Modify<LevelTwo>,
+impl<Container, Key, LevelOne, LevelTwo, LevelThree, LevelFour> ModifyKeyFourth<Key, LevelOne, LevelTwo, LevelThree, LevelFour> for Container
+    where Container: At<Key, Option<LevelOne>>,
+          LevelOne: Modify<LevelTwo>,
           LevelTwo: Modify<LevelThree>,
           LevelThree: Modify<LevelFour> {}
 
-impl<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
-ModifyFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive>
-for Option<LevelOne>
-    where LevelOne: Modify<LevelTwo>,
+impl<Container, Key, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive> ModifyKeyFifth<Key, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive> for Container
+    where Container: At<Key, Option<LevelOne>>,
+          LevelOne: Modify<LevelTwo>,
           LevelTwo: Modify<LevelThree>,
           LevelThree: Modify<LevelFour>,
           LevelFour: Modify<LevelFive> {}
 
+macro_rules! nth_instances {
+    ($position:ident, $index:tt, [$($before:ident)*] $target:ident [$($after:ident)*]) => {
+        impl<$($before,)* $target, $($after),*> GetNth<$position> for ($($before,)* $target, $($after,)*) {
+            type Target = $target;
+
+            fn get_nth(self, _position: $position) -> $target {
+                self.$index
+            }
+        }
+
+        impl<$($before,)* $target, $($after),*> GetRefNth<$position> for ($($before,)* $target, $($after,)*) {
+            fn get_ref_nth(&self, _position: $position) -> &$target {
+                &self.$index
+            }
+        }
+
+        impl<$($before,)* $target, $($after),*> GetMutNth<$position> for ($($before,)* $target, $($after,)*) {
+            fn get_mut_nth(&mut self, _position: $position) -> &mut $target {
+                &mut self.$index
+            }
+        }
+
+        impl<$($before,)* $target, $($after,)* Value> SetNth<$position, Value> for ($($before,)* $target, $($after,)*) {
+            type Output = ($($before,)* Value, $($after,)*);
+
+            #[allow(non_snake_case)]
+            fn set_nth(self, _position: $position, new_value: Value) -> Self::Output {
+                let ($($before,)* _, $($after,)*) = self;
+                ($($before,)* new_value, $($after,)*)
+            }
+        }
+
+        impl<$($before,)* $target, $($after,)* Value> ModifyNth<$position, Value> for ($($before,)* $target, $($after,)*) {
+            type Output = ($($before,)* Value, $($after,)*);
+
+            #[allow(non_snake_case)]
+            fn modify_nth(self, _position: $position, f: impl FnOnce($target) -> Value) -> Self::Output {
+                let ($($before,)* $target, $($after,)*) = self;
+                ($($before,)* f($target), $($after,)*)
+            }
+        }
+    };
+}
+
+macro_rules! tuple_instances {
+    ($($element:ident)+) => {
+        tuple_instances!(
+            @split [] [$($element)+]
+            [(First 0) (Second 1) (Third 2) (Fourth 3) (Fifth 4) (Sixth 5)
+             (Seventh 6) (Eighth 7) (Ninth 8) (Tenth 9) (Eleventh 10) (Twelfth 11)]
+        );
+    };
+    (@split [$($before:ident)*] [$target:ident $($after:ident)*] [($position:ident $index:tt) $($positions:tt)*]) => {
+        nth_instances!($position, $index, [$($before)*] $target [$($after)*]);
+        tuple_instances!(@split [$($before)* $target] [$($after)*] [$($positions)*]);
+    };
+    (@split [$($before:ident)*] [] [$($positions:tt)*]) => {};
+}
+
+tuple_instances!(A B);
+tuple_instances!(A B C);
+tuple_instances!(A B C D);
+tuple_instances!(A B C D E);
+tuple_instances!(A B C D E F);
+tuple_instances!(A B C D E F G);
+tuple_instances!(A B C D E F G H);
+tuple_instances!(A B C D E F G H I);
+tuple_instances!(A B C D E F G H I J);
+tuple_instances!(A B C D E F G H I J K);
+tuple_instances!(A B C D E F G H I J K L);
+
+impl<A, B> SetPoly<A, B> for Option<A> {
+    type Output = Option<B>;
+
+    fn set_poly(self, new_value: B) -> Option<B> {
+        self.map(|_| new_value)
+    }
+}
+
+impl<A, B> ModifyPoly<A, B> for Option<A> {
+    type Output = Option<B>;
+
+    fn modify_poly(self, f: impl FnMut(A) -> B) -> Option<B> {
+        self.map(f)
+    }
+}
+
+impl<A, B, E> SetPoly<A, B> for Result<A, E> {
+    type Output = Result<B, E>;
+
+    fn set_poly(self, new_value: B) -> Result<B, E> {
+        self.map(|_| new_value)
+    }
+}
+
+impl<A, B, E> ModifyPoly<A, B> for Result<A, E> {
+    type Output = Result<B, E>;
+
+    fn modify_poly(self, f: impl FnMut(A) -> B) -> Result<B, E> {
+        self.map(f)
+    }
+}
+
+impl<A, B> SetPoly<A, B> for Vec<A>
+    where B: Clone {
+    type Output = Vec<B>;
+
+    fn set_poly(self, new_value: B) -> Vec<B> {
+        self.modify_poly(|_| new_value.clone())
+    }
+}
+
+impl<A, B> ModifyPoly<A, B> for Vec<A> {
+    type Output = Vec<B>;
+
+    fn modify_poly(self, f: impl FnMut(A) -> B) -> Vec<B> {
+        self.into_iter().map(f).collect()
+    }
+}
+
+macro_rules! poly_tuple_instances {
+    (@element $_element:ident $type:ident) => { $type };
+    ($($element:ident)+) => {
+        impl<A, B> SetPoly<A, B> for ($(poly_tuple_instances!(@element $element A),)+)
+            where B: Clone {
+            type Output = ($(poly_tuple_instances!(@element $element B),)+);
+
+            fn set_poly(self, new_value: B) -> Self::Output {
+                self.modify_poly(|_| new_value.clone())
+            }
+        }
+
+        impl<A, B> ModifyPoly<A, B> for ($(poly_tuple_instances!(@element $element A),)+) {
+            type Output = ($(poly_tuple_instances!(@element $element B),)+);
+
+            #[allow(non_snake_case)]
+            fn modify_poly(self, mut f: impl FnMut(A) -> B) -> Self::Output {
+                let ($($element,)+) = self;
+                ($(f($element),)+)
+            }
+        }
+    };
+}
+
+poly_tuple_instances!(A B);
+poly_tuple_instances!(A B C);
+poly_tuple_instances!(A B C D);
+poly_tuple_instances!(A B C D E);
+poly_tuple_instances!(A B C D E F);
+poly_tuple_instances!(A B C D E F G);
+poly_tuple_instances!(A B C D E F G H);
+poly_tuple_instances!(A B C D E F G H I);
+poly_tuple_instances!(A B C D E F G H I J);
+poly_tuple_instances!(A B C D E F G H I J K);
+poly_tuple_instances!(A B C D E F G H I J K L);
+
+impl<Container, LevelOne, LevelTwo, Value> SetPolySecond<LevelOne, LevelTwo, Value> for Container
+    where LevelOne: SetPoly<LevelTwo, Value>,
+          Value: Clone,
+          Container: ModifyPoly<LevelOne, SetPolyOutput<LevelOne, LevelTwo, Value>> {}
+
+impl<Container, LevelOne, LevelTwo, LevelThree, Value> SetPolyThird<LevelOne, LevelTwo, LevelThree, Value> for Container
+    where LevelTwo: SetPoly<LevelThree, Value>,
+          LevelOne: ModifyPoly<LevelTwo, SetPolyOutput<LevelTwo, LevelThree, Value>>,
+          Value: Clone,
+          Container: ModifyPoly<LevelOne, ModifyPolyOutput<LevelOne, LevelTwo, SetPolyOutput<LevelTwo, LevelThree, Value>>> {}
+
+impl<Container, LevelOne, LevelTwo, LevelThree, LevelFour, Value> SetPolyFourth<LevelOne, LevelTwo, LevelThree, LevelFour, Value> for Container
+    where LevelThree: SetPoly<LevelFour, Value>,
+          LevelTwo: ModifyPoly<LevelThree, SetPolyOutput<LevelThree, LevelFour, Value>>,
+          LevelOne: ModifyPoly<LevelTwo, ModifyPolyOutput<LevelTwo, LevelThree, SetPolyOutput<LevelThree, LevelFour, Value>>>,
+          Value: Clone,
+          Container: ModifyPoly<LevelOne, ModifyPolyOutput<LevelOne, LevelTwo, ModifyPolyOutput<LevelTwo, LevelThree, SetPolyOutput<LevelThree, LevelFour, Value>>>> {}
+
+impl<Container, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive, Value> SetPolyFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive, Value> for Container
+    where LevelFour: SetPoly<LevelFive, Value>,
+          LevelThree: ModifyPoly<LevelFour, SetPolyOutput<LevelFour, LevelFive, Value>>,
+          LevelTwo: ModifyPoly<LevelThree, ModifyPolyOutput<LevelThree, LevelFour, SetPolyOutput<LevelFour, LevelFive, Value>>>,
+          LevelOne: ModifyPoly<LevelTwo, ModifyPolyOutput<LevelTwo, LevelThree, ModifyPolyOutput<LevelThree, LevelFour, SetPolyOutput<LevelFour, LevelFive, Value>>>>,
+          Value: Clone,
+          Container: ModifyPoly<LevelOne, ModifyPolyOutput<LevelOne, LevelTwo, ModifyPolyOutput<LevelTwo, LevelThree, ModifyPolyOutput<LevelThree, LevelFour, SetPolyOutput<LevelFour, LevelFive, Value>>>>> {}
+
+impl<Container, LevelOne, LevelTwo, Value> ModifyPolySecond<LevelOne, LevelTwo, Value> for Container
+    where LevelOne: ModifyPoly<LevelTwo, Value>,
+          Container: ModifyPoly<LevelOne, ModifyPolyOutput<LevelOne, LevelTwo, Value>> {}
+
+impl<Container, LevelOne, LevelTwo, LevelThree, Value> ModifyPolyThird<LevelOne, LevelTwo, LevelThree, Value> for Container
+    where LevelTwo: ModifyPoly<LevelThree, Value>,
+          LevelOne: ModifyPoly<LevelTwo, ModifyPolyOutput<LevelTwo, LevelThree, Value>>,
+          Container: ModifyPoly<LevelOne, ModifyPolyOutput<LevelOne, LevelTwo, ModifyPolyOutput<LevelTwo, LevelThree, Value>>> {}
+
+impl<Container, LevelOne, LevelTwo, LevelThree, LevelFour, Value> ModifyPolyFourth<LevelOne, LevelTwo, LevelThree, LevelFour, Value> for Container
+    where LevelThree: ModifyPoly<LevelFour, Value>,
+          LevelTwo: ModifyPoly<LevelThree, ModifyPolyOutput<LevelThree, LevelFour, Value>>,
+          LevelOne: ModifyPoly<LevelTwo, ModifyPolyOutput<LevelTwo, LevelThree, ModifyPolyOutput<LevelThree, LevelFour, Value>>>,
+          Container: ModifyPoly<LevelOne, ModifyPolyOutput<LevelOne, LevelTwo, ModifyPolyOutput<LevelTwo, LevelThree, ModifyPolyOutput<LevelThree, LevelFour, Value>>>> {}
+
+impl<Container, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive, Value> ModifyPolyFifth<LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive, Value> for Container
+    where LevelFour: ModifyPoly<LevelFive, Value>,
+          LevelThree: ModifyPoly<LevelFour, ModifyPolyOutput<LevelFour, LevelFive, Value>>,
+          LevelTwo: ModifyPoly<LevelThree, ModifyPolyOutput<LevelThree, LevelFour, ModifyPolyOutput<LevelFour, LevelFive, Value>>>,
+          LevelOne: ModifyPoly<LevelTwo, ModifyPolyOutput<LevelTwo, LevelThree, ModifyPolyOutput<LevelThree, LevelFour, ModifyPolyOutput<LevelFour, LevelFive, Value>>>>,
+          Container: ModifyPoly<LevelOne, ModifyPolyOutput<LevelOne, LevelTwo, ModifyPolyOutput<LevelTwo, LevelThree, ModifyPolyOutput<LevelThree, LevelFour, ModifyPolyOutput<LevelFour, LevelFive, Value>>>>> {}
+
+impl<Container, TagOne, TagTwo, LevelOne, LevelTwo> GetFieldSecond<TagOne, TagTwo, LevelOne, LevelTwo> for Container
+    where Container: GetField<TagOne, LevelOne>,
+          LevelOne: GetField<TagTwo, LevelTwo> {}
+
+impl<Container, TagOne, TagTwo, TagThree, LevelOne, LevelTwo, LevelThree> GetFieldThird<TagOne, TagTwo, TagThree, LevelOne, LevelTwo, LevelThree> for Container
+    where Container: GetField<TagOne, LevelOne>,
+          LevelOne: GetField<TagTwo, LevelTwo>,
+          LevelTwo: GetField<TagThree, LevelThree> {}
+
+impl<Container, TagOne, TagTwo, TagThree, TagFour, LevelOne, LevelTwo, LevelThree, LevelFour> GetFieldFourth<TagOne, TagTwo, TagThree, TagFour, LevelOne, LevelTwo, LevelThree, LevelFour> for Container
+    where Container: GetField<TagOne, LevelOne>,
+          LevelOne: GetField<TagTwo, LevelTwo>,
+          LevelTwo: GetField<TagThree, LevelThree>,
+          LevelThree: GetField<TagFour, LevelFour> {}
+
+impl<Container, TagOne, TagTwo, TagThree, TagFour, TagFive, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive> GetFieldFifth<TagOne, TagTwo, TagThree, TagFour, TagFive, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive> for Container
+    where Container: GetField<TagOne, LevelOne>,
+          LevelOne: GetField<TagTwo, LevelTwo>,
+          LevelTwo: GetField<TagThree, LevelThree>,
+          LevelThree: GetField<TagFour, LevelFour>,
+          LevelFour: GetField<TagFive, LevelFive> {}
+
+impl<'a, Container, TagOne, TagTwo, LevelOne, LevelTwo> GetRefFieldSecond<'a, TagOne, TagTwo, LevelOne, LevelTwo> for Container
+    where Container: GetRefField<TagOne, LevelOne> + 'a,
+          LevelOne: GetRefField<TagTwo, LevelTwo> + 'a {}
+
+impl<'a, Container, TagOne, TagTwo, TagThree, LevelOne, LevelTwo, LevelThree> GetRefFieldThird<'a, TagOne, TagTwo, TagThree, LevelOne, LevelTwo, LevelThree> for Container
+    where Container: GetRefField<TagOne, LevelOne> + 'a,
+          LevelOne: GetRefField<TagTwo, LevelTwo> + 'a,
+          LevelTwo: GetRefField<TagThree, LevelThree> + 'a {}
+
+impl<'a, Container, TagOne, TagTwo, TagThree, TagFour, LevelOne, LevelTwo, LevelThree, LevelFour> GetRefFieldFourth<'a, TagOne, TagTwo, TagThree, TagFour, LevelOne, LevelTwo, LevelThree, LevelFour> for Container
+    where Container: GetRefField<TagOne, LevelOne> + 'a,
+          LevelOne: GetRefField<TagTwo, LevelTwo> + 'a,
+          LevelTwo: GetRefField<TagThree, LevelThree> + 'a,
+          LevelThree: GetRefField<TagFour, LevelFour> + 'a {}
+
+impl<'a, Container, TagOne, TagTwo, TagThree, TagFour, TagFive, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive> GetRefFieldFifth<'a, TagOne, TagTwo, TagThree, TagFour, TagFive, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive> for Container
+    where Container: GetRefField<TagOne, LevelOne> + 'a,
+          LevelOne: GetRefField<TagTwo, LevelTwo> + 'a,
+          LevelTwo: GetRefField<TagThree, LevelThree> + 'a,
+          LevelThree: GetRefField<TagFour, LevelFour> + 'a,
+          LevelFour: GetRefField<TagFive, LevelFive> + 'a {}
+
+impl<Container, TagOne, TagTwo, LevelOne, LevelTwo> SetFieldSecond<TagOne, TagTwo, LevelOne, LevelTwo> for Container
+    where Container: ModifyField<TagOne, LevelOne>,
+          LevelOne: SetField<TagTwo, LevelTwo> {}
+
+impl<Container, TagOne, TagTwo, TagThree, LevelOne, LevelTwo, LevelThree> SetFieldThird<TagOne, TagTwo, TagThree, LevelOne, LevelTwo, LevelThree> for Container
+    where Container: ModifyField<TagOne, LevelOne>,
+          LevelOne: ModifyField<TagTwo, LevelTwo>,
+          LevelTwo: SetField<TagThree, LevelThree> {}
+
+impl<Container, TagOne, TagTwo, TagThree, TagFour, LevelOne, LevelTwo, LevelThree, LevelFour> SetFieldFourth<TagOne, TagTwo, TagThree, TagFour, LevelOne, LevelTwo, LevelThree, LevelFour> for Container
+    where Container: ModifyField<TagOne, LevelOne>,
+          LevelOne: ModifyField<TagTwo, LevelTwo>,
+          LevelTwo: ModifyField<TagThree, LevelThree>,
+          LevelThree: SetField<TagFour, LevelFour> {}
+
+impl<Container, TagOne, TagTwo, TagThree, TagFour, TagFive, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive> SetFieldFifth<TagOne, TagTwo, TagThree, TagFour, TagFive, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive> for Container
+    where Container: ModifyField<TagOne, LevelOne>,
+          LevelOne: ModifyField<TagTwo, LevelTwo>,
+          LevelTwo: ModifyField<TagThree, LevelThree>,
+          LevelThree: ModifyField<TagFour, LevelFour>,
+          LevelFour: SetField<TagFive, LevelFive> {}
+
+impl<Container, TagOne, TagTwo, LevelOne, LevelTwo> ModifyFieldSecond<TagOne, TagTwo, LevelOne, LevelTwo> for Container
+    where Container: ModifyField<TagOne, LevelOne>,
+          LevelOne: ModifyField<TagTwo, LevelTwo> {}
+
+impl<Container, TagOne, TagTwo, TagThree, LevelOne, LevelTwo, LevelThree> ModifyFieldThird<TagOne, TagTwo, TagThree, LevelOne, LevelTwo, LevelThree> for Container
+    where Container: ModifyField<TagOne, LevelOne>,
+          LevelOne: ModifyField<TagTwo, LevelTwo>,
+          LevelTwo: ModifyField<TagThree, LevelThree> {}
+
+impl<Container, TagOne, TagTwo, TagThree, TagFour, LevelOne, LevelTwo, LevelThree, LevelFour> ModifyFieldFourth<TagOne, TagTwo, TagThree, TagFour, LevelOne, LevelTwo, LevelThree, LevelFour> for Container
+    where Container: ModifyField<TagOne, LevelOne>,
+          LevelOne: ModifyField<TagTwo, LevelTwo>,
+          LevelTwo: ModifyField<TagThree, LevelThree>,
+          LevelThree: ModifyField<TagFour, LevelFour> {}
+
+impl<Container, TagOne, TagTwo, TagThree, TagFour, TagFive, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive> ModifyFieldFifth<TagOne, TagTwo, TagThree, TagFour, TagFive, LevelOne, LevelTwo, LevelThree, LevelFour, LevelFive> for Container
+    where Container: ModifyField<TagOne, LevelOne>,
+          LevelOne: ModifyField<TagTwo, LevelTwo>,
+          LevelTwo: ModifyField<TagThree, LevelThree>,
+          LevelThree: ModifyField<TagFour, LevelFour>,
+          LevelFour: ModifyField<TagFive, LevelFive> {}
@@ -0,0 +1,355 @@
+use crate::*;
+use serde_json::{Map, Number, Value};
+use std::{
+    borrow::Borrow,
+    fmt::{Display, Formatter, Result as FmtResult},
+    hash::Hash,
+};
+
+mod pointer;
+mod query;
+
+pub use self::{pointer::*, query::*};
+
+macro_rules! variant_prisms {
+    ($($variant:ident => $target:ty),+) => {
+        $(
+            impl GetOption<$target> for Value {
+                fn get_option(self) -> Option<$target> {
+                    match self {
+                        Value::$variant(value) => Some(value),
+                        _ => None,
+                    }
+                }
+            }
+
+            impl GetRefOption<$target> for Value {
+                fn get_ref_option(&self) -> Option<&$target> {
+                    match self {
+                        Value::$variant(value) => Some(value),
+                        _ => None,
+                    }
+                }
+            }
+
+            impl GetMutOption<$target> for Value {
+                fn get_mut_option(&mut self) -> Option<&mut $target> {
+                    match self {
+                        Value::$variant(value) => Some(value),
+                        _ => None,
+                    }
+                }
+            }
+
+            impl SetOption<$target> for Value {
+                fn set_option(self, new_value: $target) -> Option<Self> {
+                    match self {
+                        Value::$variant(_) => Some(Value::$variant(new_value)),
+                        _ => None,
+                    }
+                }
+            }
+
+            impl ModifyOption<$target> for Value {
+                fn modify_option(self, f: impl FnOnce($target) -> $target) -> Option<Self> {
+                    match self {
+                        Value::$variant(value) => Some(Value::$variant(f(value))),
+                        _ => None,
+                    }
+                }
+            }
+
+            impl ReverseGet<$target> for Value {
+                fn reverse_get(value: $target) -> Self {
+                    Value::$variant(value)
+                }
+            }
+        )+
+    };
+}
+
+variant_prisms!(
+    Bool => bool,
+    Number => Number,
+    String => String,
+    Array => Vec<Value>,
+    Object => Map<String, Value>
+);
+
+impl GetOption<()> for Value {
+    fn get_option(self) -> Option<()> {
+        self.as_null()
+    }
+}
+
+impl GetRefOption<()> for Value {
+    fn get_ref_option(&self) -> Option<&()> {
+        self.as_null().map(|_| &())
+    }
+}
+
+impl SetOption<()> for Value {
+    fn set_option(self, _: ()) -> Option<Self> {
+        self.as_null().map(|_| Value::Null)
+    }
+}
+
+impl ModifyOption<()> for Value {
+    fn modify_option(self, f: impl FnOnce(())) -> Option<Self> {
+        self.as_null().map(f).map(|_| Value::Null)
+    }
+}
+
+impl ReverseGet<()> for Value {
+    fn reverse_get(_: ()) -> Self {
+        Value::Null
+    }
+}
+
+impl GetOption<f64> for Value {
+    fn get_option(self) -> Option<f64> {
+        self.as_f64()
+    }
+}
+
+impl SetOption<f64> for Value {
+    fn set_option(self, new_value: f64) -> Option<Self> {
+        self.as_f64()
+            .and_then(|_| Number::from_f64(new_value))
+            .map(Value::Number)
+    }
+}
+
+impl ModifyOption<f64> for Value {
+    fn modify_option(self, f: impl FnOnce(f64) -> f64) -> Option<Self> {
+        self.as_f64()
+            .and_then(|value| Number::from_f64(f(value)))
+            .map(Value::Number)
+    }
+}
+
+impl ReverseGet<f64> for Value {
+    fn reverse_get(value: f64) -> Self {
+        Value::from(value)
+    }
+}
+
+impl<Q> GetKey<Q, Value> for Map<String, Value>
+    where String: Borrow<Q>,
+          Q: Ord + Eq + Hash + ?Sized {
+    fn get_key(&self, key: &Q) -> Option<&Value> {
+        self.get(key)
+    }
+}
+
+impl<Q> GetKey<Q, Value> for Value
+    where String: Borrow<Q>,
+          Q: Ord + Eq + Hash + ?Sized {
+    fn get_key(&self, key: &Q) -> Option<&Value> {
+        self.as_object()?.get(key)
+    }
+}
+
+impl At<String, Option<Value>> for Map<String, Value> {
+    fn set_key(mut self, key: String, new_focus: Option<Value>) -> Self {
+        match new_focus {
+            Some(value) => { self.insert(key, value); }
+            None => { self.remove(&key); }
+        }
+        self
+    }
+
+    fn modify_key(mut self, key: String, f: impl FnOnce(Option<Value>) -> Option<Value>) -> Self {
+        let focus = f(self.remove(&key));
+        self.set_key(key, focus)
+    }
+}
+
+impl At<String, Option<Value>> for Value {
+    fn set_key(self, key: String, new_focus: Option<Value>) -> Self {
+        self.modify_key(key, |_| new_focus)
+    }
+
+    fn modify_key(self, key: String, f: impl FnOnce(Option<Value>) -> Option<Value>) -> Self {
+        match self {
+            Value::Object(object) => Value::Object(object.modify_key(key, f)),
+            other => other,
+        }
+    }
+}
+
+fn kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
+impl GetIndex<Value> for Value {
+    fn get_index(&self, index: usize) -> Option<&Value> {
+        self.as_array()?.get(index)
+    }
+}
+
+impl SetIndex<Value> for Value {
+    fn set_index(self, index: usize, new_value: Value) -> Option<Self> {
+        GetOption::<Vec<Value>>::get_option(self)?
+            .set_index(index, new_value)
+            .map(Value::Array)
+    }
+}
+
+impl ModifyIndex<Value> for Value {
+    fn modify_index(self, index: usize, f: impl FnOnce(Value) -> Value) -> Option<Self> {
+        GetOption::<Vec<Value>>::get_option(self)?
+            .modify_index(index, f)
+            .map(Value::Array)
+    }
+}
+
+/// A step of a runtime path into a JSON value: the key of an object member, or the position of an array element (see [`json_path!`]).
+///
+/// [`json_path!`]: ../macro.json_path.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+impl From<&str> for Segment {
+    fn from(key: &str) -> Self {
+        Segment::Key(key.to_owned())
+    }
+}
+
+impl From<String> for Segment {
+    fn from(key: String) -> Self {
+        Segment::Key(key)
+    }
+}
+
+impl From<usize> for Segment {
+    fn from(index: usize) -> Self {
+        Segment::Index(index)
+    }
+}
+
+impl Display for Segment {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Segment::Key(key) => write!(f, "{:?}", key),
+            Segment::Index(index) => write!(f, "{}", index),
+        }
+    }
+}
+
+/// Reaches arbitrarily deep in a JSON value along a runtime path of [`Segment`]s, the counterpart of [`AtPath`] for data without static types.
+///
+/// A path is valid if every key segment meets an object that has the key, and every index segment meets an array that is long enough. Along an invalid path, the getters return `None`, and so do the setters (like [`SetIndex`] and [`SetOption`]).
+///
+/// [`Segment`]: enum.Segment.html
+/// [`AtPath`]: ../focus/trait.AtPath.html
+/// [`SetIndex`]: ../focus/trait.SetIndex.html
+/// [`SetOption`]: ../focus/trait.SetOption.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// use serde_json::json;
+///
+/// let config = json!({ "users": [{ "email": "joe@example.com" }, { "email": "ann@example.com" }] });
+///
+/// assert_eq!(
+///     Some(&json!("ann@example.com")),
+///     config.get_ref_json(&json_path!["users", 1, "email"])
+/// );
+///
+/// let config = config.modify_json(&json_path!["users", 1, "email"], |email| {
+///     json!(email.as_str().unwrap_or_default().to_uppercase())
+/// });
+///
+/// assert_eq!(
+///     Some(json!("ANN@EXAMPLE.COM")),
+///     config.and_then(|config| config.get_json(&json_path!["users", 1, "email"]))
+/// );
+///
+/// assert_eq!(None, json!({ "users": [] }).set_json(&json_path!["users", 0, "email"], json!(null)));
+///```
+pub trait ValuePath
+    where Self: Sized {
+    fn get_json(self, path: &[Segment]) -> Option<Value>;
+
+    fn get_ref_json(&self, path: &[Segment]) -> Option<&Value>;
+
+    fn get_mut_json(&mut self, path: &[Segment]) -> Option<&mut Value>;
+
+    fn set_json(self, path: &[Segment], new_value: Value) -> Option<Self>;
+
+    fn modify_json(self, path: &[Segment], f: impl FnOnce(Value) -> Value) -> Option<Self>;
+}
+
+impl ValuePath for Value {
+    fn get_json(mut self, path: &[Segment]) -> Option<Value> {
+        self.get_mut_json(path).map(Value::take)
+    }
+
+    fn get_ref_json(&self, path: &[Segment]) -> Option<&Value> {
+        path.iter().try_fold(self, |value, segment| match segment {
+            Segment::Key(key) => value.get(key),
+            Segment::Index(index) => value.get(index),
+        })
+    }
+
+    fn get_mut_json(&mut self, path: &[Segment]) -> Option<&mut Value> {
+        path.iter().try_fold(self, |value, segment| match segment {
+            Segment::Key(key) => value.get_mut(key),
+            Segment::Index(index) => value.get_mut(index),
+        })
+    }
+
+    fn set_json(mut self, path: &[Segment], new_value: Value) -> Option<Self> {
+        *self.get_mut_json(path)? = new_value;
+        Some(self)
+    }
+
+    fn modify_json(mut self, path: &[Segment], f: impl FnOnce(Value) -> Value) -> Option<Self> {
+        let target = self.get_mut_json(path)?;
+        *target = f(target.take());
+        Some(self)
+    }
+}
+
+/// Builds a runtime path of [`Segment`]s for [`ValuePath`]: string expressions become object keys, `usize` expressions become array positions.
+///
+/// [`Segment`]: json/enum.Segment.html
+/// [`ValuePath`]: json/trait.ValuePath.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// use serde_json::json;
+///
+/// let user = 3;
+/// let path = json_path!["users", user, "email"];
+///
+/// assert_eq!(
+///     [Segment::Key(String::from("users")), Segment::Index(3), Segment::Key(String::from("email"))],
+///     path
+/// );
+///
+/// assert_eq!("\"users\" 3 \"email\"", path.iter().map(Segment::to_string).collect::<Vec<_>>().join(" "));
+///
+/// let users = json!({ "users": [null, null, null, { "email": "joe@example.com" }] });
+///
+/// assert_eq!(Some("joe@example.com"), users.get_ref_json(&path).and_then(|email| email.as_str()));
+///```
+#[macro_export]
+macro_rules! json_path {
+    ($($segment:expr),* $(,)?) => {
+        [$(Segment::from($segment)),*]
+    };
+}
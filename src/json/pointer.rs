@@ -0,0 +1,241 @@
+use super::kind;
+use serde_json::Value;
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+/// A JSON Pointer ([RFC 6901]), parsed into its reference tokens. It is a runtime affine traversal over `serde_json::Value`: it focuses on at most one value, and every failure tells which token could not be resolved.
+///
+/// A token is looked up as a key in objects, and as a position in arrays (`0`, or digits without a leading zero). When setting a value, the last token may name a new object member, or `-` to append to an array.
+///
+/// [RFC 6901]: https://www.rfc-editor.org/rfc/rfc6901
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// use serde_json::json;
+///
+/// let order = || json!({ "items": [{ "sku": "a/1", "qty": 1 }], "note": "~" });
+///
+/// let qty: Pointer = "/items/0/qty".parse().unwrap();
+///
+/// assert_eq!(Ok(&json!(1)), qty.get(&order()));
+/// assert_eq!(Ok(json!(3)), qty.modify(order(), |qty| json!(qty.as_u64().unwrap_or_default() + 2)).and_then(|order| qty.get(&order).cloned()));
+///
+/// // `~1` and `~0` escape `/` and `~`
+///
+/// let item = Pointer::parse("/items/-").unwrap();
+/// let order = item.set(order(), json!({ "sku": "b~2", "qty": 5 })).unwrap();
+///
+/// assert_eq!(Ok(&json!("b~2")), Pointer::parse("/items/1/sku").unwrap().get(&order));
+/// assert_eq!("/a~01/b~1c", Pointer::parse("/a~01/b~1c").unwrap().to_string());
+///
+/// let (order, removed) = Pointer::parse("/note").unwrap().remove(order).unwrap();
+///
+/// assert_eq!(json!("~"), removed);
+///
+/// // Errors name the token that failed
+///
+/// let error = Pointer::parse("/items/7/qty").unwrap().get(&order).unwrap_err();
+///
+/// assert_eq!(1, error.segment);
+/// assert_eq!(PointerErrorKind::OutOfBounds { length: 2 }, error.kind);
+/// assert_eq!("token 1 `7`: index out of bounds for an array of length 2", error.to_string());
+///```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Pointer {
+    tokens: Vec<String>,
+}
+
+/// The reason a [`Pointer`] failed, see [`PointerError`].
+///
+/// [`Pointer`]: struct.Pointer.html
+/// [`PointerError`]: struct.PointerError.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PointerErrorKind {
+    MissingSlash,
+    InvalidEscape,
+    MissingKey,
+    InvalidIndex,
+    OutOfBounds { length: usize },
+    NotAContainer { found: &'static str },
+    RemoveRoot,
+}
+
+/// A [`Pointer`] that couldn't be parsed or resolved: `segment` is the position of the failing reference token (starting from 0), `token` is its text.
+///
+/// [`Pointer`]: struct.Pointer.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PointerError {
+    pub segment: usize,
+    pub token: String,
+    pub kind: PointerErrorKind,
+}
+
+impl Display for PointerErrorKind {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            PointerErrorKind::MissingSlash => write!(f, "a non-empty pointer must start with `/`"),
+            PointerErrorKind::InvalidEscape => write!(f, "`~` must be followed by `0` or `1`"),
+            PointerErrorKind::MissingKey => write!(f, "no such key in the object"),
+            PointerErrorKind::InvalidIndex => write!(f, "not a valid array index"),
+            PointerErrorKind::OutOfBounds { length } => write!(f, "index out of bounds for an array of length {}", length),
+            PointerErrorKind::NotAContainer { found } => write!(f, "expected an object or an array, found {}", found),
+            PointerErrorKind::RemoveRoot => write!(f, "the whole document can't be removed"),
+        }
+    }
+}
+
+impl Display for PointerError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "token {} `{}`: {}", self.segment, self.token, self.kind)
+    }
+}
+
+impl std::error::Error for PointerError {}
+
+fn error(segment: usize, token: &str, kind: PointerErrorKind) -> PointerError {
+    PointerError { segment, token: token.to_owned(), kind }
+}
+
+fn index(segment: usize, token: &str, length: usize) -> Result<usize, PointerError> {
+    let digits = !token.is_empty() && token.bytes().all(|byte| byte.is_ascii_digit());
+    match token {
+        "-" => Err(error(segment, token, PointerErrorKind::OutOfBounds { length })),
+        _ if !digits || (token.len() > 1 && token.starts_with('0')) => Err(error(segment, token, PointerErrorKind::InvalidIndex)),
+        _ => token.parse()
+            .map_err(|_| error(segment, token, PointerErrorKind::InvalidIndex))
+            .and_then(|index| if index < length {
+                Ok(index)
+            } else {
+                Err(error(segment, token, PointerErrorKind::OutOfBounds { length }))
+            }),
+    }
+}
+
+fn step<'a>(value: &'a Value, segment: usize, token: &str) -> Result<&'a Value, PointerError> {
+    match value {
+        Value::Object(object) => object.get(token).ok_or_else(|| error(segment, token, PointerErrorKind::MissingKey)),
+        Value::Array(array) => Ok(&array[index(segment, token, array.len())?]),
+        other => Err(error(segment, token, PointerErrorKind::NotAContainer { found: kind(other) })),
+    }
+}
+
+fn step_mut<'a>(value: &'a mut Value, segment: usize, token: &str) -> Result<&'a mut Value, PointerError> {
+    match value {
+        Value::Object(object) => object.get_mut(token).ok_or_else(|| error(segment, token, PointerErrorKind::MissingKey)),
+        Value::Array(array) => {
+            let position = index(segment, token, array.len())?;
+            Ok(&mut array[position])
+        }
+        other => Err(error(segment, token, PointerErrorKind::NotAContainer { found: kind(other) })),
+    }
+}
+
+fn walk_mut<'a>(tokens: &[String], value: &'a mut Value) -> Result<&'a mut Value, PointerError> {
+    tokens.iter()
+        .enumerate()
+        .try_fold(value, |value, (segment, token)| step_mut(value, segment, token))
+}
+
+impl Pointer {
+    /// Parses an RFC 6901 pointer: the empty string points to the whole document, every other pointer is a list of `/`-prefixed reference tokens.
+    pub fn parse(pointer: &str) -> Result<Self, PointerError> {
+        if pointer.is_empty() {
+            return Ok(Pointer::default());
+        }
+        if !pointer.starts_with('/') {
+            return Err(error(0, pointer, PointerErrorKind::MissingSlash));
+        }
+        pointer[1..].split('/')
+            .enumerate()
+            .map(|(segment, token)| {
+                let mut unescaped = String::with_capacity(token.len());
+                let mut chars = token.chars();
+                while let Some(c) = chars.next() {
+                    unescaped.push(match c {
+                        '~' => match chars.next() {
+                            Some('0') => '~',
+                            Some('1') => '/',
+                            _ => return Err(error(segment, token, PointerErrorKind::InvalidEscape)),
+                        },
+                        other => other,
+                    });
+                }
+                Ok(unescaped)
+            })
+            .collect::<Result<_, _>>()
+            .map(|tokens| Pointer { tokens })
+    }
+
+    /// The unescaped reference tokens.
+    pub fn tokens(&self) -> &[String] {
+        &self.tokens
+    }
+
+    pub fn get<'a>(&self, value: &'a Value) -> Result<&'a Value, PointerError> {
+        self.tokens.iter()
+            .enumerate()
+            .try_fold(value, |value, (segment, token)| step(value, segment, token))
+    }
+
+    pub fn get_mut<'a>(&self, value: &'a mut Value) -> Result<&'a mut Value, PointerError> {
+        walk_mut(&self.tokens, value)
+    }
+
+    /// Replaces the target value, adds it as a new member if the parent is an object, or appends it if the parent is an array and the last token is `-`.
+    pub fn set(&self, mut value: Value, new_value: Value) -> Result<Value, PointerError> {
+        let (last, parent) = match self.tokens.split_last() {
+            Some(split) => split,
+            None => return Ok(new_value),
+        };
+        let segment = parent.len();
+        match walk_mut(parent, &mut value)? {
+            Value::Object(object) => { object.insert(last.clone(), new_value); }
+            Value::Array(array) if last == "-" => array.push(new_value),
+            Value::Array(array) => {
+                let position = index(segment, last, array.len())?;
+                array[position] = new_value;
+            }
+            other => return Err(error(segment, last, PointerErrorKind::NotAContainer { found: kind(other) })),
+        }
+        Ok(value)
+    }
+
+    pub fn modify(&self, mut value: Value, f: impl FnOnce(Value) -> Value) -> Result<Value, PointerError> {
+        let target = self.get_mut(&mut value)?;
+        *target = f(target.take());
+        Ok(value)
+    }
+
+    /// Removes the target value, returns the updated document and the removed value. Array elements after the removed one are shifted to the left.
+    pub fn remove(&self, mut value: Value) -> Result<(Value, Value), PointerError> {
+        let (last, parent) = match self.tokens.split_last() {
+            Some(split) => split,
+            None => return Err(error(0, "", PointerErrorKind::RemoveRoot)),
+        };
+        let segment = parent.len();
+        let removed = match walk_mut(parent, &mut value)? {
+            Value::Object(object) => object.remove(last).ok_or_else(|| error(segment, last, PointerErrorKind::MissingKey))?,
+            Value::Array(array) => array.remove(index(segment, last, array.len())?),
+            other => return Err(error(segment, last, PointerErrorKind::NotAContainer { found: kind(other) })),
+        };
+        Ok((value, removed))
+    }
+}
+
+impl FromStr for Pointer {
+    type Err = PointerError;
+
+    fn from_str(pointer: &str) -> Result<Self, Self::Err> {
+        Pointer::parse(pointer)
+    }
+}
+
+impl Display for Pointer {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        self.tokens.iter()
+            .try_for_each(|token| write!(f, "/{}", token.replace('~', "~0").replace('/', "~1")))
+    }
+}
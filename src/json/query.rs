@@ -0,0 +1,595 @@
+use super::{kind, Segment, ValuePath};
+use serde_json::Value;
+use std::{
+    cmp::Ordering,
+    fmt::{Display, Formatter, Result as FmtResult},
+    mem,
+    str::FromStr,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Comparison {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Filter {
+    path: Vec<Segment>,
+    comparison: Option<(Comparison, Value)>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Step {
+    Key(String),
+    Index(i64),
+    Wildcard,
+    Filter(Filter),
+}
+
+/// A query in a limited subset of JSONPath, parsed into a runtime traversal over `serde_json::Value`: it focuses on any number of values, in document order.
+///
+/// The supported syntax:
+/// - `$` is the root, it starts every query,
+/// - `.name` and `['name']` (or `["name"]`) select an object member,
+/// - `[3]` selects an array element, negative positions count from the end,
+/// - `.*` and `[*]` select every element of an array, or every member of an object,
+/// - `[?(@.name)]` selects the children that have the member, and `[?(@.name == 'x')]` the children where it compares to the literal (`==`, `!=`, `<`, `<=`, `>`, `>=`; the literal is a number, a quoted string, `true`, `false`, or `null`). The path after `@` may use the same member and element selectors.
+///
+/// A filter comparison only holds for children that have the member: a child without it is neither `<` nor `>=` the literal, and it is not `==` to it either, so `!=` selects it (like in RFC 9535).
+///
+/// Selectors that don't match anything are skipped, so a valid query never fails at runtime: the parser reports the exact position of a syntax error instead. [`try_get`] and [`try_get_mut`] are the checked variants, which fail with a [`SelectionError`] naming the selector that found no member, no element, or the wrong kind of value.
+///
+/// A query is displayed in a normalized form (`.name` or `['name']`, `[*]`, `[3]`), which parses back to the same query.
+///
+/// [`try_get`]: struct.Query.html#method.try_get
+/// [`try_get_mut`]: struct.Query.html#method.try_get_mut
+/// [`SelectionError`]: struct.SelectionError.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// use serde_json::json;
+///
+/// let users = || json!({ "users": [
+///     { "name": "joe", "age": 42, "email": "joe@example.com" },
+///     { "name": "ann", "age": 17 },
+///     { "name": "bob", "age": 36, "email": "bob@example.com" },
+/// ] });
+///
+/// let adults: Query = "$.users[?(@.age >= 18)].name".parse().unwrap();
+///
+/// assert_eq!(vec![&json!("joe"), &json!("bob")], adults.get(&users()));
+///
+/// let names = Query::parse("$.users[*].name").unwrap();
+///
+/// assert_eq!(
+///     vec![&json!("JOE"), &json!("ANN"), &json!("BOB")],
+///     names.get(&names.modify(users(), |name| json!(name.as_str().unwrap_or_default().to_uppercase())))
+/// );
+///
+/// let (users, emails) = Query::parse("$.users[*].email").unwrap().remove(users());
+///
+/// assert_eq!(2, emails.len());
+/// assert_eq!(json!({ "name": "bob", "age": 36 }), Query::parse("$['users'][-1]").unwrap().get(&users)[0].clone());
+///
+/// let error = Query::parse("$.users[?(@.age > ten)]").unwrap_err();
+///
+/// assert_eq!(18, error.position);
+/// assert_eq!("expected a literal at position 18, found `t`", error.to_string());
+///
+/// let emails = Query::parse("$.users[*]['email']").unwrap();
+///
+/// assert_eq!("$.users[*].email", emails.to_string());
+/// assert_eq!(
+///     "selector 2 `.email`: no such key in the object",
+///     emails.try_get(&users).unwrap_err().to_string()
+/// );
+///```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Query {
+    steps: Vec<Step>,
+}
+
+/// A [`Query`] that couldn't be parsed: `position` is the byte offset of the problem in the query string, `found` is the character there (`None` at the end of the query).
+///
+/// [`Query`]: struct.Query.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct QueryError {
+    pub position: usize,
+    pub expected: &'static str,
+    pub found: Option<char>,
+}
+
+impl Display for QueryError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self.found {
+            Some(found) => write!(f, "expected {} at position {}, found `{}`", self.expected, self.position, found),
+            None => write!(f, "expected {} at position {}, found the end of the query", self.expected, self.position),
+        }
+    }
+}
+
+impl std::error::Error for QueryError {}
+
+/// The reason a checked [`Query`] selection failed, see [`SelectionError`].
+///
+/// [`Query`]: struct.Query.html
+/// [`SelectionError`]: struct.SelectionError.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SelectionErrorKind {
+    MissingKey,
+    OutOfBounds { length: usize },
+    WrongKind { expected: &'static str, found: &'static str },
+}
+
+/// A [`Query`] selector that matched nothing in a checked selection: `step` is the position of the selector after `$` (starting from 0), `selector` is its normalized text.
+///
+/// [`Query`]: struct.Query.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SelectionError {
+    pub step: usize,
+    pub selector: String,
+    pub kind: SelectionErrorKind,
+}
+
+impl Display for SelectionErrorKind {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            SelectionErrorKind::MissingKey => write!(f, "no such key in the object"),
+            SelectionErrorKind::OutOfBounds { length } => write!(f, "index out of bounds for an array of length {}", length),
+            SelectionErrorKind::WrongKind { expected, found } => write!(f, "expected {}, found {}", expected, found),
+        }
+    }
+}
+
+impl Display for SelectionError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "selector {} `{}`: {}", self.step, self.selector, self.kind)
+    }
+}
+
+impl std::error::Error for SelectionError {}
+
+struct Parser<'a> {
+    source: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.source[self.position..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let next = self.peek();
+        self.position += next.map_or(0, char::len_utf8);
+        next
+    }
+
+    fn error<T>(&self, expected: &'static str) -> Result<T, QueryError> {
+        Err(QueryError { position: self.position, expected, found: self.peek() })
+    }
+
+    fn eat(&mut self, expected: &str) -> bool {
+        let found = self.source[self.position..].starts_with(expected);
+        if found {
+            self.position += expected.len();
+        }
+        found
+    }
+
+    fn expect(&mut self, expected: &'static str, description: &'static str) -> Result<(), QueryError> {
+        if self.eat(expected) {
+            Ok(())
+        } else {
+            self.error(description)
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let start = self.position;
+        while self.peek().is_some_and(&predicate) {
+            self.bump();
+        }
+        &self.source[start..self.position]
+    }
+
+    fn name(&mut self) -> Result<String, QueryError> {
+        match self.take_while(|c| c.is_alphanumeric() || c == '_' || c == '-') {
+            "" => self.error("a member name"),
+            name => Ok(name.to_owned()),
+        }
+    }
+
+    fn quoted(&mut self) -> Result<String, QueryError> {
+        let quote = self.bump().unwrap_or_default();
+        let mut string = String::new();
+        loop {
+            match self.bump() {
+                Some(c) if c == quote => return Ok(string),
+                Some('\\') => match self.bump() {
+                    Some(escaped) => string.push(escaped),
+                    None => return self.error("an escaped character"),
+                },
+                Some(c) => string.push(c),
+                None => return self.error("a closing quote"),
+            }
+        }
+    }
+
+    fn index(&mut self) -> Result<i64, QueryError> {
+        let start = self.position;
+        self.eat("-");
+        self.take_while(|c| c.is_ascii_digit());
+        match self.source[start..self.position].parse() {
+            Ok(index) => Ok(index),
+            Err(_) => {
+                self.position = start;
+                self.error("an index")
+            }
+        }
+    }
+
+    fn literal(&mut self) -> Result<Value, QueryError> {
+        match self.peek() {
+            Some('\'') | Some('"') => self.quoted().map(Value::String),
+            _ if self.eat("true") => Ok(Value::Bool(true)),
+            _ if self.eat("false") => Ok(Value::Bool(false)),
+            _ if self.eat("null") => Ok(Value::Null),
+            _ => {
+                let start = self.position;
+                let number = self.take_while(|c| c.is_ascii_digit() || "+-.eE".contains(c));
+                match serde_json::from_str::<Value>(number) {
+                    Ok(number @ Value::Number(_)) => Ok(number),
+                    _ => {
+                        self.position = start;
+                        self.error("a literal")
+                    }
+                }
+            }
+        }
+    }
+
+    fn comparison(&mut self) -> Result<Comparison, QueryError> {
+        let operators = [
+            ("==", Comparison::Equal),
+            ("!=", Comparison::NotEqual),
+            ("<=", Comparison::LessOrEqual),
+            (">=", Comparison::GreaterOrEqual),
+            ("<", Comparison::Less),
+            (">", Comparison::Greater),
+        ];
+        match operators.iter().find(|(operator, _)| self.eat(operator)) {
+            Some((_, comparison)) => Ok(*comparison),
+            None => self.error("a comparison operator or `)`"),
+        }
+    }
+
+    fn filter(&mut self) -> Result<Filter, QueryError> {
+        self.skip_whitespace();
+        self.expect("@", "`@`")?;
+        let mut path = vec![];
+        loop {
+            match self.peek() {
+                Some('.') => {
+                    self.bump();
+                    path.push(Segment::Key(self.name()?));
+                }
+                Some('[') => {
+                    self.bump();
+                    path.push(match self.peek() {
+                        Some('\'') | Some('"') => Segment::Key(self.quoted()?),
+                        _ => {
+                            let start = self.position;
+                            match self.index()? {
+                                index if index >= 0 => Segment::Index(index as usize),
+                                _ => {
+                                    self.position = start;
+                                    return self.error("a quoted name or a non-negative index");
+                                }
+                            }
+                        }
+                    });
+                    self.expect("]", "`]`")?;
+                }
+                _ => break,
+            }
+        }
+        self.skip_whitespace();
+        if self.peek() == Some(')') {
+            return Ok(Filter { path, comparison: None });
+        }
+        let comparison = self.comparison()?;
+        self.skip_whitespace();
+        let literal = self.literal()?;
+        self.skip_whitespace();
+        Ok(Filter { path, comparison: Some((comparison, literal)) })
+    }
+
+    fn step(&mut self) -> Result<Step, QueryError> {
+        match self.peek() {
+            Some('.') => {
+                self.bump();
+                if self.eat("*") {
+                    Ok(Step::Wildcard)
+                } else {
+                    self.name().map(Step::Key)
+                }
+            }
+            Some('[') => {
+                self.bump();
+                let step = match self.peek() {
+                    Some('*') => {
+                        self.bump();
+                        Step::Wildcard
+                    }
+                    Some('\'') | Some('"') => Step::Key(self.quoted()?),
+                    Some('?') => {
+                        self.bump();
+                        self.expect("(", "`(`")?;
+                        let filter = self.filter()?;
+                        self.expect(")", "`)`")?;
+                        Step::Filter(filter)
+                    }
+                    Some(c) if c == '-' || c.is_ascii_digit() => Step::Index(self.index()?),
+                    _ => return self.error("`*`, a quoted name, an index, or a filter"),
+                };
+                self.expect("]", "`]`")?;
+                Ok(step)
+            }
+            _ => self.error("`.` or `[`"),
+        }
+    }
+}
+
+fn position(index: i64, length: usize) -> Option<usize> {
+    if index < 0 {
+        length.checked_sub(index.unsigned_abs() as usize)
+    } else {
+        Some(index as usize).filter(|&index| index < length)
+    }
+}
+
+impl Comparison {
+    fn holds(self, value: &Value, literal: &Value) -> bool {
+        let ordering = match (value, literal) {
+            (Value::Number(a), Value::Number(b)) => a.as_f64().partial_cmp(&b.as_f64()),
+            (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+            (a, b) if a == b => Some(Ordering::Equal),
+            _ => None,
+        };
+        match self {
+            Comparison::Equal => ordering == Some(Ordering::Equal),
+            Comparison::NotEqual => ordering != Some(Ordering::Equal),
+            Comparison::Less => ordering == Some(Ordering::Less),
+            Comparison::LessOrEqual => matches!(ordering, Some(Ordering::Less) | Some(Ordering::Equal)),
+            Comparison::Greater => ordering == Some(Ordering::Greater),
+            Comparison::GreaterOrEqual => matches!(ordering, Some(Ordering::Greater) | Some(Ordering::Equal)),
+        }
+    }
+}
+
+impl Filter {
+    fn matches(&self, candidate: &Value) -> bool {
+        match (&self.comparison, candidate.get_ref_json(&self.path)) {
+            (None, target) => target.is_some(),
+            (Some((comparison, literal)), Some(target)) => comparison.holds(target, literal),
+            (Some((comparison, _)), None) => *comparison == Comparison::NotEqual,
+        }
+    }
+}
+
+fn write_key(f: &mut Formatter, key: &str) -> FmtResult {
+    if !key.is_empty() && key.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-') {
+        write!(f, ".{}", key)
+    } else {
+        write!(f, "['{}']", key.replace('\\', "\\\\").replace('\'', "\\'"))
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(match self {
+            Comparison::Equal => "==",
+            Comparison::NotEqual => "!=",
+            Comparison::Less => "<",
+            Comparison::LessOrEqual => "<=",
+            Comparison::Greater => ">",
+            Comparison::GreaterOrEqual => ">=",
+        })
+    }
+}
+
+impl Display for Filter {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "@")?;
+        self.path.iter().try_for_each(|segment| match segment {
+            Segment::Key(key) => write_key(f, key),
+            Segment::Index(index) => write!(f, "[{}]", index),
+        })?;
+        match &self.comparison {
+            Some((comparison, Value::String(literal))) => {
+                write!(f, " {} '{}'", comparison, literal.replace('\\', "\\\\").replace('\'', "\\'"))
+            }
+            Some((comparison, literal)) => write!(f, " {} {}", comparison, literal),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Step::Key(key) => write_key(f, key),
+            Step::Index(index) => write!(f, "[{}]", index),
+            Step::Wildcard => write!(f, "[*]"),
+            Step::Filter(filter) => write!(f, "[?({})]", filter),
+        }
+    }
+}
+
+fn check(value: &Value, step: &Step, selector: usize) -> Result<(), SelectionError> {
+    let failure = match (step, value) {
+        (Step::Key(key), Value::Object(object)) if object.contains_key(key) => return Ok(()),
+        (Step::Key(_), Value::Object(_)) => SelectionErrorKind::MissingKey,
+        (Step::Key(_), other) => SelectionErrorKind::WrongKind { expected: "an object", found: kind(other) },
+        (Step::Index(index), Value::Array(array)) => match position(*index, array.len()) {
+            Some(_) => return Ok(()),
+            None => SelectionErrorKind::OutOfBounds { length: array.len() },
+        },
+        (Step::Index(_), other) => SelectionErrorKind::WrongKind { expected: "an array", found: kind(other) },
+        (Step::Wildcard | Step::Filter(_), Value::Array(_) | Value::Object(_)) => return Ok(()),
+        (_, other) => SelectionErrorKind::WrongKind { expected: "an object or an array", found: kind(other) },
+    };
+    Err(SelectionError { step: selector, selector: step.to_string(), kind: failure })
+}
+
+fn select<'a>(value: &'a Value, step: &Step) -> Vec<&'a Value> {
+    match (step, value) {
+        (Step::Key(key), Value::Object(object)) => object.get(key).into_iter().collect(),
+        (Step::Index(index), Value::Array(array)) => position(*index, array.len()).map(|index| &array[index]).into_iter().collect(),
+        (Step::Wildcard, Value::Array(array)) => array.iter().collect(),
+        (Step::Wildcard, Value::Object(object)) => object.values().collect(),
+        (Step::Filter(filter), Value::Array(array)) => array.iter().filter(|child| filter.matches(child)).collect(),
+        (Step::Filter(filter), Value::Object(object)) => object.values().filter(|child| filter.matches(child)).collect(),
+        _ => vec![],
+    }
+}
+
+fn select_mut<'a>(value: &'a mut Value, step: &Step) -> Vec<&'a mut Value> {
+    match (step, value) {
+        (Step::Key(key), Value::Object(object)) => object.get_mut(key).into_iter().collect(),
+        (Step::Index(index), Value::Array(array)) => match position(*index, array.len()) {
+            Some(index) => vec![&mut array[index]],
+            None => vec![],
+        },
+        (Step::Wildcard, Value::Array(array)) => array.iter_mut().collect(),
+        (Step::Wildcard, Value::Object(object)) => object.values_mut().collect(),
+        (Step::Filter(filter), Value::Array(array)) => array.iter_mut().filter(|child| filter.matches(child)).collect(),
+        (Step::Filter(filter), Value::Object(object)) => object.values_mut().filter(|child| filter.matches(child)).collect(),
+        _ => vec![],
+    }
+}
+
+fn remove_children(parent: &mut Value, step: &Step, removed: &mut Vec<Value>) {
+    match (step, parent) {
+        (Step::Key(key), Value::Object(object)) => removed.extend(object.remove(key)),
+        (Step::Index(index), Value::Array(array)) => removed.extend(position(*index, array.len()).map(|index| array.remove(index))),
+        (Step::Wildcard, Value::Array(array)) => removed.append(array),
+        (Step::Wildcard, Value::Object(object)) => removed.extend(mem::take(object).into_iter().map(|(_, child)| child)),
+        (Step::Filter(filter), Value::Array(array)) => {
+            let (matching, kept): (Vec<_>, _) = mem::take(array).into_iter().partition(|child| filter.matches(child));
+            *array = kept;
+            removed.extend(matching);
+        }
+        (Step::Filter(filter), Value::Object(object)) => {
+            let (matching, kept): (Vec<_>, Vec<_>) = mem::take(object).into_iter().partition(|(_, child)| filter.matches(child));
+            *object = kept.into_iter().collect();
+            removed.extend(matching.into_iter().map(|(_, child)| child));
+        }
+        _ => {}
+    }
+}
+
+fn walk_mut<'a>(steps: &[Step], value: &'a mut Value) -> Vec<&'a mut Value> {
+    steps.iter().fold(vec![value], |nodes, step| {
+        nodes.into_iter().flat_map(|node| select_mut(node, step)).collect()
+    })
+}
+
+impl Query {
+    pub fn parse(query: &str) -> Result<Self, QueryError> {
+        let mut parser = Parser { source: query, position: 0 };
+        parser.expect("$", "`$`")?;
+        let mut steps = vec![];
+        while parser.peek().is_some() {
+            steps.push(parser.step()?);
+        }
+        Ok(Query { steps })
+    }
+
+    pub fn get<'a>(&self, value: &'a Value) -> Vec<&'a Value> {
+        self.steps.iter().fold(vec![value], |nodes, step| {
+            nodes.into_iter().flat_map(|node| select(node, step)).collect()
+        })
+    }
+
+    pub fn get_mut<'a>(&self, value: &'a mut Value) -> Vec<&'a mut Value> {
+        walk_mut(&self.steps, value)
+    }
+
+    /// Like [`get`], but fails on the first selector that finds no member or element, or a value of the wrong kind. Wildcards and filters that match no child are not failures.
+    ///
+    /// [`get`]: struct.Query.html#method.get
+    pub fn try_get<'a>(&self, value: &'a Value) -> Result<Vec<&'a Value>, SelectionError> {
+        self.steps.iter().enumerate().try_fold(vec![value], |nodes, (selector, step)| {
+            nodes.into_iter()
+                .map(|node| check(node, step, selector).map(|_| select(node, step)))
+                .collect::<Result<Vec<_>, _>>()
+                .map(|selected| selected.into_iter().flatten().collect())
+        })
+    }
+
+    /// The mutable counterpart of [`try_get`].
+    ///
+    /// [`try_get`]: struct.Query.html#method.try_get
+    pub fn try_get_mut<'a>(&self, value: &'a mut Value) -> Result<Vec<&'a mut Value>, SelectionError> {
+        self.steps.iter().enumerate().try_fold(vec![value], |nodes, (selector, step)| {
+            nodes.into_iter()
+                .map(|node| check(node, step, selector).map(move |_| select_mut(node, step)))
+                .collect::<Result<Vec<_>, _>>()
+                .map(|selected| selected.into_iter().flatten().collect())
+        })
+    }
+
+    pub fn set(&self, mut value: Value, new_value: Value) -> Value {
+        self.get_mut(&mut value)
+            .into_iter()
+            .for_each(|target| *target = new_value.clone());
+        value
+    }
+
+    pub fn modify(&self, mut value: Value, mut f: impl FnMut(Value) -> Value) -> Value {
+        self.get_mut(&mut value)
+            .into_iter()
+            .for_each(|target| *target = f(target.take()));
+        value
+    }
+
+    /// Removes the selected values from their parents, returns the updated document and the removed values. The root (`$`) is never removed.
+    pub fn remove(&self, mut value: Value) -> (Value, Vec<Value>) {
+        let mut removed = vec![];
+        if let Some((last, parents)) = self.steps.split_last() {
+            walk_mut(parents, &mut value)
+                .into_iter()
+                .for_each(|parent| remove_children(parent, last, &mut removed));
+        }
+        (value, removed)
+    }
+}
+
+impl FromStr for Query {
+    type Err = QueryError;
+
+    fn from_str(query: &str) -> Result<Self, Self::Err> {
+        Query::parse(query)
+    }
+}
+
+impl Display for Query {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "$")?;
+        self.steps.iter().try_for_each(|step| write!(f, "{}", step))
+    }
+}
@@ -0,0 +1,302 @@
+use crate::*;
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+
+/// Property-based law checks with random inputs generated by `proptest` (requires the `proptest` feature).
+#[cfg(feature = "proptest")]
+pub mod proptest;
+
+/// Property-based law checks with random inputs generated by `quickcheck` (requires the `quickcheck` feature).
+#[cfg(feature = "quickcheck")]
+pub mod quickcheck;
+
+/// The laws which can be checked by the functions of this module.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Law {
+    /// Setting the value just read leaves the container unchanged: `set(s, get(s)) == s`.
+    GetSet,
+    /// Reading a value just set returns that value: `get(set(s, a)) == a`.
+    SetGet,
+    /// Setting twice is the same as setting the second value only: `set(set(s, a), b) == set(s, b)`.
+    SetSet,
+    /// Modifying with the identity function leaves the container unchanged: `modify(s, id) == s`.
+    ModifyIdentity,
+    /// Modifying twice is the same as modifying once with the composed function: `modify(modify(s, f), g) == modify(s, g . f)`.
+    ModifyComposition,
+    /// A value built by a prism can be matched by it: `preview(review(a)) == Some(a)`.
+    PreviewReview,
+    /// Building back a matched value gives the original container: `preview(s) == Some(a)` implies `review(a) == s`.
+    ReviewPreview,
+    /// Converting there and back leaves the container unchanged: `from(to(s)) == s`.
+    IsoRoundTrip,
+    /// Converting back and there leaves the target unchanged: `to(from(a)) == a`.
+    IsoReverseRoundTrip,
+}
+
+/// A failed law check: the law, and the expected and actual values (formatted with `Debug`) which should have been equal.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Violation {
+    pub law: Law,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:?} law violated: expected {}, got {}", self.law, self.expected, self.actual)
+    }
+}
+
+fn expect_equal<T>(law: Law, expected: T, actual: T) -> Result<(), Violation>
+    where T: Debug + PartialEq {
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(Violation { law, expected: format!("{:?}", expected), actual: format!("{:?}", actual) })
+    }
+}
+
+fn lens_with<S, A>(
+    source: S,
+    first: A,
+    second: A,
+    get: impl Fn(S) -> A,
+    set: impl Fn(S, A) -> S,
+) -> Vec<Violation>
+    where S: Clone + Debug + PartialEq,
+          A: Clone + Debug + PartialEq {
+    vec![
+        expect_equal(Law::GetSet, source.clone(), set(source.clone(), get(source.clone()))),
+        expect_equal(Law::SetGet, first.clone(), get(set(source.clone(), first.clone()))),
+        expect_equal(Law::SetSet, set(source.clone(), second.clone()), set(set(source, first), second)),
+    ]
+        .into_iter()
+        .filter_map(Result::err)
+        .collect()
+}
+
+fn prism_with<S, A>(source: S, value: A, preview: impl Fn(S) -> Option<A>, review: impl Fn(A) -> S) -> Vec<Violation>
+    where S: Clone + Debug + PartialEq,
+          A: Clone + Debug + PartialEq {
+    let review_preview = match preview(source.clone()) {
+        Some(matched) => expect_equal(Law::ReviewPreview, source, review(matched)),
+        None => Ok(()),
+    };
+
+    vec![
+        expect_equal(Law::PreviewReview, Some(value.clone()), preview(review(value))),
+        review_preview,
+    ]
+        .into_iter()
+        .filter_map(Result::err)
+        .collect()
+}
+
+/// Checks the get-set law of a lens (see [`Law::GetSet`]).
+///
+/// [`Law::GetSet`]: enum.Law.html#variant.GetSet
+pub fn get_set<S, A>(source: S) -> Result<(), Violation>
+    where S: Get<A> + Set<A> + Clone + Debug + PartialEq {
+    expect_equal(Law::GetSet, source.clone(), source.clone().set(source.get()))
+}
+
+/// Checks the set-get law of a lens (see [`Law::SetGet`]).
+///
+/// [`Law::SetGet`]: enum.Law.html#variant.SetGet
+pub fn set_get<S, A>(source: S, value: A) -> Result<(), Violation>
+    where S: Get<A> + Set<A>,
+          A: Clone + Debug + PartialEq {
+    expect_equal(Law::SetGet, value.clone(), source.set(value).get())
+}
+
+/// Checks the set-set law of a lens (see [`Law::SetSet`]).
+///
+/// [`Law::SetSet`]: enum.Law.html#variant.SetSet
+pub fn set_set<S, A>(source: S, first: A, second: A) -> Result<(), Violation>
+    where S: Set<A> + Clone + Debug + PartialEq,
+          A: Clone {
+    expect_equal(Law::SetSet, source.clone().set(second.clone()), source.set(first).set(second))
+}
+
+/// Checks all the lens laws for the [`Get`] and [`Set`] instances of a container, returning the violations.
+///
+/// [`Get`]: ../focus/trait.Get.html
+/// [`Set`]: ../focus/trait.Set.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// use photonix::laws::{self, Law};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// pub struct Counter { pub count: u8, pub history: Vec<u8> }
+///
+/// impl Get<u8> for Counter {
+///     fn get(self) -> u8 { self.count }
+/// }
+///
+/// // This setter is not lawful, it records the history
+/// impl Set<u8> for Counter {
+///     fn set(mut self, new_value: u8) -> Self {
+///         self.history.push(self.count);
+///         self.count = new_value;
+///         self
+///     }
+/// }
+///
+/// let counter = Counter { count: 1, history: vec![] };
+///
+/// let violated: Vec<Law> = laws::lens(counter, 2u8, 3u8).into_iter().map(|v| v.law).collect();
+///
+/// assert_eq!(vec![Law::GetSet, Law::SetSet], violated);
+///```
+pub fn lens<S, A>(source: S, first: A, second: A) -> Vec<Violation>
+    where S: Get<A> + Set<A> + Clone + Debug + PartialEq,
+          A: Clone + Debug + PartialEq {
+    lens_with(source, first, second, Get::get, Set::set)
+}
+
+/// Checks all the lens laws at the end of a type-level path (e.g. a composite several levels deep), returning the violations.
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// #[derive(Clone, Debug, Get, GetRef, Set, Modify, PartialEq)]
+/// pub struct Employee { pub name: String, pub company: Company }
+///
+/// #[derive(Clone, Debug, Get, GetRef, Set, Modify, PartialEq)]
+/// pub struct Company { pub name: String, pub headcount: u32 }
+///
+/// let john_doe = Employee {
+///     name: String::from("John Doe"),
+///     company: Company { name: String::from("Acme Corporation"), headcount: 100 },
+/// };
+///
+/// assert!(photonix::laws::lens_at::<path!(Company, u32), _, _>(john_doe, 1, 2).is_empty());
+///```
+pub fn lens_at<P, S, A>(source: S, first: A, second: A) -> Vec<Violation>
+    where S: GetPath<P, Target = A> + SetPath<P, Target = A> + Clone + Debug + PartialEq,
+          A: Clone + Debug + PartialEq {
+    lens_with(source, first, second, GetPath::get_path, SetPath::set_path)
+}
+
+/// Checks the identity law of [`Modify`] (see [`Law::ModifyIdentity`]).
+///
+/// [`Modify`]: ../focus/trait.Modify.html
+/// [`Law::ModifyIdentity`]: enum.Law.html#variant.ModifyIdentity
+pub fn modify_identity<S, A>(source: S) -> Result<(), Violation>
+    where S: Modify<A> + Clone + Debug + PartialEq {
+    expect_equal(Law::ModifyIdentity, source.clone(), source.modify(|value: A| value))
+}
+
+/// Checks the composition law of [`Modify`] with the given functions (see [`Law::ModifyComposition`]).
+///
+/// [`Modify`]: ../focus/trait.Modify.html
+/// [`Law::ModifyComposition`]: enum.Law.html#variant.ModifyComposition
+pub fn modify_composition<S, A>(source: S, f: impl Fn(A) -> A, g: impl Fn(A) -> A) -> Result<(), Violation>
+    where S: Modify<A> + Clone + Debug + PartialEq {
+    expect_equal(
+        Law::ModifyComposition,
+        source.clone().modify(|value| g(f(value))),
+        source.modify(&f).modify(&g),
+    )
+}
+
+/// Checks both laws of [`Modify`], returning the violations.
+///
+/// [`Modify`]: ../focus/trait.Modify.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// #[derive(Clone, Debug, Modify, PartialEq)]
+/// pub struct Score { pub points: i32 }
+///
+/// assert!(photonix::laws::modify(Score { points: 3 }, |p: i32| p * 2, |p| p - 1).is_empty());
+///```
+pub fn modify<S, A>(source: S, f: impl Fn(A) -> A, g: impl Fn(A) -> A) -> Vec<Violation>
+    where S: Modify<A> + Clone + Debug + PartialEq {
+    vec![modify_identity::<S, A>(source.clone()), modify_composition(source, f, g)]
+        .into_iter()
+        .filter_map(Result::err)
+        .collect()
+}
+
+/// Checks the preview-review law of a prism (see [`Law::PreviewReview`]).
+///
+/// [`Law::PreviewReview`]: enum.Law.html#variant.PreviewReview
+pub fn preview_review<S, A>(value: A) -> Result<(), Violation>
+    where S: GetOption<A> + ReverseGet<A>,
+          A: Clone + Debug + PartialEq {
+    expect_equal(Law::PreviewReview, Some(value.clone()), S::reverse_get(value).get_option())
+}
+
+/// Checks the review-preview law of a prism (see [`Law::ReviewPreview`]). Containers not matched by the prism pass trivially.
+///
+/// [`Law::ReviewPreview`]: enum.Law.html#variant.ReviewPreview
+pub fn review_preview<S, A>(source: S) -> Result<(), Violation>
+    where S: GetOption<A> + ReverseGet<A> + Clone + Debug + PartialEq {
+    match source.clone().get_option() {
+        Some(value) => expect_equal(Law::ReviewPreview, source, S::reverse_get(value)),
+        None => Ok(()),
+    }
+}
+
+/// Checks both prism laws for the [`GetOption`] and [`ReverseGet`] instances of a container, returning the violations.
+///
+/// [`GetOption`]: ../focus/trait.GetOption.html
+/// [`ReverseGet`]: ../focus/trait.ReverseGet.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// #[derive(Clone, Debug, GetOption, PartialEq)]
+/// pub enum Reading { Celsius(f64), Offline }
+///
+/// impl ReverseGet<f64> for Reading {
+///     fn reverse_get(value: f64) -> Self { Reading::Celsius(value) }
+/// }
+///
+/// assert!(photonix::laws::prism(Reading::Offline, 21.5).is_empty());
+/// assert!(photonix::laws::prism(Reading::Celsius(-4.0), 21.5).is_empty());
+///```
+pub fn prism<S, A>(source: S, value: A) -> Vec<Violation>
+    where S: GetOption<A> + ReverseGet<A> + Clone + Debug + PartialEq,
+          A: Clone + Debug + PartialEq {
+    prism_with(source, value, GetOption::get_option, ReverseGet::reverse_get)
+}
+
+/// Checks both prism laws at the end of a type-level path (e.g. a composite several levels deep), returning the violations.
+pub fn prism_at<P, S, A>(source: S, value: A) -> Vec<Violation>
+    where S: GetOptionPath<P, Target = A> + ReverseGetPath<P, Target = A> + Clone + Debug + PartialEq,
+          A: Clone + Debug + PartialEq {
+    prism_with(source, value, GetOptionPath::get_option_path, ReverseGetPath::reverse_get_path)
+}
+
+/// Checks both round trips of an [`Iso`], returning the violations.
+///
+/// [`Iso`]: ../focus/trait.Iso.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// #[derive(Clone, Debug, PartialEq)]
+/// pub struct Meters(f64);
+///
+/// impl Iso<f64> for Meters {
+///     fn to(self) -> f64 { self.0 }
+///     fn from(target: f64) -> Self { Meters(target) }
+/// }
+///
+/// assert!(photonix::laws::iso(Meters(42195.0), 100.0).is_empty());
+///```
+pub fn iso<S, A>(source: S, value: A) -> Vec<Violation>
+    where S: Iso<A> + Clone + Debug + PartialEq,
+          A: Clone + Debug + PartialEq {
+    vec![
+        expect_equal(Law::IsoRoundTrip, source.clone(), <S as Iso<A>>::from(source.to())),
+        expect_equal(Law::IsoReverseRoundTrip, value.clone(), <S as Iso<A>>::from(value).to()),
+    ]
+        .into_iter()
+        .filter_map(Result::err)
+        .collect()
+}
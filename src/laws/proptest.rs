@@ -0,0 +1,66 @@
+use crate::*;
+use ::proptest::{
+    arbitrary::{any, Arbitrary},
+    test_runner::{TestCaseError, TestRunner},
+};
+use std::{any::type_name, fmt::Debug};
+
+fn outcome(optic: &str, violations: Vec<super::Violation>) -> Result<(), TestCaseError> {
+    match violations.first() {
+        None => Ok(()),
+        Some(violation) => Err(TestCaseError::fail(format!("{}: {}", optic, violation))),
+    }
+}
+
+/// Runs the lens laws on random containers and values, using the [`Get`] and [`Set`] instances of `S`. On failure, it panics with the name of the optic, the violated law, and the minimal failing input found by shrinking.
+///
+/// The number of cases can be configured with the `PROPTEST_CASES` environment variable.
+///
+/// [`Get`]: ../../focus/trait.Get.html
+/// [`Set`]: ../../focus/trait.Set.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// use photonix::laws::proptest::check_lens;
+///
+/// check_lens::<Box<u32>, u32>();
+///```
+pub fn check_lens<S, A>()
+    where S: Arbitrary + Get<A> + Set<A> + Clone + Debug + PartialEq,
+          A: Arbitrary + Clone + Debug + PartialEq {
+    let optic = format!("lens `{}` => `{}`", type_name::<S>(), type_name::<A>());
+
+    if let Err(error) = TestRunner::default().run(&(any::<S>(), any::<A>(), any::<A>()), |(source, first, second)| {
+        outcome(&optic, super::lens(source, first, second))
+    }) {
+        panic!("{}", error);
+    }
+}
+
+/// Runs the prism laws on random containers and values, using the [`GetOption`] and [`ReverseGet`] instances of `S`. On failure, it panics with the name of the optic, the violated law, and the minimal failing input found by shrinking.
+///
+/// The number of cases can be configured with the `PROPTEST_CASES` environment variable.
+///
+/// [`GetOption`]: ../../focus/trait.GetOption.html
+/// [`ReverseGet`]: ../../focus/trait.ReverseGet.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// use photonix::laws::proptest::check_prism;
+///
+/// check_prism::<Option<String>, String>();
+/// check_prism::<Result<i8, bool>, i8>();
+///```
+pub fn check_prism<S, A>()
+    where S: Arbitrary + GetOption<A> + ReverseGet<A> + Clone + Debug + PartialEq,
+          A: Arbitrary + Clone + Debug + PartialEq {
+    let optic = format!("prism `{}` => `{}`", type_name::<S>(), type_name::<A>());
+
+    if let Err(error) = TestRunner::default().run(&(any::<S>(), any::<A>()), |(source, value)| {
+        outcome(&optic, super::prism(source, value))
+    }) {
+        panic!("{}", error);
+    }
+}
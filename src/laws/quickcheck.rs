@@ -0,0 +1,63 @@
+use crate::*;
+use ::quickcheck::{Arbitrary, QuickCheck, TestResult};
+use std::{any::type_name, fmt::Debug};
+
+fn outcome(optic: String, violations: Vec<super::Violation>) -> TestResult {
+    match violations.first() {
+        None => TestResult::passed(),
+        Some(violation) => TestResult::error(format!("{}: {}", optic, violation)),
+    }
+}
+
+fn lens_property<S, A>(source: S, first: A, second: A) -> TestResult
+    where S: Get<A> + Set<A> + Clone + Debug + PartialEq,
+          A: Clone + Debug + PartialEq {
+    outcome(format!("lens `{}` => `{}`", type_name::<S>(), type_name::<A>()), super::lens(source, first, second))
+}
+
+fn prism_property<S, A>(source: S, value: A) -> TestResult
+    where S: GetOption<A> + ReverseGet<A> + Clone + Debug + PartialEq,
+          A: Clone + Debug + PartialEq {
+    outcome(format!("prism `{}` => `{}`", type_name::<S>(), type_name::<A>()), super::prism(source, value))
+}
+
+/// Runs the lens laws on random containers and values, using the [`Get`] and [`Set`] instances of `S`. On failure, it panics with the name of the optic, the violated law, and the shrunk arguments.
+///
+/// The number of cases can be configured with the `QUICKCHECK_TESTS` environment variable.
+///
+/// [`Get`]: ../../focus/trait.Get.html
+/// [`Set`]: ../../focus/trait.Set.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// use photonix::laws::quickcheck::check_lens;
+///
+/// check_lens::<Box<u32>, u32>();
+///```
+pub fn check_lens<S, A>()
+    where S: Arbitrary + Get<A> + Set<A> + Debug + PartialEq,
+          A: Arbitrary + Debug + PartialEq {
+    QuickCheck::new().quickcheck(lens_property::<S, A> as fn(S, A, A) -> TestResult);
+}
+
+/// Runs the prism laws on random containers and values, using the [`GetOption`] and [`ReverseGet`] instances of `S`. On failure, it panics with the name of the optic, the violated law, and the shrunk arguments.
+///
+/// The number of cases can be configured with the `QUICKCHECK_TESTS` environment variable.
+///
+/// [`GetOption`]: ../../focus/trait.GetOption.html
+/// [`ReverseGet`]: ../../focus/trait.ReverseGet.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// use photonix::laws::quickcheck::check_prism;
+///
+/// check_prism::<Option<String>, String>();
+/// check_prism::<Result<i8, bool>, i8>();
+///```
+pub fn check_prism<S, A>()
+    where S: Arbitrary + GetOption<A> + ReverseGet<A> + Debug + PartialEq,
+          A: Arbitrary + Debug + PartialEq {
+    QuickCheck::new().quickcheck(prism_property::<S, A> as fn(S, A) -> TestResult);
+}
@@ -4,14 +4,18 @@
 //!
 //! This approach has some consequences. First of all, you won't need to store lens objects in memory. Second, in order to have the functionality, you won’t need to define and handle closures. Third, composition is achieved in a different way compared to traditional lenses (which rely on function composition).
 //!
-//! The **composition** feature is provided by additional traits defined in the [`composites`] module. By using composite traits, you can reach several levels deep in the implementing data structure. These traits have default implementation, so if you want to use them, all you need to do is write an empty impl block (or use the [`zoom!`] or [`zoom_all!`] macro).
+//! The **composition** feature is provided by additional traits defined in the [`composites`] module. By using composite traits, you can reach several levels deep in the implementing data structure. These traits have default implementation, so if you want to use them, all you need to do is write an empty impl block (or use the [`zoom!`], [`zoom_all!`], or [`zoom_option!`] macro).
 //!
-//! The real power of **photonix**, however, lies in its metaprogramming  features. The crate comes with auto-derives ([`photonix_derive`]) for most of the base traits it defines. The implementations of the auto-derives avoid cloning data by default. Furthermore, the [`zoom!`] and [`zoom_all!`] macros can help you get the implementation of multiple composite traits in a concise, straightforward, and readable way.
+//! The real power of **photonix**, however, lies in its metaprogramming  features. The crate comes with auto-derives ([`photonix_derive`]) for most of the base traits it defines. The implementations of the auto-derives avoid cloning data by default. Furthermore, the [`zoom!`] and [`zoom_all!`] macros can help you get the implementation of multiple composite traits in a concise, straightforward, and readable way. For types the auto-derives can't handle, the [`lens!`], [`prism!`], and [`fields!`] macros implement the base traits declaratively.
 //!
 //! [`composites`]: focus/composites/index.html
 //! [`photonix_derive`]: https://docs.rs/photonix_derive/0.1.1/photonix_derive/
 //! [`zoom!`]: macro.zoom.html
 //! [`zoom_all!`]: macro.zoom_all.html
+//! [`zoom_option!`]: macro.zoom_option.html
+//! [`lens!`]: macro.lens.html
+//! [`prism!`]: macro.prism.html
+//! [`fields!`]: macro.fields.html
 //!
 //! # Examples
 //! A quick example (inspired by [`Monocle`]).
@@ -83,12 +87,40 @@
 /// Type classes for getters and setters.
 pub mod focus;
 
-/// Relevant optics type class instances for common types (currently for `Option` only).
+/// Relevant optics type class instances for common types (`Option`, `Result`, smart pointers, and standard collections).
 pub mod implementations;
 
+/// First-class optic values (lenses, prisms, traversals, and computed lenses) built on the type classes.
+pub mod optics;
+
+/// Checkers for the lens, prism, iso, and modify laws, returning the violations instead of panicking (requires the `laws` feature). With the `proptest` or `quickcheck` feature, the laws can also be checked on random inputs.
+#[cfg(feature = "laws")]
+pub mod laws;
+
+/// Optics for `serde_json::Value` (requires the `serde_json` feature): prisms for the variants (with `f64` covering every number, and `()` standing for `null`), [`GetKey`] and [`At`] by object key, [`GetIndex`] by array position, and runtime paths ([`ValuePath`]) built with the [`json_path!`] macro.
+///
+/// Setting a number that JSON can't represent (`NaN` or infinity) through the `f64` prism returns `None`. Keys and positions are only found in objects and arrays respectively, other values are left untouched.
+///
+/// Paths coming from strings are parsed into runtime traversals: [`Pointer`] for JSON Pointers (RFC 6901), and [`Query`] for a subset of JSONPath with wildcards and filters.
+///
+/// Enabling the feature can break type inference in code that doesn't touch JSON at all. `serde_json` implements `PartialEq<Value>` for the primitive types, so comparing a literal with a generic result, as in `assert_eq!(42u8, person.get())` or `assert_eq!(60u32, items.get_all().sum())`, no longer pins down the target type, and the compiler asks for annotations. Name the target type in such cases, e.g. `let age: u8 = person.get();`, `Get::<u8>::get(person)`, or `.sum::<u32>()`.
+///
+/// [`GetKey`]: focus/trait.GetKey.html
+/// [`At`]: focus/trait.At.html
+/// [`GetIndex`]: focus/trait.GetIndex.html
+/// [`ValuePath`]: json/trait.ValuePath.html
+/// [`json_path!`]: macro.json_path.html
+/// [`Pointer`]: json/struct.Pointer.html
+/// [`Query`]: json/struct.Query.html
+#[cfg(feature = "serde_json")]
+pub mod json;
+
 pub use focus::{
     *,
     composites::*,
 };
 pub use implementations::*;
+#[cfg(feature = "serde_json")]
+pub use json::*;
+pub use optics::*;
 pub use photonix_derive::*;
@@ -0,0 +1,357 @@
+use crate::*;
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    marker::PhantomData,
+};
+
+/// A first-class lens focusing on a field of type `A` in `S`, reaching it along the type-level path `P` (see [`path!`]).
+///
+/// Lenses are zero-sized values, which can be stored in collections and struct fields, or passed around to pick a field at runtime. They don't need any extra implementation: `get`, `get_ref`, `set`, and `modify` use the [`Get`], [`GetRef`], [`Set`], and [`Modify`] instances along the path.
+///
+/// [`path!`]: ../macro.path.html
+/// [`Get`]: ../focus/trait.Get.html
+/// [`GetRef`]: ../focus/trait.GetRef.html
+/// [`Set`]: ../focus/trait.Set.html
+/// [`Modify`]: ../focus/trait.Modify.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// #[derive(Debug, Get, GetRef, Set, Modify, PartialEq)]
+/// pub struct Employee { pub name: String, pub company: Company }
+///
+/// #[derive(Debug, Get, GetRef, Set, Modify, PartialEq)]
+/// pub struct Company { pub name: String, pub founded: u16 }
+///
+/// let john_doe = || Employee {
+///     name: String::from("John Doe"),
+///     company: Company { name: String::from("Acme Corporation"), founded: 1990 },
+/// };
+///
+/// let company = Lens::<Employee, Company>::new();
+/// let company_name = company.then(Lens::<Company, String>::new());
+///
+/// assert_eq!("Acme Corporation", company_name.get_ref(&john_doe()).as_str());
+///
+/// let renamed = company_name.modify(john_doe(), |name| name.replace("Corporation", "Corp"));
+///
+/// assert_eq!("Acme Corp", company_name.get(renamed));
+///
+/// // Lenses with different paths can be collected as trait objects, e.g. for picking a column at runtime
+///
+/// let name = Lens::<Employee, String>::new();
+/// let columns: Vec<&dyn DynLens<Employee, String>> = vec![&name, &company_name];
+/// let row = john_doe();
+///
+/// assert_eq!(
+///     vec!["John Doe", "Acme Corporation"],
+///     columns.iter().map(|column| column.get_ref(&row).as_str()).collect::<Vec<_>>()
+/// );
+///
+///```
+pub struct Lens<S, A, P = Path<A, End>>(PhantomData<fn(S) -> (A, P)>);
+
+/// A first-class prism focusing on a variant of type `A` in `S`, reaching it along the type-level path `P` (see [`path!`]).
+///
+/// Prisms are zero-sized values, which can be stored in collections and struct fields. `preview`, `review`, `set`, and `modify` use the [`GetOption`], [`ReverseGet`], [`SetOption`], and [`ModifyOption`] instances along the path.
+///
+/// [`path!`]: ../macro.path.html
+/// [`GetOption`]: ../focus/trait.GetOption.html
+/// [`ReverseGet`]: ../focus/trait.ReverseGet.html
+/// [`SetOption`]: ../focus/trait.SetOption.html
+/// [`ModifyOption`]: ../focus/trait.ModifyOption.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// #[derive(Debug, GetOption, SetOption, ModifyOption, PartialEq)]
+/// pub enum Shape { Circle(Circle), Point }
+///
+/// #[derive(Debug, GetOption, SetOption, ModifyOption, PartialEq)]
+/// pub enum Circle { Radius(f64), Unknown }
+///
+/// impl ReverseGet<Circle> for Shape {
+///     fn reverse_get(value: Circle) -> Self { Shape::Circle(value) }
+/// }
+///
+/// impl ReverseGet<f64> for Circle {
+///     fn reverse_get(value: f64) -> Self { Circle::Radius(value) }
+/// }
+///
+/// let radius = Prism::<Shape, Circle>::new().then(Prism::<Circle, f64>::new());
+///
+/// assert_eq!(Some(2.0), radius.preview(Shape::Circle(Circle::Radius(2.0))));
+/// assert_eq!(None, radius.preview(Shape::Point));
+///
+/// assert_eq!(Shape::Circle(Circle::Radius(1.5)), radius.review(1.5));
+///
+/// assert_eq!(
+///     Some(Shape::Circle(Circle::Radius(4.0))),
+///     radius.modify(Shape::Circle(Circle::Radius(2.0)), |r| r * 2.0)
+/// );
+///
+/// assert_eq!(None, radius.set(Shape::Circle(Circle::Unknown), 1.0));
+///
+///```
+pub struct Prism<S, A, P = Path<A, End>>(PhantomData<fn(S) -> (A, P)>);
+
+/// A first-class traversal focusing on all the values of type `A` in `S`, reaching them along the type-level path `P` (see [`path!`]).
+///
+/// Traversals are zero-sized values, which can be stored in collections and struct fields. `get_all`, `set`, and `modify` use the [`GetAll`] and [`ModifyAll`] instances along the path.
+///
+/// [`path!`]: ../macro.path.html
+/// [`GetAll`]: ../focus/trait.GetAll.html
+/// [`ModifyAll`]: ../focus/trait.ModifyAll.html
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// let matrix = Traversal::<Vec<Vec<u8>>, Vec<u8>>::new().then(Traversal::<Vec<u8>, u8>::new());
+///
+/// let m = || vec![vec![1, 2], vec![3]];
+///
+/// assert_eq!(6u8, matrix.get_all(&m()).sum::<u8>());
+/// assert_eq!(vec![vec![2, 4], vec![6]], matrix.modify(m(), |n| n * 2));
+/// assert_eq!(vec![vec![0, 0], vec![0]], matrix.set(m(), 0));
+///
+///```
+pub struct Traversal<S, A, P = Path<A, End>>(PhantomData<fn(S) -> (A, P)>);
+
+/// A computed (virtual) lens, focusing on a value of type `A` derived from `S` by a getter function, and written back by a setter function. Unlike [`Lens`], it doesn't need a field: it can convert units, span several fields, or change representation.
+///
+/// The [`computed!`] macro defines a computed lens as a constant, and implements [`Get`], [`Set`], and [`Modify`] with it. Since a getter/setter pair is only a lens if it obeys the lens laws, [`check`] and [`check_set_get`] look for counterexamples.
+///
+/// [`Lens`]: struct.Lens.html
+/// [`computed!`]: ../macro.computed.html
+/// [`Get`]: ../focus/trait.Get.html
+/// [`Set`]: ../focus/trait.Set.html
+/// [`Modify`]: ../focus/trait.Modify.html
+/// [`check`]: #method.check
+/// [`check_set_get`]: #method.check_set_get
+///
+/// # Examples
+///```
+/// # use photonix::*;
+/// #[derive(Clone, Debug, PartialEq)]
+/// pub struct Person { pub first_name: String, pub last_name: String }
+///
+/// let full_name = Computed::new(
+///     |person: &Person| format!("{} {}", person.first_name, person.last_name),
+///     |_, full_name: String| {
+///         let (first_name, last_name) = full_name.split_once(' ').unwrap_or((&full_name, ""));
+///         Person { first_name: first_name.to_string(), last_name: last_name.to_string() }
+///     },
+/// );
+///
+/// let person = |first_name: &str, last_name: &str| Person {
+///     first_name: first_name.to_string(),
+///     last_name: last_name.to_string(),
+/// };
+///
+/// assert_eq!("John Doe", full_name.get(&person("John", "Doe")));
+///
+/// // The pair breaks the get-set law when the first name contains a space
+///
+/// assert_eq!(
+///     Err(person("Mary Ann", "Smith")),
+///     full_name.check(vec![person("John", "Doe"), person("Mary Ann", "Smith")])
+/// );
+///
+///```
+pub struct Computed<S, A> {
+    getter: fn(&S) -> A,
+    setter: fn(S, A) -> S,
+}
+
+/// Object-safe counterpart of [`Lens`], which lets lenses with different paths (but the same source and target types) live in the same collection.
+///
+/// [`Lens`]: struct.Lens.html
+pub trait DynLens<S, A> {
+    fn get_ref<'a>(&self, source: &'a S) -> &'a A;
+
+    fn set(&self, source: S, new_value: A) -> S;
+
+    fn modify(&self, source: S, f: &mut dyn FnMut(A) -> A) -> S;
+}
+
+macro_rules! optic_value {
+    ($optic:ident) => {
+        impl<S, A, P> $optic<S, A, P> {
+            pub fn new() -> Self {
+                $optic(PhantomData)
+            }
+        }
+
+        impl<S, A, P> Clone for $optic<S, A, P> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<S, A, P> Copy for $optic<S, A, P> {}
+
+        impl<S, A, P> Default for $optic<S, A, P> {
+            fn default() -> Self {
+                $optic::new()
+            }
+        }
+
+        impl<S, A, P> Debug for $optic<S, A, P> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+                f.write_str(stringify!($optic))
+            }
+        }
+    };
+}
+
+optic_value!(Lens);
+optic_value!(Prism);
+optic_value!(Traversal);
+
+impl<S, A, P> Lens<S, A, P> {
+    pub fn get(&self, source: S) -> A
+        where S: GetPath<P, Target = A> {
+        source.get_path()
+    }
+
+    pub fn get_ref<'a>(&self, source: &'a S) -> &'a A
+        where S: GetRefPath<'a, P, Target = A> {
+        source.get_ref_path()
+    }
+
+    pub fn set(&self, source: S, new_value: A) -> S
+        where S: SetPath<P, Target = A> {
+        source.set_path(new_value)
+    }
+
+    pub fn modify(&self, source: S, f: impl FnOnce(A) -> A) -> S
+        where S: ModifyPath<P, Target = A> {
+        source.modify_path(f)
+    }
+
+    /// Composes this lens with another one, focusing on a field of the current target.
+    pub fn then<B, Q>(self, _next: Lens<A, B, Q>) -> Lens<S, B, P::Output>
+        where P: Append<Q> {
+        Lens::new()
+    }
+}
+
+impl<S, A, P> DynLens<S, A> for Lens<S, A, P>
+    where S: for<'a> GetRefPath<'a, P, Target = A> + SetPath<P, Target = A> + ModifyPath<P, Target = A> {
+    fn get_ref<'a>(&self, source: &'a S) -> &'a A {
+        source.get_ref_path()
+    }
+
+    fn set(&self, source: S, new_value: A) -> S {
+        source.set_path(new_value)
+    }
+
+    fn modify(&self, source: S, f: &mut dyn FnMut(A) -> A) -> S {
+        source.modify_path(f)
+    }
+}
+
+impl<S, A, P> Prism<S, A, P> {
+    pub fn preview(&self, source: S) -> Option<A>
+        where S: GetOptionPath<P, Target = A> {
+        source.get_option_path()
+    }
+
+    pub fn review(&self, value: A) -> S
+        where S: ReverseGetPath<P, Target = A> {
+        S::reverse_get_path(value)
+    }
+
+    pub fn set(&self, source: S, new_value: A) -> Option<S>
+        where S: SetOptionPath<P, Target = A> {
+        source.set_option_path(new_value)
+    }
+
+    pub fn modify(&self, source: S, f: impl FnOnce(A) -> A) -> Option<S>
+        where S: ModifyOptionPath<P, Target = A> {
+        source.modify_option_path(f)
+    }
+
+    /// Composes this prism with another one, focusing on a variant of the current target.
+    pub fn then<B, Q>(self, _next: Prism<A, B, Q>) -> Prism<S, B, P::Output>
+        where P: Append<Q> {
+        Prism::new()
+    }
+}
+
+impl<S, A, P> Traversal<S, A, P> {
+    pub fn get_all<'a>(&self, source: &'a S) -> Box<dyn Iterator<Item = &'a A> + 'a>
+        where S: GetAllPath<'a, P, Target = A> {
+        source.get_all_path()
+    }
+
+    pub fn set(&self, source: S, new_value: A) -> S
+        where S: ModifyAllPath<P, Target = A>,
+              A: Clone {
+        source.modify_all_path(|_| new_value.clone())
+    }
+
+    pub fn modify(&self, source: S, f: impl FnMut(A) -> A) -> S
+        where S: ModifyAllPath<P, Target = A> {
+        source.modify_all_path(f)
+    }
+
+    /// Composes this traversal with another one, focusing on the values within each current target.
+    pub fn then<B, Q>(self, _next: Traversal<A, B, Q>) -> Traversal<S, B, P::Output>
+        where P: Append<Q> {
+        Traversal::new()
+    }
+}
+
+impl<S, A> Computed<S, A> {
+    pub const fn new(getter: fn(&S) -> A, setter: fn(S, A) -> S) -> Self {
+        Computed { getter, setter }
+    }
+
+    pub fn get(&self, source: &S) -> A {
+        (self.getter)(source)
+    }
+
+    pub fn set(&self, source: S, new_value: A) -> S {
+        (self.setter)(source, new_value)
+    }
+
+    pub fn modify(&self, source: S, f: impl FnOnce(A) -> A) -> S {
+        let new_value = f(self.get(&source));
+        self.set(source, new_value)
+    }
+
+    /// Checks the get-set law (`set(s, get(s)) == s`) on the given sources, returning the first one violating it.
+    pub fn check(&self, sources: impl IntoIterator<Item = S>) -> Result<(), S>
+        where S: Clone + PartialEq {
+        sources
+            .into_iter()
+            .find(|source| self.set(source.clone(), self.get(source)) != *source)
+            .map_or(Ok(()), Err)
+    }
+
+    /// Checks the set-get law (`get(set(s, a)) == a`) on every combination of the given sources and values, returning the first pair violating it.
+    pub fn check_set_get(&self, sources: impl IntoIterator<Item = S>, values: impl IntoIterator<Item = A> + Clone) -> Result<(), (S, A)>
+        where S: Clone,
+              A: Clone + PartialEq {
+        sources
+            .into_iter()
+            .flat_map(|source| values.clone().into_iter().map(move |value| (source.clone(), value)))
+            .find(|(source, value)| self.get(&self.set(source.clone(), value.clone())) != *value)
+            .map_or(Ok(()), Err)
+    }
+}
+
+impl<S, A> Clone for Computed<S, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S, A> Copy for Computed<S, A> {}
+
+impl<S, A> Debug for Computed<S, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("Computed")
+    }
+}
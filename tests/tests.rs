@@ -3,8 +3,13 @@ mod tests {
     use photonix_derive::*;
     use photonix::*;
     use std::{
-        collections::HashMap,
+        borrow::Cow,
+        collections::{BTreeMap, HashMap, HashSet, VecDeque},
         fmt::Debug,
+        num::Wrapping,
+        rc::Rc,
+        sync::Arc,
+        time::Duration,
     };
 
     #[derive(Debug, GetRef, Set, PartialEq)]
@@ -63,6 +68,65 @@ mod tests {
         );
     }
 
+    impl GetRefOption<String> for Json {
+        fn get_ref_option(&self) -> Option<&String> {
+            match self {
+                Json::JStr(value) => Some(value),
+                _ => None,
+            }
+        }
+    }
+
+    impl GetMutOption<String> for Json {
+        fn get_mut_option(&mut self) -> Option<&mut String> {
+            match self {
+                Json::JStr(value) => Some(value),
+                _ => None,
+            }
+        }
+    }
+
+    impl<'a> GetRefOptionSecond<'a, Json, String> for Option<Json> {}
+    impl<'a> GetMutOptionSecond<'a, Json, String> for Result<Json, String> {}
+
+    #[test]
+    fn get_ref_option() {
+        let tree = [Json::JNull, Json::JStr(h()), Json::JNum(0.5)];
+
+        assert_eq!(
+            vec![&h()],
+            tree.iter().filter_map(GetRefOption::<String>::get_ref_option).collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            Some(&h()),
+            Some(Json::JStr(h())).get_ref_option_second()
+        );
+
+        assert_eq!(
+            None,
+            Some(Json::JNull).get_ref_option_second()
+        );
+
+        let mut result: Result<Json, String> = Ok(Json::JStr(h()));
+
+        if let Some(value) = result.get_mut_option_second() {
+            value.push('!');
+        }
+
+        assert_eq!(
+            Ok(Json::JStr(String::from("hello!"))),
+            result
+        );
+
+        let mut missing: Option<String> = None;
+
+        assert_eq!(
+            None,
+            missing.get_mut_option()
+        );
+    }
+
     #[test]
     fn setters() {
         assert_eq!(
@@ -203,5 +267,1240 @@ mod tests {
         assert!(round_trip::<XY, f32>(XY::Y(3.0)));
         assert!(round_trip_2::<XY, f32>(3.0));
     }
+
+    #[allow(dead_code)]
+    #[derive(Debug, GetOption, SetOption, ModifyOption, PartialEq)]
+    enum Reading {
+        Sensor(Sensor),
+        Offline,
+    }
+
+    #[derive(Debug, GetOption, SetOption, ModifyOption, PartialEq)]
+    enum Sensor {
+        Celsius(f64),
+        Raw(u16),
+    }
+
+    impl ReverseGet<Sensor> for Reading {
+        fn reverse_get(value: Sensor) -> Self {
+            Reading::Sensor(value)
+        }
+    }
+
+    zoom_option![Reading => Sensor => f64];
+
+    #[test]
+    fn composite_prisms() {
+        let warm = || Reading::Sensor(Sensor::Celsius(21.5));
+        let raw = || Reading::Sensor(Sensor::Raw(512));
+
+        assert_eq!(
+            Some(21.5),
+            warm().get_option_second()
+        );
+
+        assert_eq!(
+            None,
+            raw().get_option_second()
+        );
+
+        assert_eq!(
+            Some(Reading::Sensor(Sensor::Celsius(0.0))),
+            warm().set_option_second(0.0)
+        );
+
+        assert_eq!(
+            None,
+            raw().modify_option_second(|c: f64| c + 1.0)
+        );
+
+        assert_eq!(
+            Some(Reading::Sensor(Sensor::Celsius(22.5))),
+            warm().modify_option_second(|c: f64| c + 1.0)
+        );
+    }
+
+    #[derive(Clone, Debug, Get, GetRef, Set, Modify, PartialEq)]
+    pub struct Order {
+        pub id: u32,
+        pub payment: Option<Payment>,
+    }
+
+    #[derive(Clone, Debug, GetOption, Set, Modify, PartialEq)]
+    pub enum Payment {
+        Card(Card),
+        Cash(u32),
+    }
+
+    #[derive(Clone, Debug, Get, Set, Modify, PartialEq)]
+    pub struct Card {
+        pub last4: u16,
+    }
+
+    zoom_affine![Order => Option<Payment> => ?Payment => ?Card => u16];
+    zoom_affine![Order => &Option<Payment> => ?Payment => ?u32];
+
+    #[test]
+    fn affine_composites() {
+        let by_card = || Order { id: 1, payment: Some(Payment::Card(Card { last4: 1234 })) };
+        let in_cash = || Order { id: 2, payment: Some(Payment::Cash(20)) };
+        let unpaid = || Order { id: 3, payment: None };
+
+        assert_eq!(
+            Some(1234),
+            by_card().get_affine_fourth()
+        );
+
+        assert_eq!(
+            None,
+            unpaid().get_affine_fourth()
+        );
+
+        assert_eq!(
+            (None, Some(20)),
+            (by_card().get_affine_third(), in_cash().get_affine_third())
+        );
+
+        assert_eq!(
+            Some(9999),
+            by_card().set_fourth(9999).get_affine_fourth()
+        );
+
+        assert_eq!(
+            in_cash(),
+            in_cash().modify_fourth(|last4| last4 + 1)
+        );
+
+        assert_eq!(
+            Some(25),
+            in_cash().modify_third(|cash: u32| cash + 5).get_affine_third()
+        );
+    }
+
+    #[derive(Clone, Debug, Get, GetRef, Set, Modify, PartialEq)]
+    pub struct Shop {
+        pub name: String,
+        pub stock: Stock,
+    }
+
+    #[derive(Clone, Debug, Get, GetRef, Set, Modify, PartialEq)]
+    pub struct Stock {
+        pub count: u32,
+    }
+
+    impl GetMut<Stock> for Shop {
+        fn get_mut(&mut self) -> &mut Stock {
+            &mut self.stock
+        }
+    }
+
+    impl GetMut<u32> for Stock {
+        fn get_mut(&mut self) -> &mut u32 {
+            &mut self.count
+        }
+    }
+
+    zoom_all![mut Shop => Stock => u32];
+
+    fn corner_shop() -> Shop {
+        Shop { name: String::from("corner shop"), stock: Stock { count: 10 } }
+    }
+
+    pub struct Depot {
+        pub shop: Shop,
+    }
+
+    fields! {
+        Depot {
+            shop: Shop,
+        }
+    }
+
+    zoom![mut Depot => Shop => Stock];
+
+    #[test]
+    fn get_mut() {
+        let mut shop = corner_shop();
+
+        *shop.get_mut_second() -= 3;
+
+        assert_eq!(
+            7,
+            *shop.get_ref_second()
+        );
+
+        let mut depot = Depot { shop: corner_shop() };
+
+        depot.get_mut_second().count += 5;
+
+        assert_eq!(
+            15,
+            depot.shop.stock.count
+        );
+    }
+
+    #[test]
+    fn in_place() {
+        let mut shop = corner_shop();
+
+        shop.set_in_place_second(3);
+        shop.modify_in_place_second(|count| *count *= 4);
+
+        assert_eq!(
+            12,
+            shop.stock.count
+        );
+    }
+
+    impl TryModify<u32> for Stock {
+        fn try_modify<E>(self, f: impl FnOnce(u32) -> Result<u32, E>) -> Result<Self, E> {
+            f(self.count).map(|count| Stock { count })
+        }
+    }
+
+    fn sell(amount: u32) -> impl FnOnce(u32) -> Result<u32, String> {
+        move |count| count.checked_sub(amount).ok_or_else(|| format!("only {} left", count))
+    }
+
+    #[test]
+    fn try_modify() {
+        let stock = || Some(Stock { count: 10 });
+
+        assert_eq!(
+            Ok(Some(4)),
+            stock().try_modify_second(sell(6)).map(|s| s.map(|stock| stock.count))
+        );
+
+        assert_eq!(
+            Err(String::from("only 10 left")),
+            stock().try_modify_second(sell(11)).map(|s| s.map(|stock| stock.count))
+        );
+
+        assert_eq!(
+            Ok(None),
+            None.try_modify_second(sell(11)).map(|s: Option<Stock>| s.map(|stock| stock.count))
+        );
+    }
+
+    #[test]
+    fn results() {
+        let fetched = || Ok::<Stock, String>(Stock { count: 3 });
+        let failed = || Err::<Stock, String>(String::from("timeout"));
+
+        assert_eq!(
+            Ok(Stock { count: 4 }),
+            fetched().modify_second(|count: u32| count + 1)
+        );
+
+        assert_eq!(
+            failed(),
+            failed().set_second(10u32)
+        );
+
+        assert_eq!(
+            Err(String::from("only 3 left")),
+            fetched().try_modify_second(sell(5))
+        );
+
+        assert_eq!(
+            Some(String::from("shop: timeout")),
+            failed().modify_err(|e| format!("shop: {}", e)).get_option_err()
+        );
+
+        let same: Result<u32, u32> = Ok(1);
+
+        assert_eq!(
+            (Ok(2), Ok(1)),
+            (same.set(2), same.set_err(2))
+        );
+
+        assert_eq!(
+            Err::<u32, u32>(7),
+            Result::reverse_get_err(7)
+        );
+    }
+
+    #[derive(Clone, Debug, Get, GetRef, Set, Modify, PartialEq)]
+    pub struct Franchise {
+        pub head_office: Rc<Stock>,
+        pub warehouse: Box<Stock>,
+    }
+
+    zoom![Franchise => Rc<Stock> => Stock => u32];
+
+    #[test]
+    fn smart_pointers() {
+        let franchise = Franchise {
+            head_office: Rc::new(Stock { count: 1 }),
+            warehouse: Box::new(Stock { count: 100 }),
+        };
+        let shared = franchise.clone();
+
+        let restocked = franchise.modify_third(|count: u32| count + 1);
+
+        assert_eq!(
+            (2, 1),
+            (restocked.head_office.count, shared.head_office.count)
+        );
+
+        let emptied = restocked.set_at::<path!(Box<Stock>, Stock, u32)>(0);
+
+        assert_eq!(
+            &0,
+            emptied.get_ref_at::<path!(Box<Stock>, Stock, u32)>()
+        );
+
+        let mut state = Arc::new(Stock { count: 5 });
+        let snapshot = Arc::clone(&state);
+        state.set_in_place(Stock { count: 6 });
+
+        assert_eq!(
+            (6, 5),
+            (state.count, snapshot.count)
+        );
+
+        let unique = Rc::new(Stock { count: 1 });
+        let address = Rc::as_ptr(&unique);
+        let unique = unique.set(Stock { count: 2 });
+
+        assert_eq!(
+            (address, 2),
+            (Rc::as_ptr(&unique), unique.count)
+        );
+
+        let shared = Rc::clone(&unique);
+        let detached = shared.modify(|stock: Stock| Stock { count: stock.count * 10 });
+
+        assert_eq!(
+            (2, 20),
+            (unique.count, detached.count)
+        );
+
+        let level: Cow<i8> = Cow::Borrowed(&3);
+
+        assert_eq!(
+            &3i8,
+            GetRef::<i8>::get_ref(&level)
+        );
+
+        let label: Cow<str> = Cow::Borrowed("stock");
+
+        assert_eq!(
+            "stock: 6",
+            label.modify(|mut label: String| { label.push_str(": 6"); label })
+        );
+    }
+
+    #[derive(Debug, Get, GetRef, Set, Modify, PartialEq)]
+    pub struct Mall {
+        pub name: String,
+        pub floor: Floor,
+    }
+
+    #[derive(Debug, Get, GetRef, Set, Modify, PartialEq)]
+    pub struct Floor {
+        pub level: i8,
+        pub wing: Wing,
+    }
+
+    #[derive(Debug, Get, GetRef, Set, Modify, PartialEq)]
+    pub struct Wing {
+        pub name: String,
+        pub shop: Shop,
+    }
+
+    zoom_all![Mall => Floor => Wing => Shop => Stock => u32];
+    zoom![Option<Mall> => Mall => Floor => Wing => Shop => Stock => u32];
+
+    fn mall() -> Mall {
+        Mall {
+            name: String::from("city mall"),
+            floor: Floor {
+                level: -1,
+                wing: Wing { name: String::from("east"), shop: corner_shop() },
+            },
+        }
+    }
+
+    #[test]
+    fn paths() {
+        type Count = path!(Floor, Wing, Shop, Stock, u32);
+
+        assert_eq!(
+            10,
+            mall().get_at::<Count>()
+        );
+
+        assert_eq!(
+            &10,
+            mall().get_ref_at::<Count>()
+        );
+
+        assert_eq!(
+            mall().set_fifth(12),
+            mall().set_at::<Count>(12)
+        );
+
+        assert_eq!(
+            Some(20),
+            Some(mall())
+                .modify_at::<path!(Mall, Floor, Wing, Shop, Stock, u32)>(|count| count * 2)
+                .map(|mall| mall.get_at::<Count>())
+        );
+
+        let mut shop = corner_shop();
+        *shop.get_mut_at::<path!(Stock, u32)>() += 1;
+
+        assert_eq!(
+            11,
+            shop.stock.count
+        );
+    }
+
+    struct Editor<P> {
+        field: Lens<Mall, u32, P>,
+    }
+
+    #[test]
+    fn optic_values() {
+        let shop = Lens::<Mall, Floor>::new()
+            .then(Lens::<Floor, Wing>::new())
+            .then(Lens::<Wing, Shop>::new());
+        let editor = Editor { field: shop.then(Lens::<Shop, Stock>::new()).then(Lens::<Stock, u32>::new()) };
+
+        assert_eq!(
+            mall().set_fifth(3),
+            editor.field.set(mall(), 3)
+        );
+
+        assert_eq!(
+            11,
+            editor.field.get(editor.field.modify(mall(), |count| count + 1))
+        );
+
+        let counts = Traversal::<Vec<Option<Stock>>, Option<Stock>>::new()
+            .then(Traversal::<Option<Stock>, Stock>::new())
+            .then(Traversal::<Stock, u32>::new());
+        let shelves = || vec![Some(Stock { count: 1 }), None, Some(Stock { count: 2 })];
+
+        assert_eq!(
+            vec![&1, &2],
+            counts.get_all(&shelves()).collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            vec![Some(Stock { count: 0 }), None, Some(Stock { count: 0 })],
+            counts.set(shelves(), 0)
+        );
+
+        let celsius = Prism::<Reading, Sensor>::new().then(Prism::<Sensor, f64>::new());
+
+        assert_eq!(
+            Some(-4.0),
+            celsius.preview(Reading::Sensor(Sensor::Celsius(-4.0)))
+        );
+
+        assert_eq!(
+            None,
+            celsius.set(Reading::Offline, 0.0)
+        );
+    }
+
+    #[test]
+    fn indexed() {
+        let shelves = || vec![Stock { count: 1 }, Stock { count: 2 }];
+
+        assert_eq!(
+            (Some(&Stock { count: 2 }), None),
+            (shelves().get_index(1), shelves().get_index(2))
+        );
+
+        assert_eq!(
+            Some(vec![Stock { count: 1 }, Stock { count: 5 }]),
+            shelves().set_index_second(1, 5)
+        );
+
+        assert_eq!(
+            Some(VecDeque::from(vec![Stock { count: 1 }, Stock { count: 2 }, Stock { count: 30 }])),
+            VecDeque::from(vec![Stock { count: 1 }, Stock { count: 2 }, Stock { count: 3 }])
+                .modify_index_second(2, |count| count * 10)
+        );
+
+        let shops = [corner_shop(), corner_shop()];
+
+        assert_eq!(
+            Some(&10),
+            GetIndexThird::<Shop, Stock, u32>::get_index_third(&shops, 0)
+        );
+
+        assert_eq!(
+            None,
+            ModifyIndexThird::<Shop, Stock, u32>::modify_index_third(shops, 2, |count| count + 1)
+        );
+
+        let boxed: Box<[Stock]> = shelves().into_boxed_slice();
+
+        assert_eq!(
+            Some(3),
+            boxed.modify_index(0, |stock| Stock { count: stock.count + 2 })
+                .and_then(|boxed| boxed.get_index(0).map(|stock| stock.count))
+        );
+    }
+
+    #[test]
+    fn keyed() {
+        let mut shops = BTreeMap::new();
+        shops.insert(String::from("corner"), corner_shop());
+
+        let shops = shops.set_key(String::from("kiosk"), Some(Shop { name: String::from("kiosk"), stock: Stock { count: 1 } }));
+
+        assert_eq!(
+            Some(&10),
+            GetKeyThird::<str, Shop, Stock, u32>::get_key_third(&shops, "corner")
+        );
+
+        let shops = SetKeyThird::<String, Shop, Stock, u32>::set_key_third(shops, String::from("kiosk"), 7)
+            .modify_key(String::from("corner"), |_| None);
+
+        assert_eq!(
+            vec![(&String::from("kiosk"), &7)],
+            shops.iter().map(|(name, shop)| (name, &shop.stock.count)).collect::<Vec<_>>()
+        );
+
+        let mut stocks = HashMap::new();
+        stocks.insert("north", Stock { count: 1 });
+
+        let stocks = stocks.modify_key_second("north", |count| count + 1).modify_key_second("south", |count| count + 1);
+
+        assert_eq!(
+            (Some(&Stock { count: 2 }), false),
+            (stocks.get_key(&"north"), stocks.contains_key("south"))
+        );
+
+        let open = HashSet::new().set_key("corner", true).modify_key("kiosk", |open| !open);
+
+        assert!(open.get_key(&"kiosk").is_some() && open.set_key("kiosk", false).get_key(&"kiosk").is_none());
+    }
+
+    #[test]
+    fn tuples() {
+        let row = (1u8, 2u16, 3u32, 4u64, 5i8, 6i16, 7i32, 8i64, 9usize, 10isize, 'k', "twelve");
+
+        assert_eq!(
+            ("twelve", &'k'),
+            (row.get_nth(nth::Twelfth), row.get_ref_nth(nth::Eleventh))
+        );
+
+        let mut pair = (corner_shop(), Stock { count: 2 });
+        pair.get_mut_nth(nth::First).stock.count += 1;
+
+        assert_eq!(
+            (11, 2),
+            (pair.get_ref_nth(nth::First).stock.count, pair.get_ref_nth(nth::Second).count)
+        );
+
+        let swapped: (String, Stock) = pair.modify_nth(nth::First, |shop| shop.name);
+
+        assert_eq!(
+            (String::from("corner shop"), 4),
+            swapped.modify_nth(nth::Second, |stock| stock.count * 2)
+        );
+
+        assert_eq!(
+            (1, "two", 3.0),
+            (1, 2, 3.0).set_nth(nth::Second, "two")
+        );
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct Crate<T> {
+        pub label: String,
+        pub contents: T,
+    }
+
+    impl<A, B> ModifyPoly<A, B> for Crate<A> {
+        type Output = Crate<B>;
+
+        fn modify_poly(self, mut f: impl FnMut(A) -> B) -> Crate<B> {
+            Crate { label: self.label, contents: f(self.contents) }
+        }
+    }
+
+    #[test]
+    fn polymorphic() {
+        let raw = || Crate { label: String::from("fruit"), contents: vec![Some("3"), None, Some("4")] };
+
+        assert_eq!(
+            Crate { label: String::from("fruit"), contents: vec![Some(Stock { count: 3 }), None, Some(Stock { count: 4 })] },
+            raw().modify_poly_third(|count: &str| Stock { count: count.parse().unwrap() })
+        );
+
+        assert_eq!(
+            Crate { label: String::from("fruit"), contents: vec![Some(0u8), None, Some(0u8)] },
+            raw().set_poly_third(0u8)
+        );
+
+        assert_eq!(
+            Some(Ok::<_, ()>(("3", "4"))),
+            Some(Ok((3, 4))).modify_poly_third(|n: i32| if n == 3 { "3" } else { "4" })
+        );
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct Transfer {
+        pub source: Stock,
+        pub destination: Stock,
+    }
+
+    tagged! {
+        Transfer { source: Stock => Source, destination: Stock => Destination }
+        Stock { count: u32 => Count }
+    }
+
+    #[test]
+    fn tagged_fields() {
+        let transfer = || Transfer { source: Stock { count: 5 }, destination: Stock { count: 1 } };
+
+        assert_eq!(
+            (5, &1),
+            (transfer().get_field_second(Source, Count), transfer().get_ref_field_second(Destination, Count))
+        );
+
+        let mut moved = transfer()
+            .modify_field_second(Source, Count, |count| count - 2)
+            .set_field(Destination, Stock { count: 3 });
+
+        assert_eq!(
+            Transfer { source: Stock { count: 3 }, destination: Stock { count: 3 } },
+            moved
+        );
+
+        *moved.get_mut_field(Destination).get_mut_field(Count) += 1;
+
+        assert_eq!(
+            Stock { count: 4 },
+            moved.get_field(Destination)
+        );
+
+        let page = Page { number: 2, rows: vec![Stock { count: 1 }] }
+            .modify_field(Number, |number| number + 1)
+            .modify_field(Rows, |mut rows| { rows.push(Stock { count: 2 }); rows });
+
+        assert_eq!(
+            (&3, Some(&2)),
+            (page.get_ref_field(Number), page.get_ref_field(Rows).last().map(|stock| &stock.count))
+        );
+    }
+
+    tagged! {
+        pub(crate) impl<T> Page<T> where T: Clone { number: u32 => Number, rows: Vec<T> => Rows }
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct Page<T> {
+        pub number: u32,
+        pub rows: Vec<T>,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub enum Envelope<M> {
+        Sealed(Page<M>),
+        Empty,
+    }
+
+    fields! {
+        impl<T> Page<T> {
+            number: u32,
+            rows: Vec<T>,
+        }
+    }
+
+    impl<M> GetOption<Page<M>> for Envelope<M> {
+        fn get_option(self) -> Option<Page<M>> {
+            match self {
+                Envelope::Sealed(page) => Some(page),
+                Envelope::Empty => None,
+            }
+        }
+    }
+
+    impl<M> Modify<Page<M>> for Envelope<M> {
+        fn modify(self, f: impl FnOnce(Page<M>) -> Page<M>) -> Self {
+            match self {
+                Envelope::Sealed(page) => Envelope::Sealed(f(page)),
+                Envelope::Empty => Envelope::Empty,
+            }
+        }
+    }
+
+    pub struct Binder<T> {
+        pub title: String,
+        pub page: Page<T>,
+    }
+
+    fields! {
+        impl<T: Clone> Binder<T> {
+            title: String,
+            page: Page<T>,
+        }
+    }
+
+    zoom_all![mut impl<T: Clone> Binder<T> => Page<T> => Vec<T>];
+    zoom![impl<M> Envelope<M> => Page<M> => u32 where M: Debug];
+
+    pub struct Quote<'a, T> {
+        pub text: &'a str,
+        pub notes: Vec<T>,
+    }
+
+    pub struct Shelf<'a, T> {
+        pub quote: Quote<'a, T>,
+    }
+
+    fields! {
+        impl<'a, T: Clone + Into<Vec<u8>>> Quote<'a, T> {
+            text: &'a str,
+            notes: Vec<T>,
+        }
+    }
+
+    fields! {
+        impl<'a, T: Clone + Into<Vec<u8>>> Shelf<'a, T> {
+            quote: Quote<'a, T>,
+        }
+    }
+
+    zoom_all![mut impl<'a, T: Clone + Into<Vec<u8>>> Shelf<'a, T> => Quote<'a, T> => &'a str];
+
+    #[test]
+    fn generics() {
+        let mut binder = Binder { title: String::from("notes"), page: Page { number: 1, rows: vec!["a"] } };
+
+        binder.get_mut_second().push("b");
+
+        assert_eq!(
+            vec!["a", "b", "c"],
+            binder.modify_second(|mut rows: Vec<&str>| { rows.push("c"); rows }).get_second()
+        );
+
+        assert_eq!(
+            Envelope::Sealed(Page { number: 8, rows: vec![0.5] }),
+            Envelope::Sealed(Page { number: 4, rows: vec![0.5] }).modify_second(|number| number * 2)
+        );
+
+        assert_eq!(
+            Envelope::<String>::Empty,
+            Envelope::Empty.set_second(3)
+        );
+
+        let shelf = Shelf { quote: Quote { text: "draft", notes: vec![String::from("a")] } };
+        let shelf = shelf.set_second("final");
+
+        assert_eq!(
+            ("final", &vec![String::from("a")]),
+            (*shelf.get_ref_second(), &shelf.quote.notes)
+        );
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub enum Slot {
+        Occupied(Shop),
+        Vacant(u32),
+    }
+
+    prism!(Slot::Occupied => Shop);
+    prism!(Slot::Vacant => u32);
+    lens!(Wrapping<Stock> => 0: Stock);
+
+    #[test]
+    fn declarative_optics() {
+        let occupied = Prism::<Slot, Shop>::new();
+
+        assert_eq!(
+            (Some(corner_shop()), None),
+            (occupied.preview(Slot::Occupied(corner_shop())), occupied.preview(Slot::Vacant(30)))
+        );
+
+        assert_eq!(
+            Some(Slot::Vacant(31)),
+            Slot::Vacant(30).modify_option(|size: u32| size + 1)
+        );
+
+        assert_eq!(
+            Slot::Occupied(corner_shop()),
+            occupied.review(corner_shop())
+        );
+
+        let count = Lens::<Wrapping<Stock>, Stock>::new().then(Lens::<Stock, u32>::new());
+
+        assert_eq!(
+            4,
+            count.get(count.modify(Wrapping(Stock { count: 2 }), |count| count * 2))
+        );
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Thermostat {
+        pub celsius: i32,
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Fahrenheit(i32);
+
+    computed!(FAHRENHEIT: Thermostat => Fahrenheit {
+        get: |thermostat| Fahrenheit(thermostat.celsius * 9 / 5 + 32),
+        set: |_, fahrenheit| Thermostat { celsius: (fahrenheit.0 - 32) * 5 / 9 },
+    });
+
+    #[test]
+    fn computed_lenses() {
+        let thermostat = || Thermostat { celsius: 10 };
+
+        assert_eq!(
+            Fahrenheit(50),
+            thermostat().get()
+        );
+
+        assert_eq!(
+            Thermostat { celsius: 20 },
+            thermostat().modify(|fahrenheit: Fahrenheit| Fahrenheit(fahrenheit.0 + 18))
+        );
+
+        assert_eq!(
+            Err(Thermostat { celsius: 1 }),
+            FAHRENHEIT.check((0..=10).step_by(5).chain(1..5).map(|celsius| Thermostat { celsius }))
+        );
+
+        assert_eq!(
+            Err((thermostat(), Fahrenheit(33))),
+            FAHRENHEIT.check_set_get(vec![thermostat()], vec![Fahrenheit(32), Fahrenheit(33)])
+        );
+    }
+
+    #[cfg(feature = "laws")]
+    #[test]
+    fn law_reports() {
+        use photonix::laws::{self, Law, Violation};
+
+        assert!(laws::lens(corner_shop(), Stock { count: 1 }, Stock { count: 2 }).is_empty());
+        assert!(laws::lens_at::<path!(Stock, u32), _, _>(corner_shop(), 1, 2).is_empty());
+        assert!(laws::modify(corner_shop(), |name: String| name + "!", |name| name.to_uppercase()).is_empty());
+        assert!(laws::prism(XY::Y(0.5), 7u8).is_empty());
+
+        assert_eq!(
+            vec![Violation { law: Law::SetGet, expected: String::from("Fahrenheit(33)"), actual: String::from("Fahrenheit(32)") }],
+            laws::lens(Thermostat { celsius: 0 }, Fahrenheit(33), Fahrenheit(50))
+        );
+
+        assert_eq!(
+            Err(Law::GetSet),
+            laws::get_set::<_, Fahrenheit>(Thermostat { celsius: 1 }).map_err(|violation| violation.law)
+        );
+    }
+
+    #[cfg(feature = "proptest")]
+    impl proptest::arbitrary::Arbitrary for Fahrenheit {
+        type Parameters = ();
+        type Strategy = proptest::strategy::Map<std::ops::Range<i32>, fn(i32) -> Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            use proptest::strategy::Strategy;
+            (-1000..1000).prop_map(Fahrenheit)
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl proptest::arbitrary::Arbitrary for Thermostat {
+        type Parameters = ();
+        type Strategy = proptest::strategy::Map<std::ops::Range<i32>, fn(i32) -> Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            use proptest::strategy::Strategy;
+            (-1000..1000).prop_map(|celsius| Thermostat { celsius })
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    #[test]
+    fn proptest_laws() {
+        use photonix::laws::proptest::{check_lens, check_prism};
+
+        check_lens::<Box<u32>, u32>();
+        check_prism::<Option<u8>, u8>();
+        check_prism::<Result<bool, String>, bool>();
+    }
+
+    #[cfg(feature = "proptest")]
+    #[test]
+    #[should_panic(expected = "Thermostat` => `")]
+    fn proptest_law_violation() {
+        photonix::laws::proptest::check_lens::<Thermostat, Fahrenheit>();
+    }
+
+    #[cfg(feature = "quickcheck")]
+    impl quickcheck::Arbitrary for Fahrenheit {
+        fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+            Fahrenheit(i32::arbitrary(g) % 1000)
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            Box::new(self.0.shrink().map(Fahrenheit))
+        }
+    }
+
+    #[cfg(feature = "quickcheck")]
+    impl quickcheck::Arbitrary for Thermostat {
+        fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+            Thermostat { celsius: i32::arbitrary(g) % 1000 }
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            Box::new(self.celsius.shrink().map(|celsius| Thermostat { celsius }))
+        }
+    }
+
+    #[cfg(feature = "quickcheck")]
+    #[test]
+    fn quickcheck_laws() {
+        use photonix::laws::quickcheck::{check_lens, check_prism};
+
+        check_lens::<Box<u32>, u32>();
+        check_prism::<Option<u8>, u8>();
+        check_prism::<Result<bool, String>, bool>();
+    }
+
+    #[cfg(feature = "quickcheck")]
+    #[test]
+    #[should_panic(expected = "Thermostat` => `")]
+    fn quickcheck_law_violation() {
+        photonix::laws::quickcheck::check_lens::<Thermostat, Fahrenheit>();
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn serde_values() {
+        use serde_json::{json, Map, Value};
+
+        let users = || json!({ "users": [{ "name": "joe", "age": 42 }, { "name": "ann", "email": null }] });
+
+        assert_eq!(
+            Some(String::from("hello")),
+            json!("hello").get_option()
+        );
+
+        assert_eq!(
+            Some(42.0),
+            GetOption::<f64>::get_option(json!(42))
+        );
+
+        assert_eq!(
+            None,
+            SetOption::<f64>::set_option(json!(42), f64::NAN)
+        );
+
+        assert_eq!(
+            Some(json!([1, 2])),
+            json!([1]).modify_option(|mut array: Vec<Value>| { array.push(json!(2)); array })
+        );
+
+        assert_eq!(
+            Some(&()),
+            Value::Null.get_ref_option()
+        );
+
+        assert_eq!(
+            Some(json!({ "name": "joe", "age": 43 })),
+            users().get_key("users").cloned()
+                .and_then(|users| users.modify_index(0, |user| user.modify_key(String::from("age"), |age| age.map(|age| json!(age.as_u64().unwrap_or_default() + 1)))))
+                .and_then(|users| users.get_index(0).cloned())
+        );
+
+        assert_eq!(
+            json!(true),
+            json!(true).set_key(String::from("users"), None)
+        );
+
+        assert_eq!(
+            Map::new(),
+            Map::new().set_key(String::from("name"), Some(json!("joe"))).set_key(String::from("name"), None)
+        );
+
+        assert_eq!(
+            Some(json!({ "users": [{ "name": "joe", "age": 42 }, { "name": "ann", "email": "ann@example.com" }] })),
+            users().set_json(&json_path!["users", 1, "email"], json!("ann@example.com"))
+        );
+
+        assert_eq!(
+            Some(json!("JOE")),
+            users()
+                .modify_json(&json_path!["users", 0, "name"], |name| json!(name.as_str().map(str::to_uppercase)))
+                .and_then(|users| users.get_json(&json_path!["users", 0, "name"]))
+        );
+
+        assert_eq!(
+            None,
+            users().modify_json(&json_path!["users", 2, "name"], |name| name)
+        );
+
+        let mut users = users();
+
+        if let Some(Value::String(name)) = users.get_mut_json(&json_path!["users", 1, "name"]) {
+            name.push('!');
+        }
+
+        assert_eq!(
+            Some("ann!"),
+            users.get_ref_json(&json_path!["users", 1, "name"]).and_then(Value::as_str)
+        );
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn json_pointers() {
+        use serde_json::json;
+
+        let inventory = || json!({ "shops": { "corner": [{ "count": 2 }, { "count": 0 }], "a/b": { "m~n": 7 } } });
+        let pointer = |pointer: &str| Pointer::parse(pointer).unwrap();
+        let failure = |segment: usize, token: &str, kind: PointerErrorKind| PointerError { segment, token: token.to_owned(), kind };
+
+        assert_eq!(Ok(&inventory()), pointer("").get(&inventory()));
+        assert_eq!(Ok(&json!(7)), pointer("/shops/a~1b/m~0n").get(&inventory()));
+        assert_eq!("/shops/a~1b/m~0n", pointer("/shops/a~1b/m~0n").to_string());
+
+        assert_eq!(Err(failure(0, "shops", PointerErrorKind::MissingSlash)), Pointer::parse("shops"));
+        assert_eq!(Err(failure(1, "a~2b", PointerErrorKind::InvalidEscape)), Pointer::parse("/shops/a~2b"));
+        assert_eq!(Err(failure(1, "market", PointerErrorKind::MissingKey)), pointer("/shops/market").get(&inventory()));
+        assert_eq!(Err(failure(2, "01", PointerErrorKind::InvalidIndex)), pointer("/shops/corner/01").get(&inventory()));
+        assert_eq!(Err(failure(2, "-", PointerErrorKind::OutOfBounds { length: 2 })), pointer("/shops/corner/-").get(&inventory()));
+        assert_eq!(
+            Err(failure(4, "x", PointerErrorKind::NotAContainer { found: "a number" })),
+            pointer("/shops/corner/0/count/x").get(&inventory())
+        );
+        assert_eq!(Err(failure(0, "", PointerErrorKind::RemoveRoot)), pointer("").remove(inventory()));
+
+        assert_eq!(
+            Ok(json!({ "count": 5 })),
+            pointer("/shops/corner/-").set(inventory(), json!({ "count": 5 })).and_then(|inventory| pointer("/shops/corner/2").get(&inventory).cloned())
+        );
+
+        assert_eq!(
+            Ok(json!({ "m~n": 7, "new": true })),
+            pointer("/shops/a~1b/new").set(inventory(), json!(true)).map(|inventory| inventory["shops"]["a/b"].clone())
+        );
+
+        assert_eq!(
+            Ok(json!(null)),
+            pointer("").set(inventory(), json!(null))
+        );
+
+        assert_eq!(
+            Ok((json!([{ "count": 0 }]), json!({ "count": 2 }))),
+            pointer("/shops/corner/0").remove(inventory()).map(|(inventory, removed)| (inventory["shops"]["corner"].clone(), removed))
+        );
+
+        let mut inventory = inventory();
+
+        if let Ok(count) = pointer("/shops/corner/1/count").get_mut(&mut inventory) {
+            *count = json!(3);
+        }
+
+        assert_eq!(
+            Ok(json!(6)),
+            pointer("/shops/corner/1/count").modify(inventory, |count| json!(count.as_u64().unwrap_or_default() * 2)).map(|inventory| inventory["shops"]["corner"][1]["count"].clone())
+        );
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn json_queries() {
+        use serde_json::json;
+
+        let inventory = || json!({ "shops": {
+            "corner": { "stock": [{ "count": 2, "name": "tea" }, { "count": 0, "name": "milk" }] },
+            "market": { "stock": [{ "count": 5, "name": "tea" }, { "name": "jam" }] },
+        } });
+        let query = |query: &str| Query::parse(query).unwrap();
+        let failure = |position: usize, expected: &'static str, found: Option<char>| QueryError { position, expected, found };
+
+        assert_eq!(
+            vec![&inventory()],
+            query("$").get(&inventory())
+        );
+
+        assert_eq!(
+            vec![&json!(2), &json!(0), &json!(5)],
+            query("$.shops.*.stock[*].count").get(&inventory())
+        );
+
+        assert_eq!(
+            vec![&json!("milk"), &json!("jam")],
+            query("$['shops'][*][\"stock\"][-1].name").get(&inventory())
+        );
+
+        assert_eq!(
+            vec![&json!("tea"), &json!("tea")],
+            query("$.shops.*.stock[?(@.count >= 2)].name").get(&inventory())
+        );
+
+        assert_eq!(
+            vec![&json!("milk")],
+            query("$.shops.*.stock[?(@.count < 1)].name").get(&inventory())
+        );
+
+        assert_eq!(
+            vec![&json!({ "name": "jam" })],
+            query("$.shops.market.stock[?( @['name'] == \"jam\" )]").get(&inventory())
+        );
+
+        assert_eq!(
+            vec![&json!("milk"), &json!("jam")],
+            query("$.shops.*.stock[?(@.name < 'n')].name").get(&inventory())
+        );
+
+        assert_eq!(
+            vec![&json!("tea"), &json!("tea"), &json!("jam")],
+            query("$.shops.*.stock[?(@.count != 0)].name").get(&inventory())
+        );
+
+        assert_eq!(
+            vec![&json!({ "stock": [{ "count": 2, "name": "tea" }, { "count": 0, "name": "milk" }] })],
+            query("$.shops[?(@.stock[1].count == 0)]").get(&inventory())
+        );
+
+        assert_eq!(
+            json!([{ "count": 1, "name": "tea" }, { "count": 1, "name": "milk" }]),
+            query("$.shops.corner.stock[*].count").set(inventory(), json!(1))["shops"]["corner"]["stock"]
+        );
+
+        assert_eq!(
+            vec![&json!(3), &json!(1), &json!(6)],
+            query("$.shops.*.stock[*].count").get(&query("$.shops.*.stock[*].count").modify(inventory(), |count| json!(count.as_u64().unwrap_or_default() + 1)))
+        );
+
+        let (remaining, removed) = query("$.shops.*.stock[?(@.count)]").remove(inventory());
+
+        assert_eq!(3, removed.len());
+        assert_eq!(vec![&json!("jam")], query("$.shops.*.stock[*].name").get(&remaining));
+
+        assert_eq!(Err(failure(0, "`$`", Some('.'))), Query::parse(".shops"));
+        assert_eq!(Err(failure(2, "a member name", Some('.'))), Query::parse("$..shops"));
+        assert_eq!(Err(failure(9, "a closing quote", None)), Query::parse("$['shops]"));
+        assert_eq!(Err(failure(16, "a comparison operator or `)`", Some('='))), Query::parse("$.stock[?(@.cnt = 1)]"));
+
+        let normalized = "$.shops['a b'][?(@['it\\'s'][0] != 'don\\'t')][-1][*][?(@.count >= 1.5)]";
+
+        assert_eq!(normalized, query("$.shops['a b'][?(@[\"it's\"][0]!=\"don't\")][-1].*[?(@.count>=1.5)]").to_string());
+        assert_eq!(Ok(query(normalized)), Query::parse(&query(normalized).to_string()));
+
+        let missing = |step: usize, selector: &str, kind: SelectionErrorKind| SelectionError { step, selector: selector.to_owned(), kind };
+
+        assert_eq!(Ok(vec![&json!(2), &json!(0)]), query("$.shops.corner.stock[*].count").try_get(&inventory()));
+        assert_eq!(Ok(vec![]), query("$.shops.*.stock[?(@.count > 9)]").try_get(&inventory()));
+        assert_eq!(
+            Err(missing(4, ".count", SelectionErrorKind::MissingKey)),
+            query("$.shops.*.stock[*].count").try_get(&inventory())
+        );
+        assert_eq!(
+            Err(missing(3, "[2]", SelectionErrorKind::OutOfBounds { length: 2 })),
+            query("$.shops.corner.stock[2]").try_get(&inventory())
+        );
+        assert_eq!(
+            Err(missing(2, "[0]", SelectionErrorKind::WrongKind { expected: "an array", found: "an object" })),
+            query("$.shops.corner[0]").try_get(&inventory())
+        );
+        assert_eq!(
+            "selector 5 `[*]`: expected an object or an array, found a number",
+            query("$.shops.corner.stock[0].count[*]").try_get(&inventory()).unwrap_err().to_string()
+        );
+
+        let mut inventory = inventory();
+
+        if let Ok(counts) = query("$.shops.corner.stock[*].count").try_get_mut(&mut inventory) {
+            counts.into_iter().for_each(|count| *count = json!(9));
+        }
+
+        assert_eq!(vec![&json!(9), &json!(9), &json!(5)], query("$.shops.*.stock[*].count").get(&inventory));
+    }
+
+    traverse![Stock => u32];
+
+    #[test]
+    fn traversals() {
+        let mut branches = HashMap::new();
+        branches.insert("north", [Stock { count: 1 }, Stock { count: 2 }]);
+        branches.insert("south", [Stock { count: 3 }, Stock { count: 4 }]);
+
+        assert_eq!(
+            10u32,
+            branches.get_all_third().sum::<u32>()
+        );
+
+        let restocked = branches.modify_all_third(|count: u32| count + 10);
+
+        assert_eq!(
+            vec![13, 14],
+            restocked["south"].get_all_second().copied().collect::<Vec<u32>>()
+        );
+    }
+
+    #[test]
+    fn folds() {
+        let counts = vec![Some(4u32), None, Some(6)];
+        let shelves = [Stock { count: 0 }, Stock { count: 7 }];
+
+        assert_eq!(
+            3,
+            Fold::<Option<u32>>::count(&counts)
+        );
+
+        assert_eq!(
+            10u32,
+            counts.get_all_second().sum::<u32>()
+        );
+
+        assert_eq!(
+            Some(7),
+            shelves.find(|stock| stock.count > 0).map(|stock| stock.count)
+        );
+
+        assert!(
+            shelves.any(|stock| stock.count == 0)
+        );
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Millis {
+        pub value: u64,
+    }
+
+    impl Iso<Duration> for Millis {
+        fn to(self) -> Duration {
+            Duration::from_millis(self.value)
+        }
+
+        fn from(target: Duration) -> Self {
+            Millis { value: target.as_millis() as u64 }
+        }
+    }
+
+    iso![Millis => Duration];
+
+    #[test]
+    fn isos() {
+        let second = || Millis { value: 1000 };
+
+        assert!(second().round_trip());
+        assert!(Millis::reverse_round_trip(&Duration::from_millis(3)));
+
+        assert_eq!(
+            Millis { value: 1500 },
+            second().modify(|d: Duration| d + d / 2)
+        );
+
+        assert_eq!(
+            Duration::from_secs(2),
+            second().set(Duration::from_secs(2)).get()
+        );
+    }
 }
 